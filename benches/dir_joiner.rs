@@ -0,0 +1,48 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::path::PathBuf;
+use xdirs::{cache_dir_for, dir_joiner_for, DirKind};
+
+const APP: &str = "Chrome";
+const KEYS: usize = 1_000;
+
+fn keys() -> Vec<String> {
+    (0..KEYS).map(|i| format!("{:016x}", i * 7919)).collect()
+}
+
+fn bench_dir_joiner(c: &mut Criterion) {
+    let keys = keys();
+    let mut group = c.benchmark_group("cache_paths");
+
+    group.bench_function("cache_dir_for", |b| {
+        b.iter(|| {
+            for key in &keys {
+                black_box(cache_dir_for(APP).map(|dir| dir.join(key)));
+            }
+        })
+    });
+
+    group.bench_function("DirJoiner::join", |b| {
+        b.iter(|| {
+            let cache = dir_joiner_for(DirKind::Cache, APP).unwrap();
+            for key in &keys {
+                black_box(cache.join(key));
+            }
+        })
+    });
+
+    group.bench_function("DirJoiner::join_into", |b| {
+        b.iter(|| {
+            let cache = dir_joiner_for(DirKind::Cache, APP).unwrap();
+            let mut path = PathBuf::new();
+            for key in &keys {
+                cache.join_into(key, &mut path);
+                black_box(&path);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dir_joiner);
+criterion_main!(benches);
@@ -0,0 +1,13 @@
+//
+// Defines the `bsd` configuration alias for the BSD family of operating systems, which share a
+// layout of their machine-wide directories beneath /usr/local.
+//
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(bsd)");
+    if matches!(
+        std::env::var("CARGO_CFG_TARGET_OS").as_deref(),
+        Ok("freebsd" | "openbsd" | "netbsd" | "dragonfly")
+    ) {
+        println!("cargo:rustc-cfg=bsd");
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * C declarations for the xdirs library, built with:
+ *
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ *
+ * Each function writes the path, UTF-8 encoded and NUL-terminated, into buf and returns its length
+ * in bytes, not including the NUL. If the returned length is not less than len nothing has been
+ * written; call again with a buffer of at least the returned length plus one. A negative value is
+ * one of the XDIRS_ error codes below.
+ */
+
+#ifndef XDIRS_H
+#define XDIRS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define XDIRS_UNAVAILABLE (-1)
+#define XDIRS_INVALID_ARGUMENT (-2)
+#define XDIRS_FAILED (-3)
+
+intptr_t xdirs_dir(const char *kind, char *buf, size_t len);
+intptr_t xdirs_dir_for(const char *kind, const char *app, char *buf, size_t len);
+intptr_t xdirs_cache_dir_for(const char *app, char *buf, size_t len);
+intptr_t xdirs_config_dir_for(const char *app, char *buf, size_t len);
+intptr_t xdirs_data_dir_for(const char *app, char *buf, size_t len);
+intptr_t xdirs_log_dir_for(const char *app, char *buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
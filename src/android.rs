@@ -0,0 +1,261 @@
+pub use crate::environment::home_dir;
+pub use crate::unsupported::{
+    application_dir, application_scripts_dir_for, application_shared_dir, autostart_dir,
+    crash_dumps_dir, crash_reports_dir, desktop_dir, desktop_entry_dir, document_dir, download_dir,
+    executable_dir, extra_plugin_dirs_for, favorites_dir, flatpak_id, font_dir, glib_schemas_dir,
+    group_container_dir_for, host_home_dir, icloud_container_dir_for, icloud_drive_dir, icons_dir,
+    installed_container_dir_for, keyfile_backend_dir, known_folder_dir, launch_agents_dir,
+    launch_daemons_dir, log_dir, man_dir, mime_dir, package_dir_for, package_family_name,
+    pipe_name_for, public_data_dir, public_dir, recent_dir, registered_install_dir_for,
+    runtime_dir, sandbox_container_dir_for, sandbox_container_id, sandboxed_app_dir,
+    saved_games_dir, shared_documents_dir, snap_name, snap_user_common_dir, system_autostart_dir,
+    system_crash_reports_dir, system_desktop_entry_dir, system_glib_schemas_dir,
+    system_launch_agents_dir, system_locale_dirs, system_log_dir, system_man_dir, system_mime_dir,
+    system_template_dir, system_wallpaper_dir, template_dir, themes_dir, trash_dir,
+    user_application_dir, user_application_shared_dir, user_id, wallpaper_dir,
+};
+use crate::DirKind;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn cache_dir() -> Option<PathBuf> {
+    app_data_dir().map(|path| path.join(D_CACHE))
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    app_data_dir().map(|path| path.join(D_FILES))
+}
+
+pub fn config_local_dir() -> Option<PathBuf> {
+    config_dir()
+}
+
+pub fn data_dir() -> Option<PathBuf> {
+    app_data_dir().map(|path| path.join(D_FILES))
+}
+
+pub fn data_local_dir() -> Option<PathBuf> {
+    data_dir()
+}
+
+pub fn preference_dir() -> Option<PathBuf> {
+    config_dir()
+}
+
+pub fn state_dir() -> Option<PathBuf> {
+    data_local_dir()
+}
+
+pub fn is_remote(_path: &Path) -> bool {
+    false
+}
+
+pub fn temp_dir() -> Option<PathBuf> {
+    cache_dir().map(|path| path.join(D_TMP))
+}
+
+pub fn system_config_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn system_data_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn system_domain_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn service_dirs_for(_: &str) -> crate::ServiceDirs {
+    crate::ServiceDirs::default()
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub fn system_font_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_FONTS))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub fn fallback_source(kind: DirKind) -> Option<&'static str> {
+    match kind {
+        DirKind::Cache
+        | DirKind::Config
+        | DirKind::ConfigLocal
+        | DirKind::Data
+        | DirKind::DataLocal
+        | DirKind::Preference
+        | DirKind::State
+            if context_data_dir().is_none() =>
+        {
+            Some("/data/data/{package}")
+        }
+        _ => None,
+    }
+}
+
+pub fn uses_system_journal() -> bool {
+    true
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_CACHE: &str = "cache";
+const D_FILES: &str = "files";
+const D_TMP: &str = "tmp";
+const D_DATA: &str = "/data/data";
+const D_USER_DATA: &str = "/data/user";
+const D_SYSTEM_FONTS: &str = "/system/fonts";
+const F_CMDLINE: &str = "/proc/self/cmdline";
+const F_STATUS: &str = "/proc/self/status";
+const PER_USER_RANGE: u32 = 100_000;
+
+///
+/// Returns the application's private data directory, the parent of the `files` and `cache`
+/// directories. This is taken from the application context where one is available, otherwise
+/// it is computed from the package name of the current process; the context is asked again on
+/// each call until it answers, as it may be initialized after the first call.
+///
+fn app_data_dir() -> Option<PathBuf> {
+    static CONVENTIONAL_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+    context_data_dir().or_else(|| CONVENTIONAL_DIR.get_or_init(conventional_data_dir).clone())
+}
+
+fn conventional_data_dir() -> Option<PathBuf> {
+    let cmdline = std::fs::read(F_CMDLINE).ok()?;
+    let package = package_name(&cmdline)?;
+    let status = std::fs::read_to_string(F_STATUS).ok();
+    Some(data_dir_of(
+        &package,
+        status.as_deref().and_then(android_user_id),
+    ))
+}
+
+///
+/// Returns the package name from the contents of `/proc/self/cmdline`; the first argument of an
+/// application process is its package name, followed by `:name` for any secondary process.
+///
+fn package_name(cmdline: &[u8]) -> Option<String> {
+    let first = cmdline.split(|b| *b == 0).next()?;
+    let first = std::str::from_utf8(first).ok()?;
+    let package = first.split(':').next()?;
+    if !package.is_empty() && package.contains('.') && !package.contains('/') {
+        Some(package.to_string())
+    } else {
+        None
+    }
+}
+
+///
+/// Returns the Android user the process is running as, from the real uid in the contents of
+/// `/proc/self/status`.
+///
+fn android_user_id(status: &str) -> Option<u32> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Uid:"))
+        .and_then(|uids| uids.split_whitespace().next())
+        .and_then(|uid| uid.parse::<u32>().ok())
+        .map(|uid| uid / PER_USER_RANGE)
+}
+
+fn data_dir_of(package: &str, user: Option<u32>) -> PathBuf {
+    match user {
+        Some(user) if user != 0 => Path::new(D_USER_DATA).join(user.to_string()).join(package),
+        _ => Path::new(D_DATA).join(package),
+    }
+}
+
+///
+/// Returns the data directory reported by the application context, keeping only an answer; if
+/// there is no context yet it is asked again on the next call.
+///
+#[cfg(feature = "ndk-context")]
+fn context_data_dir() -> Option<PathBuf> {
+    static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+    if let Some(path) = DATA_DIR.get() {
+        return Some(path.clone());
+    }
+    let path = jni_data_dir()?;
+    Some(DATA_DIR.get_or_init(|| path).clone())
+}
+
+#[cfg(not(feature = "ndk-context"))]
+fn context_data_dir() -> Option<PathBuf> {
+    None
+}
+
+///
+/// Asks the application context, through JNI, for its files directory and returns its parent.
+/// The context is only present once the activity glue has initialized `ndk-context`; until
+/// then `android_context` panics, which is treated as there being no context.
+///
+#[cfg(feature = "ndk-context")]
+fn jni_data_dir() -> Option<PathBuf> {
+    use jni::objects::{JObject, JString};
+    use jni::JavaVM;
+
+    let context = std::panic::catch_unwind(ndk_context::android_context).ok()?;
+    let vm = unsafe { JavaVM::from_raw(context.vm().cast()) }.ok()?;
+    let mut env = vm.attach_current_thread().ok()?;
+    let context = unsafe { JObject::from_raw(context.context().cast()) };
+
+    let path = env
+        .call_method(&context, "getFilesDir", "()Ljava/io/File;", &[])
+        .and_then(|files| files.l())
+        .and_then(|files| env.call_method(&files, "getAbsolutePath", "()Ljava/lang/String;", &[]))
+        .and_then(|path| path.l())
+        .and_then(|path| env.get_string(&JString::from(path)).map(String::from));
+    match path {
+        Ok(path) => Path::new(&path).parent().map(Path::to_path_buf),
+        Err(_) => {
+            let _ = env.exception_clear();
+            None
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(
+            package_name(b"com.example.chrome\0"),
+            Some("com.example.chrome".to_string())
+        );
+        assert_eq!(
+            package_name(b"com.example.chrome:remote\0"),
+            Some("com.example.chrome".to_string())
+        );
+        assert_eq!(package_name(b"/system/bin/sh\0-c\0"), None);
+        assert_eq!(package_name(b""), None);
+    }
+
+    #[test]
+    fn test_data_dir_of() {
+        assert_eq!(
+            data_dir_of("com.example.chrome", None),
+            PathBuf::from("/data/data/com.example.chrome")
+        );
+        assert_eq!(
+            data_dir_of(
+                "com.example.chrome",
+                android_user_id("Name:\tchrome\nUid:\t1010123\t1010123\t1010123\t1010123\n")
+            ),
+            PathBuf::from("/data/user/10/com.example.chrome")
+        );
+    }
+}
@@ -0,0 +1,169 @@
+/*!
+Provides validation and sanitization of the application names passed to the `_for` functions.
+
+An application name is used as a single path component, and so must not contain path
+separators, must not be a relative component such as `..`, and must be acceptable to the file
+systems of every supported platform. The same rules are applied on every platform so that an
+application's directories are named alike wherever it runs.
+*/
+
+use std::borrow::Cow;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns `app` in a form that is safe to use as a single path component; this is the form used
+/// by all of the `_for` functions. A valid name is returned unchanged, otherwise:
+///
+/// * path separators, control characters, and characters not permitted on Windows
+///   (`:*?"<>|`) are replaced with `_`,
+/// * leading and trailing spaces, and trailing periods, are removed,
+/// * names reserved by Windows, such as `CON` or `nul.txt`, are prefixed with `_`,
+/// * an empty result, including `.` and `..`, is replaced with `_`,
+/// * names longer than 255 bytes are truncated.
+///
+/// ```rust
+/// use xdirs::sanitize_app_name;
+///
+/// assert_eq!(sanitize_app_name("MyApp"), "MyApp");
+/// assert_eq!(sanitize_app_name("../evil"), ".._evil");
+/// assert_eq!(sanitize_app_name("CON"), "_CON");
+/// ```
+///
+pub fn sanitize_app_name(app: &str) -> Cow<'_, str> {
+    if validate_app_name(app).is_ok() {
+        return Cow::Borrowed(app);
+    }
+    let mut replaced: String = app
+        .chars()
+        .map(|c| if is_forbidden(c) { REPLACEMENT } else { c })
+        .collect();
+    if replaced.len() >= MAX_LEN {
+        let mut end = MAX_LEN - 1;
+        while !replaced.is_char_boundary(end) {
+            end -= 1;
+        }
+        replaced.truncate(end);
+    }
+    let trimmed = replaced.trim().trim_end_matches(['.', ' ']);
+    let sanitized = if trimmed.is_empty() {
+        REPLACEMENT.to_string()
+    } else if is_reserved(trimmed) {
+        format!("{}{}", REPLACEMENT, trimmed)
+    } else {
+        trimmed.to_string()
+    };
+    Cow::Owned(sanitized)
+}
+
+///
+/// Returns `Ok` if `app` may be used, unchanged, as an application name, otherwise a short
+/// description of the reason it may not. The function [`try_dir_for`](fn.try_dir_for.html)
+/// rejects names that fail this check, rather than sanitizing them.
+///
+pub fn validate_app_name(app: &str) -> Result<(), &'static str> {
+    if app.is_empty() {
+        Err("the name is empty")
+    } else if app == "." || app == ".." {
+        Err("the name is a relative path component")
+    } else if app.contains(['/', '\\']) {
+        Err("the name contains a path separator")
+    } else if app.chars().any(char::is_control) {
+        Err("the name contains a control character")
+    } else if app.chars().any(is_forbidden) {
+        Err("the name contains a character not permitted on Windows")
+    } else if app.starts_with(' ') || app.ends_with(' ') || app.ends_with('.') {
+        Err("the name begins or ends with a space, or ends with a period")
+    } else if is_reserved(app) {
+        Err("the name is reserved on Windows")
+    } else if app.len() > MAX_LEN {
+        Err("the name is longer than 255 bytes")
+    } else {
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const MAX_LEN: usize = 255;
+
+const REPLACEMENT: char = '_';
+
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+fn is_forbidden(c: char) -> bool {
+    c.is_control() || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|')
+}
+
+fn is_reserved(app: &str) -> bool {
+    let stem = app.split('.').next().unwrap_or_default().trim_end();
+    RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: &[(&str, &str)] = &[
+        ("Chrome", "Chrome"),
+        ("my-app 2", "my-app 2"),
+        ("org.example.App", "org.example.App"),
+        ("", "_"),
+        (".", "_"),
+        ("..", "_"),
+        ("../../etc", ".._.._etc"),
+        ("/", "_"),
+        ("a/b\\c", "a_b_c"),
+        ("C:\\Windows", "C__Windows"),
+        ("nul\0byte", "nul_byte"),
+        ("CON", "_CON"),
+        ("lpt9.txt", "_lpt9.txt"),
+        ("trailing. ", "trailing"),
+        (" leading", "leading"),
+    ];
+
+    #[test]
+    fn test_sanitize() {
+        for (name, expected) in NAMES {
+            assert_eq!(sanitize_app_name(name), *expected);
+            assert_eq!(validate_app_name(&sanitize_app_name(name)), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_sanitize_long_name() {
+        let name = "\u{e9}".repeat(200);
+        let sanitized = sanitize_app_name(&name);
+        assert!(sanitized.len() <= MAX_LEN);
+        assert_eq!(validate_app_name(&sanitized), Ok(()));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate_app_name("Chrome").is_ok());
+        assert!(validate_app_name("..").is_err());
+        assert!(validate_app_name("a/b").is_err());
+        assert!(validate_app_name("Aux").is_err());
+        assert!(validate_app_name("auxiliary").is_ok());
+    }
+
+    #[test]
+    fn test_for_functions_stay_in_tree() {
+        if let (Some(base), Some(path)) = (crate::cache_dir(), crate::cache_dir_for("../evil")) {
+            assert!(path.starts_with(base));
+        }
+    }
+}
@@ -0,0 +1,169 @@
+/*!
+Provides the conventional file names of the artifacts an installer creates to integrate an
+application with the platform's shell, launcher, or service manager; these complement the
+directory functions so that the complete path of such an artifact may be built.
+*/
+
+use crate::naming::naming;
+use crate::{sanitize_app_name, Naming, Platform};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Identifies a kind of platform-native artifact, each of which is native to a single platform.
+///
+/// |Artifact         | Platform | Name                         | Example                       |
+/// | --------------- | -------- | ---------------------------- | ----------------------------- |
+/// | `DesktopEntry`  | Linux    | {app}.desktop                | My App.desktop                |
+/// | `SystemdUnit`   | Linux    | {app}.service, lower-case    | my-app.service                |
+/// | `PropertyList`  | macOS    | {app}.plist, or {bundle_id}.plist | com.Example-Corp.My-App.plist |
+/// | `Shortcut`      | Windows  | {app}.lnk                    | My App.lnk                    |
+///
+/// The desktop entry and property list are named according to the application's
+/// [`Naming`](enum.Naming.html) policy, so that, for example, the bundle identifier is used on
+/// macOS once a conventional policy has been set with [`set_naming`](fn.set_naming.html).
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArtifactKind {
+    /// A freedesktop.org desktop entry, used by application launchers.
+    DesktopEntry,
+    /// A systemd service unit.
+    SystemdUnit,
+    /// A property list, such as a launch agent or preferences file, named for the bundle.
+    PropertyList,
+    /// A shell link, used in the Start menu, on the desktop, or in the Startup folder.
+    Shortcut,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the suggested file name of the artifact `kind` for the application `app` on the
+/// current platform, or `None` if the artifact is not native to the current platform.
+///
+/// ```rust
+/// use xdirs::{suggested_artifact_name, ArtifactKind};
+///
+/// if cfg!(windows) {
+///     assert_eq!(
+///         suggested_artifact_name(ArtifactKind::Shortcut, "My App"),
+///         Some(String::from("My App.lnk"))
+///     );
+/// }
+/// ```
+///
+pub fn suggested_artifact_name(kind: ArtifactKind, app: &str) -> Option<String> {
+    kind.file_name(Platform::current(), app, &naming(app))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ArtifactKind {
+    ///
+    /// Returns the platform to which this kind of artifact is native.
+    ///
+    pub fn platform(&self) -> Platform {
+        match self {
+            Self::DesktopEntry | Self::SystemdUnit => Platform::Linux,
+            Self::PropertyList => Platform::MacOS,
+            Self::Shortcut => Platform::Windows,
+        }
+    }
+
+    ///
+    /// Returns the file extension, without a leading period, of this kind of artifact.
+    ///
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::DesktopEntry => "desktop",
+            Self::SystemdUnit => "service",
+            Self::PropertyList => "plist",
+            Self::Shortcut => "lnk",
+        }
+    }
+
+    fn file_name(&self, platform: Platform, app: &str, naming: &Naming) -> Option<String> {
+        if platform != self.platform() {
+            return None;
+        }
+        let stem = match self {
+            Self::DesktopEntry | Self::PropertyList => naming
+                .dir_name(platform, app)
+                .to_string_lossy()
+                .into_owned(),
+            Self::SystemdUnit => Naming::conventional("", "")
+                .dir_name(platform, app)
+                .to_string_lossy()
+                .into_owned(),
+            Self::Shortcut => sanitize_app_name(app).into_owned(),
+        };
+        Some(format!("{}.{}", stem, self.extension()))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbatim_names() {
+        let name =
+            |kind: ArtifactKind| kind.file_name(kind.platform(), "My App", &Naming::Verbatim);
+        assert_eq!(
+            name(ArtifactKind::DesktopEntry),
+            Some(String::from("My App.desktop"))
+        );
+        assert_eq!(
+            name(ArtifactKind::SystemdUnit),
+            Some(String::from("my-app.service"))
+        );
+        assert_eq!(
+            name(ArtifactKind::PropertyList),
+            Some(String::from("My App.plist"))
+        );
+        assert_eq!(
+            name(ArtifactKind::Shortcut),
+            Some(String::from("My App.lnk"))
+        );
+    }
+
+    #[test]
+    fn test_conventional_names() {
+        let naming = Naming::conventional("com", "Example Corp");
+        assert_eq!(
+            ArtifactKind::PropertyList.file_name(Platform::MacOS, "My App", &naming),
+            Some(String::from("com.Example-Corp.My-App.plist"))
+        );
+        assert_eq!(
+            ArtifactKind::DesktopEntry.file_name(Platform::Linux, "My App", &naming),
+            Some(String::from("my-app.desktop"))
+        );
+        assert_eq!(
+            ArtifactKind::Shortcut.file_name(Platform::Windows, "../My App", &naming),
+            Some(String::from(".._My App.lnk"))
+        );
+    }
+
+    #[test]
+    fn test_not_native() {
+        assert_eq!(
+            ArtifactKind::Shortcut.file_name(Platform::Linux, "Chrome", &Naming::Verbatim),
+            None
+        );
+        assert_eq!(
+            suggested_artifact_name(ArtifactKind::DesktopEntry, "Chrome").is_some(),
+            Platform::current() == Platform::Linux
+        );
+    }
+}
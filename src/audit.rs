@@ -0,0 +1,371 @@
+/*!
+Provides an audit trail of the file system changes made by this crate, for users who must account
+for everything a library touched. Each directory created, moved, or deleted is reported to the
+sink set with [`set_audit_sink`](fn.set_audit_sink.html); by default there is no sink and nothing
+is recorded.
+*/
+
+use crate::DirKind;
+use std::fmt::{Display, Formatter, Write as _};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A file system change made by this crate.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    /// A directory was created.
+    Create,
+    /// A directory was moved; the event's path is the new location.
+    Move,
+    /// A directory, and its contents, was deleted.
+    Delete,
+}
+
+///
+/// The record of a single file system change, as passed to an [`Audit`](trait.Audit.html) sink.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditEvent {
+    /// The time of the change.
+    pub time: SystemTime,
+    /// The change made.
+    pub operation: Operation,
+    /// The path changed.
+    pub path: PathBuf,
+    /// The kind of directory the change was made for, if any; missing parents created for a
+    /// directory are reported with the directory's kind.
+    pub kind: Option<DirKind>,
+    /// The public function that made the change, such as `create_dir_for`.
+    pub reason: &'static str,
+}
+
+///
+/// A sink that receives every file system change made by this crate. Sinks are called on the
+/// thread that made the change, after it has been made, and should not block for long.
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use xdirs::{set_audit_sink, Audit, AuditEvent};
+///
+/// struct Print;
+///
+/// impl Audit for Print {
+///     fn record(&self, event: &AuditEvent) {
+///         eprintln!("xdirs: {}", event);
+///     }
+/// }
+///
+/// set_audit_sink(Some(Arc::new(Print)));
+/// ```
+///
+pub trait Audit: Send + Sync {
+    ///
+    /// Record the change `event`.
+    ///
+    fn record(&self, event: &AuditEvent);
+}
+
+///
+/// An audit sink that appends each event, as a single line of JSON, to a file.
+///
+/// ```json
+/// {"time":1700000000,"operation":"create","path":"/home/alice/.cache/MyApp","kind":"cache","reason":"create_dir_for"}
+/// ```
+///
+/// Errors writing to the file are not reported, so that auditing never causes a change to fail.
+///
+#[derive(Debug)]
+pub struct JsonLinesAudit {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Set the sink that receives every file system change made by this crate, or remove the
+/// current sink with `None`. The sink is process-wide.
+///
+pub fn set_audit_sink(sink: Option<Arc<dyn Audit>>) {
+    let mut current = match audit_sink().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    *current = sink;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Report a change to the current sink, if any.
+///
+pub(crate) fn record(
+    operation: Operation,
+    path: &Path,
+    kind: Option<DirKind>,
+    reason: &'static str,
+) {
+    let current = match audit_sink().read() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if let Some(sink) = current.as_ref() {
+        sink.record(&AuditEvent {
+            time: SystemTime::now(),
+            operation,
+            path: path.to_path_buf(),
+            kind,
+            reason,
+        });
+    }
+}
+
+///
+/// Returns those of `path` and its ancestors that do not exist, outermost first; these are the
+/// directories that creating `path` will create.
+///
+pub(crate) fn missing_dirs(path: &Path) -> Vec<PathBuf> {
+    let mut missing: Vec<PathBuf> = path
+        .ancestors()
+        .take_while(|ancestor| !ancestor.as_os_str().is_empty() && !ancestor.exists())
+        .map(Path::to_path_buf)
+        .collect();
+    missing.reverse();
+    missing
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Operation::Create => "create",
+                Operation::Move => "move",
+                Operation::Delete => "delete",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for AuditEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.operation, self.path.display())?;
+        if let Some(kind) = self.kind {
+            write!(f, " ({})", kind)?;
+        }
+        write!(f, " by {}", self.reason)
+    }
+}
+
+impl AuditEvent {
+    ///
+    /// Returns this event as a single line of JSON, without a trailing newline.
+    ///
+    pub fn to_json(&self) -> String {
+        let time = self
+            .time
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let kind = match self.kind {
+            Some(kind) => json_string(kind.name()),
+            None => String::from("null"),
+        };
+        format!(
+            "{{\"time\":{},\"operation\":{},\"path\":{},\"kind\":{},\"reason\":{}}}",
+            time,
+            json_string(&self.operation.to_string()),
+            json_string(&self.path.to_string_lossy()),
+            kind,
+            json_string(self.reason)
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Audit for JsonLinesAudit {
+    fn record(&self, event: &AuditEvent) {
+        let mut file = match self.file.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let _ = writeln!(file, "{}", event.to_json());
+    }
+}
+
+impl JsonLinesAudit {
+    ///
+    /// Construct a sink that appends to the file at `path`, creating it, but not its parent, if
+    /// necessary.
+    ///
+    pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    ///
+    /// Construct a sink that appends to the file `audit.jsonl` in the state directory of
+    /// `app`, creating the directory if necessary. The creation of the directory itself is not
+    /// recorded.
+    ///
+    pub fn for_app(app: &str) -> io::Result<Self> {
+        let dir = crate::state_dir_for(app).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                crate::Error::Unavailable(DirKind::State),
+            )
+        })?;
+        std::fs::create_dir_all(&dir)?;
+        Self::new(dir.join(AUDIT_FILE_NAME))
+    }
+
+    ///
+    /// Returns the path of the file this sink appends to.
+    ///
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const AUDIT_FILE_NAME: &str = "audit.jsonl";
+
+fn audit_sink() -> &'static RwLock<Option<Arc<dyn Audit>>> {
+    static SINK: OnceLock<RwLock<Option<Arc<dyn Audit>>>> = OnceLock::new();
+    SINK.get_or_init(Default::default)
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{read_to_string, remove_dir_all};
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\n\u{1}"), "\"a\\\"b\\\\c\\n\\u0001\"");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let event = AuditEvent {
+            time: UNIX_EPOCH,
+            operation: Operation::Create,
+            path: PathBuf::from("/tmp/My App"),
+            kind: Some(DirKind::Cache),
+            reason: "create_dir_for",
+        };
+        assert_eq!(
+            event.to_json(),
+            "{\"time\":0,\"operation\":\"create\",\"path\":\"/tmp/My App\",\"kind\":\"cache\",\"reason\":\"create_dir_for\"}"
+        );
+        assert_eq!(
+            event.to_string(),
+            "create /tmp/My App (cache) by create_dir_for"
+        );
+    }
+
+    #[test]
+    fn test_missing_dirs() {
+        let root = std::env::temp_dir();
+        assert_eq!(
+            missing_dirs(&root.join("xdirs-missing/a/b")),
+            vec![
+                root.join("xdirs-missing"),
+                root.join("xdirs-missing/a"),
+                root.join("xdirs-missing/a/b")
+            ]
+        );
+        assert!(missing_dirs(&root).is_empty());
+    }
+
+    #[test]
+    fn test_for_app() {
+        let root = std::env::temp_dir().join(format!("xdirs-audit-app-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let sink = JsonLinesAudit::for_app("Chrome").unwrap();
+        assert_eq!(
+            sink.path(),
+            crate::state_dir_for("Chrome")
+                .unwrap()
+                .join(AUDIT_FILE_NAME)
+        );
+        assert!(sink.path().starts_with(&root) && sink.path().is_file());
+        remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_json_lines_audit() {
+        let name = format!("xdirs-audit-{}", std::process::id());
+        let root = std::env::temp_dir().join(&name);
+        let created = root.join("a/b");
+        std::fs::create_dir_all(&root).unwrap();
+        let sink = Arc::new(JsonLinesAudit::new(root.join("audit.jsonl")).unwrap());
+        set_audit_sink(Some(sink.clone()));
+        crate::create_private_dir(&created).unwrap();
+        set_audit_sink(None);
+
+        let lines: Vec<String> = read_to_string(sink.path())
+            .unwrap()
+            .lines()
+            .filter(|line| line.contains(&name) && !line.contains("audit.jsonl"))
+            .map(String::from)
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("\"operation\":\"create\""));
+        assert!(lines[1].contains("\"reason\":\"create_private_dir\""));
+        remove_dir_all(root).unwrap();
+    }
+}
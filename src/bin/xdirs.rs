@@ -0,0 +1,197 @@
+/*!
+A command-line tool that prints the directories provided by the `xdirs` crate.
+
+```text
+xdirs <function> [app]
+xdirs --all [app] [--json]
+xdirs --list
+```
+
+The function name is any of the crate's directory functions, with either dashes or underscores,
+so `xdirs config-dir-for MyApp` and `xdirs config_dir_for MyApp` are equivalent. A function that
+has no value on the current platform prints nothing and exits with status 1; usage errors exit
+with status 2.
+*/
+
+use std::fmt::Display;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::exit;
+use std::str::FromStr;
+use xdirs::DirKind;
+
+// ------------------------------------------------------------------------------------------------
+// Main
+// ------------------------------------------------------------------------------------------------
+
+const EXIT_NO_VALUE: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+const USAGE: &str = "usage: xdirs <function> [app]
+       xdirs --all [app] [--json]
+       xdirs --list";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (flags, values): (Vec<&String>, Vec<&String>) =
+        args.iter().partition(|arg| arg.starts_with("--"));
+    let flags: Vec<&str> = flags.iter().map(|flag| flag.as_str()).collect();
+    let values: Vec<&str> = values.iter().map(|value| value.as_str()).collect();
+
+    match (flags.as_slice(), values.as_slice()) {
+        (["--help"], []) => print_line(USAGE),
+        (["--list"], []) => list(),
+        (["--all"], values) | (["--all", "--json"], values) | (["--json", "--all"], values)
+            if values.len() <= 1 =>
+        {
+            all(values.first().copied(), flags.contains(&"--json"))
+        }
+        ([], [function]) => single(function, None),
+        ([], [function, app]) => single(function, Some(app)),
+        _ => usage_error(None),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn usage_error(message: Option<String>) -> ! {
+    if let Some(message) = message {
+        eprintln!("error: {}", message);
+    }
+    eprintln!("{}", USAGE);
+    exit(EXIT_USAGE)
+}
+
+///
+/// Write a line to stdout, exiting quietly if it has been closed (e.g. when piped into `head`).
+///
+fn print_line<T: Display>(line: T) {
+    if writeln!(std::io::stdout(), "{}", line).is_err() {
+        exit(0)
+    }
+}
+
+fn list() {
+    for (name, _) in functions(None) {
+        print_line(name);
+    }
+}
+
+fn single(function: &str, app: Option<&str>) {
+    let function = function.replace('-', "_");
+    let (kind, app_specific) = if let Some(kind) = function.strip_suffix("_dir_for") {
+        (kind, true)
+    } else if let Some(kind) = function.strip_suffix("_dir") {
+        (kind, false)
+    } else {
+        usage_error(Some(format!("'{}' is not a known function", function)))
+    };
+    let kind = DirKind::from_str(kind).unwrap_or_else(|e| usage_error(Some(e.to_string())));
+    let path = match (app_specific, app) {
+        (true, Some(app)) if kind.has_app_form() => kind.dir_for(app),
+        (false, None) if kind.has_generic_form() => kind.dir(),
+        (true, None) => usage_error(Some(format!("'{}' requires an application name", function))),
+        (false, Some(_)) => usage_error(Some(format!(
+            "'{}' does not take an application name",
+            function
+        ))),
+        _ => usage_error(Some(format!("'{}' is not a known function", function))),
+    };
+    match path {
+        Some(path) => print_line(path.display()),
+        None => exit(EXIT_NO_VALUE),
+    }
+}
+
+fn all(app: Option<&str>, json: bool) {
+    let results = functions(app);
+    if json {
+        let entries: Vec<String> = results
+            .iter()
+            .map(|(name, path)| {
+                format!(
+                    "  {}: {}",
+                    json_string(name),
+                    path.as_ref()
+                        .map(|path| json_string(&path.to_string_lossy()))
+                        .unwrap_or_else(|| "null".to_string())
+                )
+            })
+            .collect();
+        print_line(format!("{{\n{}\n}}", entries.join(",\n")));
+    } else {
+        let width = results
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        for (name, path) in results {
+            print_line(format!(
+                "{:width$}  {}",
+                name,
+                path.map(|path| path.display().to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                width = width
+            ));
+        }
+    }
+}
+
+///
+/// Returns the name and value of every function; if `app` is `None` the application-specific
+/// functions are still named but have no value.
+///
+fn functions(app: Option<&str>) -> Vec<(String, Option<PathBuf>)> {
+    let mut results = Vec::new();
+    for kind in DirKind::all() {
+        if kind.has_generic_form() {
+            results.push((format!("{}_dir", kind), kind.dir()));
+        }
+        if kind.has_app_form() {
+            results.push((
+                format!("{}_dir_for", kind),
+                app.and_then(|app| kind.dir_for(app)),
+            ));
+        }
+    }
+    results
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::json_string;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("/home/alice"), "\"/home/alice\"");
+        assert_eq!(
+            json_string("C:\\Users\\\"Alice\"\n"),
+            "\"C:\\\\Users\\\\\\\"Alice\\\"\\n\""
+        );
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+}
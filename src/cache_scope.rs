@@ -0,0 +1,137 @@
+/*!
+Provides named scopes within an application's cache directory, such as `http` or `thumbnails`,
+so that an application partitioning its cache need not choose its own layout.
+*/
+
+use crate::naming::file_stem;
+use crate::{sanitize_app_name, Platform};
+use std::fs::read_dir;
+use std::io;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path to the directory of the cache scope `scope` of a specific application,
+/// beneath the directory returned by [`cache_dir_for`](fn.cache_dir_for.html). The scope is named
+/// in the platform's conventional form, which on Linux is lower-case with words separated by
+/// dashes, and on macOS and Windows has each word capitalized.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_CACHE_HOME` or `$HOME`/.cache/{app}/{scope} | /home/alice/.cache/MyApp/http-responses |
+/// | macOS   | `$HOME`/Library/Caches/{app}/{scope}     | /Users/Alice/Library/Caches/MyApp/Http Responses |
+/// | Windows | `{FOLDERID_LocalAppData}`\{app}\Cache\{scope} | C:\Users\Alice\AppData\Local\MyApp\Cache\Http Responses |
+///
+/// ```rust
+/// use xdirs::cache_dir_for_scope;
+///
+/// let thumbnails = cache_dir_for_scope("MyApp", "thumbnails");
+/// let http = cache_dir_for_scope("MyApp", "http responses");
+/// ```
+///
+pub fn cache_dir_for_scope(app: &str, scope: &str) -> Option<PathBuf> {
+    crate::cache_dir_for(app).map(|path| path.join(scope_name(Platform::current(), scope)))
+}
+
+///
+/// Returns the names of the existing scopes in the cache directory of a specific application, in
+/// order; this is the name of each directory within it. A cache directory that does not exist
+/// has no scopes.
+///
+/// ```rust
+/// use xdirs::cache_scopes_for;
+///
+/// for scope in cache_scopes_for("MyApp").unwrap_or_default() {
+///     println!("{}", scope);
+/// }
+/// ```
+///
+pub fn cache_scopes_for(app: &str) -> io::Result<Vec<String>> {
+    let path = match crate::cache_dir_for(app) {
+        Some(path) => path,
+        None => return Ok(Vec::new()),
+    };
+    let entries = match read_dir(path) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut scopes = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            scopes.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    scopes.sort();
+    Ok(scopes)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn scope_name(platform: Platform, scope: &str) -> String {
+    match platform {
+        Platform::Linux => file_stem(scope),
+        _ => {
+            let words: Vec<String> = scope
+                .split(|c: char| c.is_whitespace() || c == '_' || c == '-')
+                .filter(|word| !word.is_empty())
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
+                })
+                .collect();
+            sanitize_app_name(&words.join(" ")).into_owned()
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_name() {
+        assert_eq!(
+            scope_name(Platform::Linux, "HTTP Responses"),
+            "http-responses"
+        );
+        assert_eq!(
+            scope_name(Platform::MacOS, "http responses"),
+            "Http Responses"
+        );
+        assert_eq!(scope_name(Platform::Windows, "thumbnails"), "Thumbnails");
+        assert_eq!(scope_name(Platform::Windows, "GPU_cache"), "GPU Cache");
+        assert_eq!(scope_name(Platform::Linux, "../index"), ".._index");
+    }
+
+    #[test]
+    fn test_cache_scopes_for() {
+        let root = std::env::temp_dir().join(format!("xdirs-cache-scope-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        assert!(cache_scopes_for("Chrome").unwrap().is_empty());
+        let index = cache_dir_for_scope("Chrome", "index").unwrap();
+        let http = cache_dir_for_scope("Chrome", "http").unwrap();
+        std::fs::create_dir_all(&index).unwrap();
+        std::fs::create_dir_all(&http).unwrap();
+        std::fs::write(http.parent().unwrap().join("file"), "").unwrap();
+        let name = |path: &PathBuf| path.file_name().unwrap().to_string_lossy().into_owned();
+        assert_eq!(
+            cache_scopes_for("Chrome").unwrap(),
+            vec![name(&http), name(&index)]
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
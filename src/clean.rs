@@ -0,0 +1,260 @@
+/*!
+Provides the removal of stale entries from an application's cache directory, by age and by
+total size, so that a cache does not grow without bound.
+
+Each entry removed is reported to the sink set with [`set_audit_sink`](fn.set_audit_sink.html).
+*/
+
+use crate::audit::{self, Operation};
+use crate::usage::disk_usage;
+use crate::{DirKind, Error};
+use std::fs::{read_dir, remove_dir_all, remove_file, symlink_metadata};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The rules by which [`clean_cache_for`](fn.clean_cache_for.html) selects entries to remove.
+/// Each entry directly within the cache directory, a file or a whole directory, is removed or
+/// kept as a unit.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use xdirs::CleanPolicy;
+///
+/// // Report what would be removed to keep the cache within 100MiB of entries used in the last
+/// // week, without removing anything.
+/// let policy = CleanPolicy {
+///     max_age: Some(Duration::from_secs(7 * 24 * 60 * 60)),
+///     max_size: Some(100 * 1024 * 1024),
+///     dry_run: true,
+/// };
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CleanPolicy {
+    /// Remove entries that have not been modified for longer than this; the modification time of
+    /// a directory is the most recent of any file within it.
+    pub max_age: Option<Duration>,
+    /// Then remove the least recently modified entries until the total size of those remaining,
+    /// in bytes, is no more than this.
+    pub max_size: Option<u64>,
+    /// Report the entries that would be removed, without removing them.
+    pub dry_run: bool,
+}
+
+///
+/// The outcome of [`clean_cache_for`](fn.clean_cache_for.html).
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CleanReport {
+    /// The entries removed, or that would be removed in a dry run, least recently modified first.
+    pub removed: Vec<PathBuf>,
+    /// The total size, in bytes, of the entries removed.
+    pub freed: u64,
+    /// The total size, in bytes, of the entries remaining.
+    pub remaining: u64,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Remove entries from the cache directory of a specific application, as returned by
+/// [`cache_dir_for`](fn.cache_dir_for.html), according to `policy`. A cache directory that does
+/// not exist is already clean.
+///
+/// If the directory has no value on the current platform the returned error has the kind
+/// `NotFound`, and wraps an [`Error::Unavailable`](enum.Error.html#variant.Unavailable). An
+/// error removing an entry stops the clean, leaving the remaining entries in place.
+///
+pub fn clean_cache_for(app: &str, policy: &CleanPolicy) -> io::Result<CleanReport> {
+    match crate::cache_dir_for(app) {
+        Some(path) => clean(&path, policy, SystemTime::now()),
+        None => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            Error::Unavailable(DirKind::Cache),
+        )),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const REASON: &str = "clean_cache_for";
+
+fn clean(dir: &Path, policy: &CleanPolicy, now: SystemTime) -> io::Result<CleanReport> {
+    if !dir.is_dir() {
+        return Ok(CleanReport::default());
+    }
+    let mut entries = Vec::new();
+    for entry in read_dir(dir)? {
+        let path = entry?.path();
+        let (size, modified) = disk_usage(&path)?;
+        entries.push(Entry {
+            path,
+            size,
+            modified,
+        });
+    }
+    let (removed, kept) = select(entries, policy, now);
+    let mut report = CleanReport {
+        remaining: kept.iter().map(|entry| entry.size).sum(),
+        ..Default::default()
+    };
+    for entry in removed {
+        if !policy.dry_run {
+            remove(&entry.path)?;
+            audit::record(Operation::Delete, &entry.path, Some(DirKind::Cache), REASON);
+        }
+        report.freed += entry.size;
+        report.removed.push(entry.path);
+    }
+    Ok(report)
+}
+
+///
+/// Split `entries` into those to remove, least recently modified first, and those to keep.
+///
+fn select(
+    mut entries: Vec<Entry>,
+    policy: &CleanPolicy,
+    now: SystemTime,
+) -> (Vec<Entry>, Vec<Entry>) {
+    entries.sort_by(|a, b| {
+        a.modified
+            .cmp(&b.modified)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    let (mut removed, mut kept): (Vec<Entry>, Vec<Entry>) = match policy.max_age {
+        Some(max_age) => entries.into_iter().partition(|entry| {
+            now.duration_since(entry.modified)
+                .map(|age| age > max_age)
+                .unwrap_or_default()
+        }),
+        None => (Vec::new(), entries),
+    };
+    if let Some(max_size) = policy.max_size {
+        let mut size: u64 = kept.iter().map(|entry| entry.size).sum();
+        let excess = kept
+            .iter()
+            .take_while(|entry| {
+                let over = size > max_size;
+                size -= entry.size;
+                over
+            })
+            .count();
+        removed.extend(kept.drain(..excess));
+    }
+    (removed, kept)
+}
+
+fn remove(path: &Path) -> io::Result<()> {
+    if symlink_metadata(path)?.is_dir() {
+        remove_dir_all(path)
+    } else {
+        remove_file(path)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, write};
+
+    fn entry(name: &str, size: u64, age: u64, now: SystemTime) -> Entry {
+        Entry {
+            path: PathBuf::from(name),
+            size,
+            modified: now - Duration::from_secs(age),
+        }
+    }
+
+    fn names(entries: &[Entry]) -> Vec<&str> {
+        entries
+            .iter()
+            .map(|entry| entry.path.to_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_select() {
+        let now = SystemTime::now();
+        let entries = vec![
+            entry("new", 10, 1, now),
+            entry("old", 10, 100, now),
+            entry("older", 10, 200, now),
+            entry("mid", 10, 50, now),
+        ];
+        let policy = CleanPolicy {
+            max_age: Some(Duration::from_secs(150)),
+            ..Default::default()
+        };
+        let (removed, kept) = select(entries.clone(), &policy, now);
+        assert_eq!(names(&removed), vec!["older"]);
+        assert_eq!(names(&kept), vec!["old", "mid", "new"]);
+
+        let policy = CleanPolicy {
+            max_age: Some(Duration::from_secs(150)),
+            max_size: Some(15),
+            dry_run: false,
+        };
+        let (removed, kept) = select(entries, &policy, now);
+        assert_eq!(names(&removed), vec!["older", "old", "mid"]);
+        assert_eq!(names(&kept), vec!["new"]);
+    }
+
+    #[test]
+    fn test_clean() {
+        let root = std::env::temp_dir().join(format!("xdirs-clean-{}", std::process::id()));
+        create_dir_all(root.join("dir")).unwrap();
+        write(root.join("dir/a"), [0u8; 8]).unwrap();
+        write(root.join("b"), [0u8; 4]).unwrap();
+
+        let later = SystemTime::now() + Duration::from_secs(60);
+        let policy = CleanPolicy {
+            max_age: Some(Duration::from_secs(30)),
+            dry_run: true,
+            ..Default::default()
+        };
+        let report = clean(&root, &policy, later).unwrap();
+        assert_eq!(report.removed.len(), 2);
+        assert_eq!(report.freed, 12);
+        assert_eq!(report.remaining, 0);
+        assert!(root.join("dir/a").exists());
+
+        let policy = CleanPolicy {
+            dry_run: false,
+            ..policy
+        };
+        assert_eq!(clean(&root, &policy, later).unwrap().freed, 12);
+        assert_eq!(read_dir(&root).unwrap().count(), 0);
+        remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            clean(&root, &policy, later).unwrap(),
+            CleanReport::default()
+        );
+    }
+}
@@ -0,0 +1,263 @@
+/*!
+Provides the detection of the folders kept in sync by cloud storage clients, such as OneDrive,
+Dropbox, and iCloud Drive, so that applications offering to save to a cloud folder can find them
+portably.
+*/
+
+use crate::{known_folder, FolderId};
+#[cfg(feature = "dropbox")]
+use crate::{override_root, DirKind};
+use std::fs::read_dir;
+#[cfg(feature = "dropbox")]
+use std::fs::read_to_string;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The cloud storage service that keeps a folder in sync.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CloudProvider {
+    /// Apple's iCloud Drive.
+    ICloudDrive,
+    /// Microsoft OneDrive, for a personal or business account.
+    OneDrive,
+    /// Dropbox, for a personal or business account.
+    Dropbox,
+    /// Google Drive.
+    GoogleDrive,
+    /// Another provider, registered with the macOS File Provider framework under this name.
+    Other(String),
+}
+
+///
+/// A folder kept in sync by a cloud storage client, as returned by
+/// [`cloud_sync_dirs`](fn.cloud_sync_dirs.html).
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CloudSyncDir {
+    /// The service that keeps this folder in sync.
+    pub provider: CloudProvider,
+    /// The root of the folder.
+    pub path: PathBuf,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the existing folders kept in sync by the cloud storage clients of the current user. A
+/// user may have several, and more than one from the same provider, such as personal and
+/// business accounts.
+///
+/// |Platform | Value                                                                        |
+/// | ------- | ---------------------------------------------------------------------------- |
+/// | Linux   | Dropbox, from `$HOME`/.dropbox/info.json                                     |
+/// | macOS   | iCloud Drive, each of `$HOME`/Library/CloudStorage/\*, and Dropbox, as on Linux |
+/// | Windows | `{FOLDERID_SkyDrive}`, and Dropbox, from `{FOLDERID_RoamingAppData}`\Dropbox\info.json or `{FOLDERID_LocalAppData}`\Dropbox\info.json |
+///
+/// The Dropbox folders are only found when built with the `dropbox` feature, which reads the
+/// client's `info.json` with [serde_json](https://crates.io/crates/serde_json).
+///
+/// ```rust
+/// use xdirs::cloud_sync_dirs;
+///
+/// for dir in cloud_sync_dirs() {
+///     println!("{:?} syncs {:?}", dir.provider, dir.path);
+/// }
+/// ```
+///
+pub fn cloud_sync_dirs() -> Vec<CloudSyncDir> {
+    let mut dirs: Vec<CloudSyncDir> = Vec::new();
+    let mut add = |provider: CloudProvider, path: PathBuf| {
+        if path.is_dir() && !dirs.iter().any(|dir| dir.path == path) {
+            dirs.push(CloudSyncDir { provider, path });
+        }
+    };
+    if let Some(path) = crate::icloud_drive_dir() {
+        add(CloudProvider::ICloudDrive, path);
+    }
+    if let Some(path) = known_folder(FolderId::OneDrive) {
+        add(CloudProvider::OneDrive, path);
+    }
+    if cfg!(target_os = "macos") {
+        for (name, path) in file_provider_dirs() {
+            add(file_provider(&name), path);
+        }
+    }
+    #[cfg(feature = "dropbox")]
+    for path in dropbox_info_files()
+        .iter()
+        .filter_map(|file| read_to_string(file).ok())
+        .flat_map(|info| info_json_paths(&info))
+    {
+        if let Some(path) = override_root::apply(Some(path)) {
+            add(CloudProvider::Dropbox, path);
+        }
+    }
+    dirs
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_CLOUD_STORAGE: &str = "Library/CloudStorage";
+#[cfg(feature = "dropbox")]
+const D_DROPBOX_INFO: &str = ".dropbox";
+#[cfg(feature = "dropbox")]
+const D_DROPBOX: &str = "Dropbox";
+#[cfg(feature = "dropbox")]
+const F_DROPBOX_INFO: &str = "info.json";
+
+///
+/// Returns the name and path of each folder registered with the macOS File Provider framework,
+/// in order of name.
+///
+fn file_provider_dirs() -> Vec<(String, PathBuf)> {
+    let mut dirs: Vec<(String, PathBuf)> = crate::home_dir()
+        .and_then(|home| read_dir(home.join(D_CLOUD_STORAGE)).ok())
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| {
+                    (
+                        entry.file_name().to_string_lossy().into_owned(),
+                        entry.path(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+///
+/// Returns the provider of a File Provider folder from its name, which is the provider's name
+/// followed, for most, by a dash and the account.
+///
+fn file_provider(name: &str) -> CloudProvider {
+    let provider = name.split('-').next().unwrap_or_default();
+    match provider {
+        "OneDrive" => CloudProvider::OneDrive,
+        "Dropbox" => CloudProvider::Dropbox,
+        "GoogleDrive" => CloudProvider::GoogleDrive,
+        _ => CloudProvider::Other(name.to_string()),
+    }
+}
+
+///
+/// Returns the paths at which the Dropbox client may have written the description of its
+/// accounts.
+///
+#[cfg(feature = "dropbox")]
+fn dropbox_info_files() -> Vec<PathBuf> {
+    if cfg!(windows) {
+        vec![DirKind::Config.dir(), crate::config_local_dir()]
+            .into_iter()
+            .flatten()
+            .map(|path| path.join(D_DROPBOX).join(F_DROPBOX_INFO))
+            .collect()
+    } else {
+        crate::home_dir()
+            .map(|home| home.join(D_DROPBOX_INFO).join(F_DROPBOX_INFO))
+            .into_iter()
+            .collect()
+    }
+}
+
+///
+/// Returns the absolute `path` of each account in the Dropbox `info.json` document `json`;
+/// Dropbox records each account, personal or business, as a member of the top-level object.
+///
+#[cfg(feature = "dropbox")]
+fn info_json_paths(json: &str) -> Vec<PathBuf> {
+    let info: serde_json::Value = match serde_json::from_str(json) {
+        Ok(info) => info,
+        Err(_) => return Vec::new(),
+    };
+    info.as_object()
+        .map(|accounts| {
+            accounts
+                .values()
+                .filter_map(|account| account.get("path")?.as_str())
+                .map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "dropbox")]
+    #[test]
+    fn test_info_json_paths() {
+        let info = r#"{"personal": {"path": "/home/alice/Dropbox", "host": 1},
+                       "business": {"path" : "C:\\Users\\Alice\\Dropbox (Acme)"}}"#;
+        let expected: Vec<PathBuf> = if cfg!(windows) {
+            vec![PathBuf::from("C:\\Users\\Alice\\Dropbox (Acme)")]
+        } else {
+            vec![PathBuf::from("/home/alice/Dropbox")]
+        };
+        assert_eq!(info_json_paths(info), expected);
+        assert!(info_json_paths("{\"personal\": {\"path\": \"unterminated").is_empty());
+        assert!(info_json_paths("{\"path\": \"/home/alice/Dropbox\"}").is_empty());
+        assert!(info_json_paths("").is_empty());
+    }
+
+    #[test]
+    fn test_file_provider() {
+        assert_eq!(file_provider("OneDrive-Personal"), CloudProvider::OneDrive);
+        assert_eq!(
+            file_provider("GoogleDrive-alice@example.com"),
+            CloudProvider::GoogleDrive
+        );
+        assert_eq!(file_provider("Dropbox"), CloudProvider::Dropbox);
+        assert_eq!(
+            file_provider("Box-Box"),
+            CloudProvider::Other(String::from("Box-Box"))
+        );
+    }
+
+    #[cfg(all(unix, feature = "dropbox"))]
+    #[test]
+    fn test_cloud_sync_dirs() {
+        let home = crate::home_dir().unwrap();
+        let root = std::env::temp_dir().join(format!("xdirs-cloud-sync-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let info = crate::home_dir().unwrap().join(D_DROPBOX_INFO);
+        std::fs::create_dir_all(&info).unwrap();
+        std::fs::write(
+            info.join(F_DROPBOX_INFO),
+            format!(
+                "{{\"personal\": {{\"path\": {:?}}}}}",
+                home.join("Dropbox").to_string_lossy()
+            ),
+        )
+        .unwrap();
+        assert!(cloud_sync_dirs().is_empty());
+        let dropbox = crate::home_dir().unwrap().join("Dropbox");
+        std::fs::create_dir_all(&dropbox).unwrap();
+        assert_eq!(
+            cloud_sync_dirs(),
+            vec![CloudSyncDir {
+                provider: CloudProvider::Dropbox,
+                path: dropbox
+            }]
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
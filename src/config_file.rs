@@ -0,0 +1,76 @@
+/*!
+Provides the conventional path of an application's main configuration file, so that each
+application need not choose its own name for it.
+*/
+
+use crate::naming::file_stem;
+use crate::sanitize_app_name;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path to the main configuration file of a specific application, in the directory
+/// returned by [`config_dir_for`](fn.config_dir_for.html), with the file extension `extension`,
+/// such as `toml`; a leading period is ignored, and an empty extension gives a file with none.
+///
+/// The file is named for the application in lower-case, with words separated by dashes, on every
+/// platform, and so is the same whatever [`Naming`](enum.Naming.html) policy is set for the
+/// application's directories.
+///
+/// |Platform | Value                                    | Example                                  |
+/// | ------- | ---------------------------------------- | ---------------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config/{app}/{name}.{ext} | /home/alice/.config/My App/my-app.toml |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/{name}.{ext} | /Users/Alice/Library/Application Support/My App/my-app.toml |
+/// | Windows | `{FOLDERID_RoamingAppData}`\{app}\{name}.{ext} | C:\Users\Alice\AppData\Roaming\My App\my-app.toml |
+///
+/// ```rust
+/// use xdirs::config_file_for;
+///
+/// if let Some(file) = config_file_for("My App", "toml") {
+///     assert!(file.ends_with("my-app.toml"));
+/// }
+/// ```
+///
+pub fn config_file_for(app: &str, extension: &str) -> Option<PathBuf> {
+    crate::config_dir_for(app).map(|path| path.join(file_name(app, extension)))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn file_name(app: &str, extension: &str) -> String {
+    let stem = file_stem(app);
+    match extension.trim_start_matches('.') {
+        "" => stem,
+        extension => sanitize_app_name(&format!("{}.{}", stem, extension)).into_owned(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name("Chrome", "toml"), "chrome.toml");
+        assert_eq!(file_name("My App", ".json"), "my-app.json");
+        assert_eq!(file_name("My App", ""), "my-app");
+        assert_eq!(file_name("My App", "../toml"), "my-app._toml");
+    }
+
+    #[test]
+    fn test_config_file_for() {
+        assert_eq!(
+            config_file_for("Chrome", "toml"),
+            crate::config_dir_for("Chrome").map(|path| path.join("chrome.toml"))
+        );
+    }
+}
@@ -0,0 +1,137 @@
+/*!
+Provides the ordering of an application's configuration files for layered configuration, in which
+the settings shared by all users are overridden by the user's own, and those by a file named on
+the command line.
+*/
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Finds each copy of a configuration file of a specific application, in the directories returned
+/// by [`config_search_dirs_for`](fn.config_search_dirs_for.html), ordered from the lowest
+/// precedence to the highest, so that an application merging them in turn lets the user's
+/// settings override those shared by all users. An explicit file, such as one given with a
+/// `--config` option, may be added with the highest precedence of all.
+///
+/// ```rust
+/// use xdirs::ConfigResolver;
+///
+/// let from_command_line: Option<&str> = None;
+/// let files = ConfigResolver::new("MyApp", "settings.toml")
+///     .with_override(from_command_line)
+///     .resolve()
+///     .unwrap();
+/// for file in &files {
+///     println!("merging {:?}", file);
+/// }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigResolver {
+    app: String,
+    file_name: PathBuf,
+    explicit: Option<PathBuf>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ConfigResolver {
+    ///
+    /// Returns a resolver for the file `file_name`, which may include subdirectories, within the
+    /// configuration directories of `app`.
+    ///
+    pub fn new<P: AsRef<Path>>(app: &str, file_name: P) -> Self {
+        Self {
+            app: app.to_string(),
+            file_name: file_name.as_ref().to_path_buf(),
+            explicit: None,
+        }
+    }
+
+    ///
+    /// Set the explicit file, with the highest precedence; `None` leaves only the files found in
+    /// the configuration directories.
+    ///
+    pub fn with_override<P: AsRef<Path>>(mut self, explicit: Option<P>) -> Self {
+        self.explicit = explicit.map(|path| path.as_ref().to_path_buf());
+        self
+    }
+
+    ///
+    /// Returns every location at which the file may be found, whether or not it exists, from the
+    /// lowest precedence to the highest; the directories shared by all users first, then the
+    /// user's local and roaming directories, and last any explicit file.
+    ///
+    pub fn candidates(&self) -> Vec<PathBuf> {
+        crate::config_search_dirs_for(&self.app)
+            .into_iter()
+            .rev()
+            .map(|dir| dir.join(&self.file_name))
+            .chain(self.explicit.clone())
+            .collect()
+    }
+
+    ///
+    /// Returns the existing files among the [`candidates`](#method.candidates), from the lowest
+    /// precedence to the highest. An explicit file must exist; if it does not, an error with the
+    /// kind `NotFound` is returned, as the user asked for settings that cannot be read.
+    ///
+    pub fn resolve(&self) -> io::Result<Vec<PathBuf>> {
+        if let Some(explicit) = self.explicit.as_ref().filter(|path| !path.is_file()) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("configuration file {:?} does not exist", explicit),
+            ));
+        }
+        Ok(self
+            .candidates()
+            .into_iter()
+            .filter(|path| path.is_file())
+            .collect())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+
+    #[test]
+    fn test_config_resolver() {
+        let root = std::env::temp_dir().join(format!("xdirs-resolver-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let resolver = ConfigResolver::new("Chrome", "settings.toml");
+        let candidates = resolver.candidates();
+        let user = crate::config_dir_for("Chrome")
+            .unwrap()
+            .join("settings.toml");
+        assert_eq!(candidates.last(), Some(&user));
+        assert!(resolver.resolve().unwrap().is_empty());
+
+        let system = candidates.first().unwrap().clone();
+        for file in [&system, &user] {
+            create_dir_all(file.parent().unwrap()).unwrap();
+            write(file, "theme = 1\n").unwrap();
+        }
+        let explicit = root.join("explicit.toml");
+        let resolver = resolver.with_override(Some(&explicit));
+        assert_eq!(
+            resolver.resolve().unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+        write(&explicit, "theme = 2\n").unwrap();
+        assert_eq!(resolver.resolve().unwrap(), vec![system, user, explicit]);
+        remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+/*!
+Provides the generation, from a build script, of compile-time constants for each platform's
+conventional locations relative to the home directory and file system root, such as
+`Library/Logs` or `AppData\Local`, so that installer manifests and packaging scripts built by the
+same crate stay in step with the directories resolved at run time.
+
+The build script, with this crate as a build dependency, writes the constants for the platform
+being built for:
+
+```rust,no_run
+// in the main function of build.rs
+xdirs::write_consts().unwrap();
+```
+
+and the crate includes them wherever they are needed:
+
+```rust,ignore
+mod dirs {
+    xdirs::consts!();
+}
+
+// ".cache" when built for Linux, and "AppData\\Local" for Windows.
+println!("{}", dirs::HOME_CACHE);
+```
+*/
+
+use crate::{DirKind, Platform};
+use std::fmt::Write as _;
+use std::io;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Includes the constants written by [`write_consts`](fn.write_consts.html) in the build script
+/// of the calling crate; see [`path_consts`](fn.path_consts.html) for their names.
+///
+#[macro_export]
+macro_rules! consts {
+    () => {
+        include!(concat!(env!("OUT_DIR"), "/xdirs_consts.rs"));
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns Rust source declaring a `&str` constant for each kind of directory that `platform`
+/// places, by default, beneath the home directory or the file system root, with components
+/// separated by the platform's separator. Constants relative to the home directory are named
+/// `HOME_` followed by the upper-case [`DirKind::name`](enum.DirKind.html#method.name), such as
+/// `HOME_CONFIG_LOCAL`, and those relative to the root `SYSTEM_`, such as `SYSTEM_APPLICATION`.
+///
+/// ```rust
+/// use xdirs::{path_consts, Platform};
+///
+/// let source = path_consts(Platform::MacOS);
+/// assert!(source.contains(r#"pub const HOME_LOG: &str = "Library/Logs";"#));
+/// ```
+///
+pub fn path_consts(platform: Platform) -> String {
+    let mut source = String::new();
+    for kind in DirKind::all() {
+        let dirs = [
+            ("HOME", platform.home_relative_dir(kind)),
+            ("SYSTEM", platform.system_relative_dir(kind)),
+        ];
+        for (prefix, dir) in dirs.iter().filter_map(|(p, d)| d.as_ref().map(|d| (p, d))) {
+            let _ = writeln!(
+                source,
+                "pub const {}_{}: &str = {:?};",
+                prefix,
+                kind.name().to_uppercase(),
+                dir
+            );
+        }
+    }
+    source
+}
+
+///
+/// Write the constants returned by [`path_consts`](fn.path_consts.html), for the platform of the
+/// target being built, to `xdirs_consts.rs` in the build's output directory, from which they are
+/// included by [`consts!`](macro.consts.html). This is intended to be called from a build script,
+/// and returns an error with the kind `NotFound` if `OUT_DIR` is not set, or `Unsupported` if
+/// this crate does not support the target's operating system, so that the build fails rather
+/// than writing another platform's locations.
+///
+pub fn write_consts() -> io::Result<()> {
+    let out_dir = std::env::var_os(V_OUT_DIR)
+        .map(PathBuf::from)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "OUT_DIR is not set"))?;
+    let os = std::env::var(V_TARGET_OS).unwrap_or_else(|_| std::env::consts::OS.to_string());
+    let platform = Platform::for_target_os(&os).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("the target operating system {:?} is not supported", os),
+        )
+    })?;
+    std::fs::write(out_dir.join(F_CONSTS), path_consts(platform))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const F_CONSTS: &str = "xdirs_consts.rs";
+const V_OUT_DIR: &str = "OUT_DIR";
+const V_TARGET_OS: &str = "CARGO_CFG_TARGET_OS";
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_consts() {
+        assert_eq!(
+            Platform::Windows.home_relative_dir(DirKind::DataLocal),
+            Some("AppData\\Local".to_string())
+        );
+        assert_eq!(Platform::Linux.system_relative_dir(DirKind::Cache), None);
+        assert_eq!(Platform::for_target_os("ios"), Some(Platform::MacOS));
+        assert_eq!(Platform::for_target_os("freebsd"), Some(Platform::Linux));
+        assert_eq!(Platform::for_target_os("vxworks"), None);
+        assert_eq!(
+            Platform::for_target_os(std::env::consts::OS),
+            Some(Platform::current())
+        );
+
+        let source = path_consts(Platform::Windows);
+        assert!(source.contains(r#"pub const HOME_DATA_LOCAL: &str = "AppData\\Local";"#));
+        assert!(source.contains(r#"pub const SYSTEM_APPLICATION: &str = "Program Files";"#));
+        assert!(!source.contains("SYSTEM_CACHE"));
+    }
+}
@@ -0,0 +1,80 @@
+/*!
+Provides the choice, for each resolution, between the platform's native layout and the XDG
+layout, so that a single program may use both.
+*/
+
+use crate::DirKind;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The layout convention followed by [`resolve`](fn.resolve.html).
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Convention {
+    /// The platform's own layout, such as `~/Library/Application Support` on macOS; this ignores
+    /// [`set_strict_xdg`](fn.set_strict_xdg.html).
+    Native,
+    /// The XDG layout, as on Linux, honoring the `XDG_*` environment variables if set, on every
+    /// platform. This applies to the configuration, data, cache, and log directories; all other
+    /// kinds follow the native layout.
+    Xdg,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the application-specific directory of kind `kind` for `app`, following `convention`
+/// rather than the process-wide mode set by [`set_strict_xdg`](fn.set_strict_xdg.html). This
+/// allows a hybrid application to keep its GUI data in the native location while sharing its
+/// configuration with command-line tools in the XDG location.
+///
+/// Environment overrides, such as `MYAPP_CONFIG_DIR`, and [`override_root`](fn.override_root.html)
+/// apply as for the `_for` functions; values cached by [`warm`](fn.warm.html) do not.
+///
+/// ```rust
+/// use xdirs::{resolve, Convention, DirKind};
+///
+/// let gui_data = resolve(DirKind::Data, "MyApp", Convention::Native);
+/// let shared_config = resolve(DirKind::Config, "MyApp", Convention::Xdg);
+/// if let Some(path) = shared_config {
+///     assert!(path.ends_with("MyApp"));
+/// }
+/// ```
+///
+pub fn resolve(kind: DirKind, app: &str, convention: Convention) -> Option<PathBuf> {
+    crate::resolve::convention_dir_for(kind, app, convention)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{xdg_base, BaseKind};
+
+    #[test]
+    fn test_resolve() {
+        let cache = xdg_base(BaseKind::CacheHome).value.unwrap();
+        assert_eq!(
+            resolve(DirKind::Cache, "Chrome", Convention::Xdg),
+            Some(cache.join("Chrome"))
+        );
+        assert_eq!(
+            resolve(DirKind::Favorites, "Chrome", Convention::Xdg),
+            resolve(DirKind::Favorites, "Chrome", Convention::Native)
+        );
+        #[cfg(not(target_os = "macos"))]
+        assert_eq!(
+            resolve(DirKind::Config, "Chrome", Convention::Native),
+            crate::config_dir_for("Chrome")
+        );
+    }
+}
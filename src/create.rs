@@ -0,0 +1,749 @@
+/*!
+Provides variants of the application-specific functions that also create the directory, and any
+missing parents, if it does not already exist.
+
+Directories that may hold sensitive content, such as caches, are created such that they are only
+accessible by the current user: with mode `0700` on Unix-like systems, and with a protected DACL
+granting access only to the current user on Windows. The same semantics are available for any
+directory via [`create_private_dir`](fn.create_private_dir.html).
+
+Each directory created, including any missing parents, is reported to the sink set with
+[`set_audit_sink`](fn.set_audit_sink.html).
+*/
+
+use crate::audit::{self, Operation};
+use crate::{DirKind, Error};
+use std::fs::{create_dir_all, DirBuilder};
+use std::io;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the generic form of the directory `kind`, creating it if necessary; for example, the
+/// per-user application directory on Windows may be a fallback location which does not yet
+/// exist.
+///
+/// Errors are reported as for [`create_dir_for`](fn.create_dir_for.html).
+///
+pub fn create_dir(kind: DirKind) -> io::Result<PathBuf> {
+    ensure(kind, kind.dir(), false, "create_dir")
+}
+
+///
+/// Returns the application-specific form of the directory `kind`, creating it if necessary. A
+/// kind for which [`DirKind::is_private`](enum.DirKind.html#method.is_private) is `true`, such as
+/// the cache directory, is created such that it is only accessible by the current user, as for
+/// [`create_private_dir_for`](fn.create_private_dir_for.html).
+///
+/// If the directory has no value on the current platform the returned error has the kind
+/// `NotFound`, and wraps an [`Error::Unavailable`](enum.Error.html#variant.Unavailable).
+///
+pub fn create_dir_for(kind: DirKind, app: &str) -> io::Result<PathBuf> {
+    ensure(kind, kind.dir_for(app), kind.is_private(), "create_dir_for")
+}
+
+///
+/// Returns the application-specific form of the directory `kind`, creating it if necessary, and
+/// ensuring that it is only accessible by the current user.
+///
+/// Errors are reported as for [`create_dir_for`](fn.create_dir_for.html).
+///
+pub fn create_private_dir_for(kind: DirKind, app: &str) -> io::Result<PathBuf> {
+    ensure(kind, kind.dir_for(app), true, "create_private_dir_for")
+}
+
+///
+/// Create the directory `path`, and any missing parents, and ensure that the directory itself
+/// is only accessible by the current user. Any parents created are given the default permissions.
+///
+/// If the directory already exists its permissions are replaced; this matches the requirements of
+/// the XDG base directory specification for `$XDG_RUNTIME_DIR`. If it is a symbolic link, or not a
+/// directory, an error is returned with the kind `AlreadyExists`.
+///
+pub fn create_private_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let path = path.as_ref();
+    let missing = audit::missing_dirs(path);
+    create_private(path)?;
+    record_created(&missing, None, "create_private_dir");
+    Ok(())
+}
+
+///
+/// Returns the path to the temporary directory for a specific application, creating it if
+/// necessary such that it is only accessible by the current user. See
+/// [`temp_dir_for`](fn.temp_dir_for.html).
+///
+/// As the temporary directory may be shared between users it is created without following an
+/// existing path. If an existing path is a symbolic link, or not a directory, an error is returned
+/// with the kind `AlreadyExists`. On Unix-like systems an existing directory is checked, not
+/// changed, before it is used; if it belongs to another user, or is accessible by other users, an
+/// error is returned with the kind `PermissionDenied`. On Windows, where the temporary directory
+/// belongs to the user, the DACL of an existing directory is replaced, as for
+/// [`create_private_dir`](fn.create_private_dir.html).
+///
+pub fn temp_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_exclusive(
+        available(crate::temp_dir_for(app), "temporary")?,
+        "temp_dir_for_create",
+    )
+}
+
+///
+/// Returns the path to the socket directory for a specific application, creating it if
+/// necessary such that it is only accessible by the current user. See
+/// [`socket_dir_for`](fn.socket_dir_for.html).
+///
+/// As a client trusts whatever is listening on a socket in this directory, an existing directory
+/// is checked, and errors are reported, as for [`temp_dir_for_create`](fn.temp_dir_for_create.html).
+///
+pub fn socket_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_exclusive(
+        available(crate::socket_dir_for(app), "socket")?,
+        "socket_dir_for_create",
+    )
+}
+
+///
+/// Returns the path to the lock file directory for a specific application, creating it if
+/// necessary such that it is only accessible by the current user. See
+/// [`lock_dir_for`](fn.lock_dir_for.html).
+///
+/// As another user able to write to this directory could hold, or remove, the application's
+/// locks, an existing directory is checked, and errors are reported, as for
+/// [`temp_dir_for_create`](fn.temp_dir_for_create.html). On macOS and Windows, where this is also
+/// the application's data directory, that directory must therefore not have been created with the
+/// default permissions.
+///
+pub fn lock_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_exclusive(
+        available(crate::lock_dir_for(app), "lock")?,
+        "lock_dir_for_create",
+    )
+}
+
+///
+/// Returns the path to the documents directory for a specific application, creating it if
+/// necessary. See [`document_dir_for`](fn.document_dir_for.html).
+///
+pub fn document_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    ensure_path(
+        available(crate::document_dir_for(app), "documents")?,
+        "document_dir_for_create",
+    )
+}
+
+///
+/// Returns the path to the downloads directory for a specific application, creating it if
+/// necessary. See [`download_dir_for`](fn.download_dir_for.html).
+///
+pub fn download_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    ensure_path(
+        available(crate::download_dir_for(app), "downloads")?,
+        "download_dir_for_create",
+    )
+}
+
+///
+/// Returns the path to the desktop directory for a specific application, creating it if
+/// necessary. See [`desktop_dir_for`](fn.desktop_dir_for.html).
+///
+pub fn desktop_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    ensure_path(
+        available(crate::desktop_dir_for(app), "desktop")?,
+        "desktop_dir_for_create",
+    )
+}
+
+///
+/// Returns the path to the public share directory for a specific application, creating it if
+/// necessary. See [`public_share_dir_for`](fn.public_share_dir_for.html).
+///
+pub fn public_share_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    ensure_path(
+        available(crate::public_share_dir_for(app), "public")?,
+        "public_share_dir_for_create",
+    )
+}
+
+///
+/// Returns the path to the backup directory for a specific application, creating it if
+/// necessary. See [`backup_dir_for`](fn.backup_dir_for.html).
+///
+pub fn backup_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    ensure_path(
+        available(crate::backup_dir_for(app), "backup")?,
+        "backup_dir_for_create",
+    )
+}
+
+///
+/// Returns the path to the secrets directory for a specific application, creating it if
+/// necessary, and ensuring that it is only accessible by the current user. See
+/// [`secrets_dir_for`](fn.secrets_dir_for.html).
+///
+pub fn secrets_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    let path = available(crate::secrets_dir_for(app), "secrets")?;
+    let missing = audit::missing_dirs(&path);
+    create_private(&path)?;
+    record_created(&missing, None, "secrets_dir_for_create");
+    Ok(path)
+}
+
+///
+/// Returns the path to the user's cache directory for a specific application, creating it if
+/// necessary such that it is only accessible by the current user. See
+/// [`cache_dir_for`](fn.cache_dir_for.html).
+///
+pub fn cache_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Cache, app)
+}
+
+///
+/// Returns the path to the user's config directory for a specific application, creating it if
+/// necessary. See [`config_dir_for`](fn.config_dir_for.html).
+///
+pub fn config_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Config, app)
+}
+
+///
+/// Returns the path to the user's local config directory for a specific application, creating
+/// it if necessary. See [`config_local_dir_for`](fn.config_local_dir_for.html).
+///
+pub fn config_local_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::ConfigLocal, app)
+}
+
+///
+/// Returns the path to the user's data directory for a specific application, creating it if
+/// necessary. See [`data_dir_for`](fn.data_dir_for.html).
+///
+pub fn data_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Data, app)
+}
+
+///
+/// Returns the path to the user's local data directory for a specific application, creating it if
+/// necessary. See [`data_local_dir_for`](fn.data_local_dir_for.html).
+///
+pub fn data_local_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::DataLocal, app)
+}
+
+///
+/// Returns the path to the installation directory for a specific application, creating it if
+/// necessary; this will usually require elevated privileges. See
+/// [`application_dir_for`](fn.application_dir_for.html).
+///
+pub fn application_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Application, app)
+}
+
+///
+/// Returns the path to the user's installation directory for a specific application, creating it
+/// if necessary. See [`user_application_dir_for`](fn.user_application_dir_for.html).
+///
+pub fn user_application_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::UserApplication, app)
+}
+
+///
+/// Returns the path to the user's favorites directory for a specific application, creating it if
+/// necessary. See [`favorites_dir_for`](fn.favorites_dir_for.html).
+///
+pub fn favorites_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Favorites, app)
+}
+
+///
+/// Returns the path to the user's log file directory for a specific application, creating it if
+/// necessary. See [`log_dir_for`](fn.log_dir_for.html).
+///
+pub fn log_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Log, app)
+}
+
+///
+/// Returns the path to the system-wide log directory for a specific application, creating it if
+/// necessary; this will usually require elevated privileges. See
+/// [`system_log_dir_for`](fn.system_log_dir_for.html).
+///
+pub fn system_log_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::SystemLog, app)
+}
+
+///
+/// Returns the path to the user's preference file directory for a specific application, creating
+/// it if necessary. See [`preference_dir_for`](fn.preference_dir_for.html).
+///
+pub fn preference_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Preference, app)
+}
+
+///
+/// Returns the path to the user's template directory for a specific application, creating it if
+/// necessary. See [`template_dir_for`](fn.template_dir_for.html).
+///
+pub fn template_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Template, app)
+}
+
+///
+/// Returns the path to the user's saved games directory for a specific application, creating it
+/// if necessary. See [`saved_games_dir_for`](fn.saved_games_dir_for.html).
+///
+pub fn saved_games_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::SavedGames, app)
+}
+
+///
+/// Returns the path to the user's icons directory for a specific application, creating it if
+/// necessary. See [`icons_dir_for`](fn.icons_dir_for.html).
+///
+pub fn icons_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Icons, app)
+}
+
+///
+/// Returns the path to the user's themes directory for a specific application, creating it if
+/// necessary. See [`themes_dir_for`](fn.themes_dir_for.html).
+///
+pub fn themes_dir_for_create(app: &str) -> io::Result<PathBuf> {
+    create_dir_for(DirKind::Themes, app)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Create `path`, an application directory of `kind` or one beneath it, and any missing parents;
+/// `private_root`, if given, is the directory at or above `path` that must only be accessible by
+/// the current user.
+///
+pub(crate) fn ensure_project_path(
+    path: &Path,
+    kind: Option<DirKind>,
+    private_root: Option<&Path>,
+) -> io::Result<()> {
+    let missing = audit::missing_dirs(path);
+    if let Some(root) = private_root {
+        create_private(root)?;
+    }
+    create_dir_all(path)?;
+    record_created(&missing, kind, "ProjectPath::ensure");
+    Ok(())
+}
+
+///
+/// Create `path`, the directory `kind`, and any missing parents, such that it is only accessible
+/// by the current user if `private`; if there is no `path` a `NotFound` error wrapping
+/// `Error::Unavailable` is returned.
+///
+pub(crate) fn ensure(
+    kind: DirKind,
+    path: Option<PathBuf>,
+    private: bool,
+    reason: &'static str,
+) -> io::Result<PathBuf> {
+    match path {
+        Some(path) => {
+            let missing = audit::missing_dirs(&path);
+            if private {
+                create_private(&path)?;
+            } else {
+                create_dir_all(&path)?;
+            }
+            record_created(&missing, Some(kind), reason);
+            Ok(path)
+        }
+        None => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            Error::Unavailable(kind),
+        )),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(unix)]
+const PRIVATE_MODE: u32 = 0o700;
+
+fn ensure_path(path: PathBuf, reason: &'static str) -> io::Result<PathBuf> {
+    let missing = audit::missing_dirs(&path);
+    create_dir_all(&path)?;
+    record_created(&missing, None, reason);
+    Ok(path)
+}
+
+///
+/// Returns `path`, or a `NotFound` error naming the `name` directory if there is none; this is
+/// the equivalent of `Error::Unavailable` for those directories that have no `DirKind`.
+///
+///
+/// Create `path`, without following an existing path, such that it is only accessible by the
+/// current user; an existing directory is checked by `check_private`.
+///
+fn create_exclusive(path: PathBuf, reason: &'static str) -> io::Result<PathBuf> {
+    let missing = audit::missing_dirs(&path);
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    match private_builder().create(&path) {
+        Ok(()) => restrict_to_owner(&path)?,
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => check_private(&path)?,
+        Err(e) => return Err(e),
+    }
+    record_created(&missing, None, reason);
+    Ok(path)
+}
+
+fn available(path: Option<PathBuf>, name: &str) -> io::Result<PathBuf> {
+    path.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no {} directory", name)))
+}
+
+fn create_private(path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    match private_builder().create(path) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            // the permissions of a symbolic link's target, which may be anywhere, are not changed.
+            let _ = check_directory(path)?;
+        }
+        Err(e) => return Err(e),
+    }
+    restrict_to_owner(path)
+}
+
+///
+/// Returns a builder that creates a single directory, with mode `0700` on Unix-like systems.
+///
+fn private_builder() -> DirBuilder {
+    #[allow(unused_mut)]
+    let mut builder = DirBuilder::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(PRIVATE_MODE);
+    }
+    builder
+}
+
+///
+/// Returns an error unless the existing `path` is a directory, not a symbolic link, that is owned
+/// by the effective user and has the mode `0700`.
+///
+#[cfg(unix)]
+fn check_private(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::MetadataExt;
+    let metadata = check_directory(path)?;
+    if metadata.uid() != unsafe { libc::geteuid() } {
+        Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "directory belongs to another user",
+        ))
+    } else if metadata.mode() & 0o777 != PRIVATE_MODE {
+        Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "directory is accessible by other users",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+///
+/// Returns an error unless the existing `path` is a directory, not a symbolic link, and restricts
+/// it to the current user; its owner is not checked.
+///
+#[cfg(not(unix))]
+fn check_private(path: &Path) -> io::Result<()> {
+    let _ = check_directory(path)?;
+    restrict_to_owner(path)
+}
+
+fn check_directory(path: &Path) -> io::Result<std::fs::Metadata> {
+    let metadata = std::fs::symlink_metadata(path)?;
+    if metadata.file_type().is_symlink() {
+        Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "directory is a symbolic link",
+        ))
+    } else if !metadata.is_dir() {
+        Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "path is not a directory",
+        ))
+    } else {
+        Ok(metadata)
+    }
+}
+
+fn record_created(created: &[PathBuf], kind: Option<DirKind>, reason: &'static str) {
+    for path in created {
+        audit::record(Operation::Create, path, kind, reason);
+    }
+}
+
+#[cfg(unix)]
+fn restrict_to_owner(path: &Path) -> io::Result<()> {
+    use std::fs::{set_permissions, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+    set_permissions(path, Permissions::from_mode(PRIVATE_MODE))
+}
+
+///
+/// Replace the DACL on `path` with one that grants full control to the current user only, and
+/// that is protected from inheriting entries from its parent.
+///
+#[cfg(windows)]
+fn restrict_to_owner(path: &Path) -> io::Result<()> {
+    use std::os::windows::ffi::OsStrExt;
+    use std::ptr::null_mut;
+    use windows_sys::Win32::Foundation::{
+        CloseHandle, LocalFree, ERROR_SUCCESS, GENERIC_ALL, HANDLE,
+    };
+    use windows_sys::Win32::Security::Authorization::{
+        SetEntriesInAclW, SetNamedSecurityInfoW, EXPLICIT_ACCESS_W, NO_MULTIPLE_TRUSTEE,
+        SET_ACCESS, SE_FILE_OBJECT, TRUSTEE_IS_SID, TRUSTEE_IS_USER, TRUSTEE_W,
+    };
+    use windows_sys::Win32::Security::{
+        GetTokenInformation, TokenUser, ACL, DACL_SECURITY_INFORMATION,
+        PROTECTED_DACL_SECURITY_INFORMATION, SUB_CONTAINERS_AND_OBJECTS_INHERIT, TOKEN_QUERY,
+        TOKEN_USER,
+    };
+    use windows_sys::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    unsafe {
+        let mut token: HANDLE = null_mut();
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let mut length = 0;
+        let _ = GetTokenInformation(token, TokenUser, null_mut(), 0, &mut length);
+        // a u64 buffer ensures the TOKEN_USER header is suitably aligned.
+        let mut buffer = vec![0u64; (length as usize + 7) / 8];
+        let result = GetTokenInformation(
+            token,
+            TokenUser,
+            buffer.as_mut_ptr() as *mut _,
+            length,
+            &mut length,
+        );
+        let _ = CloseHandle(token);
+        if result == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let user = &*(buffer.as_ptr() as *const TOKEN_USER);
+
+        let access = EXPLICIT_ACCESS_W {
+            grfAccessPermissions: GENERIC_ALL,
+            grfAccessMode: SET_ACCESS,
+            grfInheritance: SUB_CONTAINERS_AND_OBJECTS_INHERIT,
+            Trustee: TRUSTEE_W {
+                pMultipleTrustee: null_mut(),
+                MultipleTrusteeOperation: NO_MULTIPLE_TRUSTEE,
+                TrusteeForm: TRUSTEE_IS_SID,
+                TrusteeType: TRUSTEE_IS_USER,
+                ptstrName: user.User.Sid as *mut _,
+            },
+        };
+        let mut acl: *mut ACL = null_mut();
+        let result = SetEntriesInAclW(1, &access, null_mut(), &mut acl);
+        if result != ERROR_SUCCESS {
+            return Err(io::Error::from_raw_os_error(result as i32));
+        }
+        let result = SetNamedSecurityInfoW(
+            wide.as_ptr(),
+            SE_FILE_OBJECT,
+            DACL_SECURITY_INFORMATION | PROTECTED_DACL_SECURITY_INFORMATION,
+            null_mut(),
+            null_mut(),
+            acl,
+            null_mut(),
+        );
+        let _ = LocalFree(acl as *mut _);
+        if result != ERROR_SUCCESS {
+            return Err(io::Error::from_raw_os_error(result as i32));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(any(unix, windows)))]
+fn restrict_to_owner(_: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::remove_dir_all;
+
+    #[test]
+    fn test_ensure_creates_parents() {
+        let root = std::env::temp_dir().join(format!("xdirs-create-{}", std::process::id()));
+        let path = root.join("a/b/c");
+        assert_eq!(
+            ensure(DirKind::Cache, Some(path.clone()), false, "test").unwrap(),
+            path
+        );
+        assert!(path.is_dir());
+        // a second call succeeds on the existing directory.
+        assert_eq!(
+            ensure(DirKind::Cache, Some(path.clone()), false, "test").unwrap(),
+            path
+        );
+        remove_dir_all(root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_create_private_dir() {
+        use std::fs::{metadata, set_permissions, Permissions};
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = |path: &Path| metadata(path).unwrap().permissions().mode() & 0o777;
+        let root = std::env::temp_dir().join(format!("xdirs-private-{}", std::process::id()));
+        let path = root.join("a/secret");
+        create_private_dir(&path).unwrap();
+        assert_eq!(mode(&path), 0o700);
+
+        // an existing directory has its permissions restricted.
+        set_permissions(&path, Permissions::from_mode(0o755)).unwrap();
+        create_private_dir(&path).unwrap();
+        assert_eq!(mode(&path), 0o700);
+
+        // a symbolic link is rejected, and its target left unchanged.
+        let target = root.join("target");
+        let link = root.join("a/link");
+        std::fs::create_dir(&target).unwrap();
+        set_permissions(&target, Permissions::from_mode(0o755)).unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+        let error = create_private_dir(&link).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(mode(&target), 0o755);
+        remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_create_dir_unavailable() {
+        let error = create_dir(DirKind::AppContainer).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_ensure_unavailable() {
+        let error = ensure(DirKind::Log, None, false, "test").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_cache_dir_for_create() {
+        let root = std::env::temp_dir().join(format!("xdirs-create-cache-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let path = cache_dir_for_create("Chrome").unwrap();
+        assert_eq!(Some(path.clone()), crate::cache_dir_for("Chrome"));
+        assert!(path.starts_with(&root) && path.is_dir());
+        remove_dir_all(root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_secrets_dir_for_create() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root =
+            std::env::temp_dir().join(format!("xdirs-create-secrets-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let path = secrets_dir_for_create("Chrome").unwrap();
+        assert_eq!(Some(path.clone()), crate::secrets_dir_for("Chrome"));
+        assert!(path.starts_with(&root));
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o700
+        );
+        remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_temp_dir_for_create() {
+        let app = format!("xdirs-temp-{}", std::process::id());
+        let path = temp_dir_for_create(&app).unwrap();
+        assert_eq!(Some(path.clone()), crate::temp_dir_for(&app));
+        assert!(path.is_dir());
+        remove_dir_all(path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_temp_dir_for_create_symlink() {
+        let app = format!("xdirs-temp-link-{}", std::process::id());
+        let path = crate::temp_dir_for(&app).unwrap();
+        std::os::unix::fs::symlink(std::env::temp_dir(), &path).unwrap();
+        let error = temp_dir_for_create(&app).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_temp_dir_for_create_mode() {
+        use std::fs::{metadata, set_permissions, Permissions};
+        use std::os::unix::fs::PermissionsExt;
+
+        let app = format!("xdirs-temp-mode-{}", std::process::id());
+        let path = temp_dir_for_create(&app).unwrap();
+        assert_eq!(metadata(&path).unwrap().permissions().mode() & 0o777, 0o700);
+        set_permissions(&path, Permissions::from_mode(0o755)).unwrap();
+        let error = temp_dir_for_create(&app).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        // the existing directory is left unchanged.
+        assert_eq!(metadata(&path).unwrap().permissions().mode() & 0o777, 0o755);
+        remove_dir_all(path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_socket_and_lock_dir_for_create() {
+        use std::fs::{metadata, set_permissions, Permissions};
+        use std::os::unix::fs::PermissionsExt;
+
+        let root =
+            std::env::temp_dir().join(format!("xdirs-create-runtime-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        // on Linux these are the same directory, and so each is removed once checked.
+        for create in [socket_dir_for_create, lock_dir_for_create] {
+            let path = create("Chrome").unwrap();
+            assert!(path.starts_with(&root));
+            assert_eq!(metadata(&path).unwrap().permissions().mode() & 0o777, 0o700);
+            set_permissions(&path, Permissions::from_mode(0o755)).unwrap();
+            assert_eq!(
+                create("Chrome").unwrap_err().kind(),
+                io::ErrorKind::PermissionDenied
+            );
+            remove_dir_all(path).unwrap();
+        }
+        assert_eq!(
+            socket_dir_for_create("Chrome").ok(),
+            crate::socket_dir_for("Chrome")
+        );
+        assert_eq!(
+            lock_dir_for_create("Chrome").ok(),
+            crate::lock_dir_for("Chrome")
+        );
+        remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+/*!
+Provides the traditional location of an application's files, a hidden directory in the user's
+home directory, so that migration tooling can find data written before the application adopted
+the platform's directories.
+*/
+
+use crate::naming::file_stem;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path to the traditional hidden directory of a specific application, in the home
+/// directory returned by [`home_dir`](fn.home_dir.html); this is the application name in
+/// lower-case, with words separated by dashes, following a period. This is where applications
+/// kept their files before the XDG base directories and their equivalents, and is intended for
+/// tools that migrate those files to the directories returned by this crate; whether the
+/// directory exists is not checked.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$HOME`/.{app}                           | /home/alice/.my-app                     |
+/// | macOS   | `$HOME`/.{app}                           | /Users/Alice/.my-app                    |
+/// | Windows | `{FOLDERID_Profile}`\\.{app}             | C:\Users\Alice\\.my-app                 |
+///
+/// ```rust
+/// use xdirs::{config_dir_for, dot_dir_for};
+///
+/// if let (Some(old), Some(new)) = (dot_dir_for("My App"), config_dir_for("My App")) {
+///     if old.is_dir() && !new.exists() {
+///         println!("migrating {:?} to {:?}", old, new);
+///     }
+/// }
+/// ```
+///
+pub fn dot_dir_for(app: &str) -> Option<PathBuf> {
+    crate::home_dir().map(|home| home.join(dot_name(app)))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn dot_name(app: &str) -> String {
+    format!(".{}", file_stem(app).trim_start_matches('.'))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_name() {
+        assert_eq!(dot_name("Chrome"), ".chrome");
+        assert_eq!(dot_name("My App"), ".my-app");
+        assert_eq!(dot_name(".vim"), ".vim");
+    }
+
+    #[test]
+    fn test_dot_dir_for() {
+        assert_eq!(
+            dot_dir_for("Chrome"),
+            crate::home_dir().map(|home| home.join(".chrome"))
+        );
+    }
+}
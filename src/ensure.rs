@@ -0,0 +1,159 @@
+/*!
+Provides asynchronous variants of the directory-creation functions, for applications running on
+the Tokio runtime. Creating a directory, and checking its parents, may block for some time, for
+example where the home directory is on a network share; these functions perform the work on the
+runtime's blocking thread pool, as `tokio::fs` does, so that the runtime's worker threads are not
+held up during start-up.
+
+Directories are resolved on the calling task, and so are subject to any
+[`override_root`](fn.override_root.html) of its thread, and are then created exactly as by the
+corresponding functions of the **create** feature, with the same permissions, audit records, and
+errors.
+*/
+
+use crate::{create, DirKind};
+use std::io;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the generic form of the directory `kind`, creating it if necessary; see
+/// [`create_dir`](fn.create_dir.html).
+///
+pub async fn ensure_dir(kind: DirKind) -> io::Result<PathBuf> {
+    let path = kind.dir();
+    blocking(move || create::ensure(kind, path, false, "ensure_dir")).await
+}
+
+///
+/// Returns the application-specific form of the directory `kind`, creating it if necessary; see
+/// [`create_dir_for`](fn.create_dir_for.html).
+///
+/// ```rust
+/// use xdirs::{ensure_dir_for, DirKind};
+///
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// match ensure_dir_for(DirKind::Config, "MyApp").await {
+///     Ok(path) => println!("configuration in {:?}", path),
+///     Err(e) => eprintln!("no configuration directory: {}", e),
+/// }
+/// # });
+/// ```
+///
+pub async fn ensure_dir_for(kind: DirKind, app: &str) -> io::Result<PathBuf> {
+    let path = kind.dir_for(app);
+    blocking(move || create::ensure(kind, path, kind.is_private(), "ensure_dir_for")).await
+}
+
+///
+/// Returns the application-specific form of the directory `kind`, creating it if necessary, and
+/// ensuring that it is only accessible by the current user; see
+/// [`create_private_dir_for`](fn.create_private_dir_for.html).
+///
+pub async fn ensure_private_dir_for(kind: DirKind, app: &str) -> io::Result<PathBuf> {
+    let path = kind.dir_for(app);
+    blocking(move || create::ensure(kind, path, true, "ensure_private_dir_for")).await
+}
+
+///
+/// Returns the path to the user's cache directory for a specific application, creating it if
+/// necessary such that it is only accessible by the current user. See
+/// [`cache_dir_for_create`](fn.cache_dir_for_create.html).
+///
+pub async fn ensure_cache_dir_for(app: &str) -> io::Result<PathBuf> {
+    ensure_dir_for(DirKind::Cache, app).await
+}
+
+///
+/// Returns the path to the user's config directory for a specific application, creating it if
+/// necessary. See [`config_dir_for_create`](fn.config_dir_for_create.html).
+///
+pub async fn ensure_config_dir_for(app: &str) -> io::Result<PathBuf> {
+    ensure_dir_for(DirKind::Config, app).await
+}
+
+///
+/// Returns the path to the user's local config directory for a specific application, creating
+/// it if necessary. See [`config_local_dir_for_create`](fn.config_local_dir_for_create.html).
+///
+pub async fn ensure_config_local_dir_for(app: &str) -> io::Result<PathBuf> {
+    ensure_dir_for(DirKind::ConfigLocal, app).await
+}
+
+///
+/// Returns the path to the user's data directory for a specific application, creating it if
+/// necessary. See [`data_dir_for_create`](fn.data_dir_for_create.html).
+///
+pub async fn ensure_data_dir_for(app: &str) -> io::Result<PathBuf> {
+    ensure_dir_for(DirKind::Data, app).await
+}
+
+///
+/// Returns the path to the user's local data directory for a specific application, creating it
+/// if necessary. See [`data_local_dir_for_create`](fn.data_local_dir_for_create.html).
+///
+pub async fn ensure_data_local_dir_for(app: &str) -> io::Result<PathBuf> {
+    ensure_dir_for(DirKind::DataLocal, app).await
+}
+
+///
+/// Returns the path to the user's log file directory for a specific application, creating it if
+/// necessary. See [`log_dir_for_create`](fn.log_dir_for_create.html).
+///
+pub async fn ensure_log_dir_for(app: &str) -> io::Result<PathBuf> {
+    ensure_dir_for(DirKind::Log, app).await
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Run `f` on the runtime's blocking thread pool; a panic in `f` is resumed in the caller.
+///
+async fn blocking<F>(f: F) -> io::Result<PathBuf>
+where
+    F: FnOnce() -> io::Result<PathBuf> + Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => Err(io::Error::new(io::ErrorKind::Other, e)),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ensure_dir_for() {
+        let root = std::env::temp_dir().join(format!("xdirs-ensure-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let expected = crate::config_dir_for("Chrome");
+            match ensure_config_dir_for("Chrome").await {
+                Ok(path) => {
+                    assert_eq!(Some(path.clone()), expected);
+                    assert!(path.is_dir());
+                }
+                Err(e) => {
+                    assert!(expected.is_none());
+                    assert_eq!(e.kind(), io::ErrorKind::NotFound);
+                }
+            }
+        });
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
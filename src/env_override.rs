@@ -0,0 +1,149 @@
+/*!
+Provides an environment-variable override layer for the application-specific functions.
+
+Before resolving the platform path for an application, each `_for` function checks for an
+environment variable named `{PREFIX}_{KIND}_DIR`, where `KIND` is the upper-case name of the
+[`DirKind`](enum.DirKind.html), and `PREFIX` is by default derived from the application name by
+upper-casing it and replacing any character that is not ASCII alphanumeric with `_`. For example,
+the configuration directory for `my-app` may be redirected by setting `MY_APP_CONFIG_DIR`.
+
+The value is used as-is, the application name is not appended. As with the XDG base directory
+specification, values that are empty or that are not absolute paths are ignored.
+
+The prefix for an application may be changed, or the override disabled, with
+[`set_env_prefix`](fn.set_env_prefix.html).
+*/
+
+use crate::DirKind;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Set the prefix used to construct override environment variable names for the application
+/// `app`; for example, a prefix of `XDIRS` results in variables such as `XDIRS_CONFIG_DIR`. A
+/// prefix of `None` disables environment overrides for the application.
+///
+pub fn set_env_prefix(app: &str, prefix: Option<&str>) {
+    let mut prefixes = match prefixes().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let _ = prefixes.insert(app.to_string(), prefix.map(str::to_string));
+}
+
+///
+/// Returns the name of the environment variable that overrides the directory `kind` for the
+/// application `app`, or `None` if overrides have been disabled for the application.
+///
+pub fn env_override_var(kind: DirKind, app: &str) -> Option<String> {
+    let prefixes = match prefixes().read() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let prefix = match prefixes.get(app) {
+        Some(Some(prefix)) => prefix.clone(),
+        Some(None) => return None,
+        None => default_prefix(app),
+    };
+    Some(format!(
+        "{}_{}_DIR",
+        prefix,
+        kind.name().to_ascii_uppercase()
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the value of the override environment variable for `kind` and `app`, if one is set
+/// and valid.
+///
+pub(crate) fn dir_for(kind: DirKind, app: &str) -> Option<PathBuf> {
+    env_override_var(kind, app)
+        .and_then(|var| crate::environment::var_os(&var))
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn prefixes() -> &'static RwLock<HashMap<String, Option<String>>> {
+    static PREFIXES: OnceLock<RwLock<HashMap<String, Option<String>>>> = OnceLock::new();
+    PREFIXES.get_or_init(Default::default)
+}
+
+fn default_prefix(app: &str) -> String {
+    app.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::EnvGuard;
+
+    #[test]
+    fn test_default_var_name() {
+        assert_eq!(
+            env_override_var(DirKind::DataLocal, "my-app 2"),
+            Some(String::from("MY_APP_2_DATA_LOCAL_DIR"))
+        );
+    }
+
+    #[test]
+    fn test_override() {
+        let mut env = EnvGuard::lock();
+        let path = std::env::temp_dir().join("xdirs-env-override");
+        env.set("XDIRS_ENV_TEST_1_CONFIG_DIR", &path);
+        crate::reload_env();
+        assert_eq!(crate::config_dir_for("xdirs-env-test-1"), Some(path));
+        assert_ne!(
+            crate::cache_dir_for("xdirs-env-test-1"),
+            crate::config_dir_for("xdirs-env-test-1")
+        );
+    }
+
+    #[test]
+    fn test_relative_override_ignored() {
+        let mut env = EnvGuard::lock();
+        env.set("XDIRS_ENV_TEST_2_CACHE_DIR", "relative/cache");
+        crate::reload_env();
+        assert_eq!(dir_for(DirKind::Cache, "xdirs-env-test-2"), None);
+    }
+
+    #[test]
+    fn test_custom_prefix() {
+        let mut env = EnvGuard::lock();
+        let path = std::env::temp_dir().join("xdirs-env-prefix");
+        set_env_prefix("xdirs-env-test-3", Some("XDIRS_ENV_TEST_PREFIX"));
+        env.set("XDIRS_ENV_TEST_PREFIX_LOG_DIR", &path);
+        crate::reload_env();
+        assert_eq!(crate::log_dir_for("xdirs-env-test-3"), Some(path));
+        set_env_prefix("xdirs-env-test-3", None);
+        assert_eq!(env_override_var(DirKind::Log, "xdirs-env-test-3"), None);
+        assert_ne!(
+            crate::log_dir_for("xdirs-env-test-3"),
+            dir_for(DirKind::Log, "xdirs-env-test-3")
+        );
+    }
+}
@@ -0,0 +1,373 @@
+/*!
+Provides the snapshot of the process environment from which all directories are resolved.
+
+Reading the environment while another thread modifies it, with `std::env::set_var`, is a data
+race on most platforms, and may corrupt or crash the process. This crate therefore reads the
+environment once, on first use, and resolves every directory from that snapshot; the snapshot is
+only taken again when the application asks for it, with [`reload_env`](fn.reload_env.html), at a
+point where it knows that no other thread is modifying the environment.
+
+Directories may also be resolved against an explicit [`Environment`](struct.Environment.html),
+with [`resolve_in`](fn.resolve_in.html), which reads neither the process environment nor the
+snapshot, so that path logic may be tested hermetically.
+*/
+
+use crate::naming::naming;
+use crate::nesting::nesting_scheme;
+use crate::user::HomeProvider;
+use crate::{env_override_var, DirKind, Platform};
+use std::collections::HashMap;
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An explicit environment against which directories are resolved by
+/// [`resolve_in`](fn.resolve_in.html): the user's home directory and the environment variables
+/// that relocate directories, such as `XDG_CONFIG_HOME` or `MYAPP_CONFIG_DIR`.
+///
+/// ```rust
+/// use xdirs::{resolve_in, DirKind, Environment};
+///
+/// let env = Environment::new("/home/alice").with_var("XDG_CONFIG_HOME", "/tmp/config");
+/// let config = resolve_in(&env, DirKind::Config, "MyApp");
+/// if cfg!(target_os = "linux") {
+///     assert_eq!(config, Some("/tmp/config/MyApp".into()));
+/// }
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Environment {
+    /// The user's home directory.
+    pub home: PathBuf,
+    /// The environment variables, by name.
+    pub vars: HashMap<String, OsString>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the application-specific form of the directory `kind` for `app`, resolved only
+/// against `env`; the process environment, the crate's snapshot of it, and any root set by
+/// [`override_root`](fn.override_root.html) are not used. The directory is taken from the
+/// application's override variable, as named by [`env_override_var`](fn.env_override_var.html),
+/// then the platform's variables that relocate the generic directories, and otherwise from the
+/// platform's default locations beneath `env.home`. Only absolute values of variables are used.
+///
+/// The application's naming policy and nesting scheme apply as for
+/// [`DirKind::dir_for`](enum.DirKind.html#method.dir_for); Known Folder redirection, on
+/// Windows, and the user's `user-dirs.dirs`, on Linux, are not consulted.
+///
+pub fn resolve_in(env: &Environment, kind: DirKind, app: &str) -> Option<PathBuf> {
+    let overridden = env_override_var(kind, app)
+        .and_then(|var| env.vars.get(&var))
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute());
+    if overridden.is_some() {
+        return overridden;
+    }
+    Platform::current().named_dir_for(
+        &HomeProvider::new(&env.home, Some(&env.vars)),
+        kind,
+        app,
+        &naming(app),
+        nesting_scheme(app),
+    )
+}
+
+///
+/// Take a new snapshot of the process environment, from which all subsequent resolution is done.
+/// An application that modifies variables used by this crate, such as `XDG_CONFIG_HOME` or an
+/// override such as `MYAPP_CONFIG_DIR`, must call this function, while no other thread is
+/// modifying the environment, for the change to be seen.
+///
+/// The generic directories are also resolved once and reused; [`refresh`](fn.refresh.html)
+/// calls this function and also discards those.
+///
+/// ```rust
+/// xdirs::reload_env();
+/// ```
+///
+pub fn reload_env() {
+    let mut snapshot = match snapshot().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    *snapshot = capture();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Environment {
+    ///
+    /// Construct an environment with the home directory `home`, and no variables.
+    ///
+    pub fn new<P: Into<PathBuf>>(home: P) -> Self {
+        Self {
+            home: home.into(),
+            vars: Default::default(),
+        }
+    }
+
+    ///
+    /// Construct an environment from the current user's home directory and the crate's snapshot
+    /// of the process environment; variables whose names are not valid Unicode are omitted.
+    /// This is `None` if the home directory cannot be found.
+    ///
+    pub fn current() -> Option<Self> {
+        let home = crate::sys::home_dir()?;
+        let snapshot = match snapshot().read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let vars = snapshot
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_str()?.to_string(), value.clone())))
+            .collect();
+        Some(Self { home, vars })
+    }
+
+    ///
+    /// Set the variable `name` to `value`, returning this environment.
+    ///
+    pub fn with_var<V: Into<OsString>>(mut self, name: &str, value: V) -> Self {
+        let _ = self.vars.insert(name.to_string(), value.into());
+        self
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the value of the environment variable `name` in the snapshot, as for
+/// `std::env::var_os`.
+///
+pub(crate) fn var_os(name: &str) -> Option<OsString> {
+    let snapshot = match snapshot().read() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    snapshot.get(&key(OsStr::new(name))).cloned()
+}
+
+///
+/// Returns the value of the environment variable `name` in the snapshot, if it is set, valid
+/// Unicode, and not empty.
+///
+pub(crate) fn non_empty_var(name: &str) -> Option<String> {
+    var_os(name)
+        .and_then(|value| value.into_string().ok())
+        .filter(|value| !value.is_empty())
+}
+
+///
+/// Returns the user's home directory, from `$HOME` in the snapshot if it is set to an absolute
+/// path; otherwise as given by the [`HomeFallback`](../enum.HomeFallback.html) strategy, which
+/// by default, as `dirs-next` does, looks in the user database.
+///
+#[cfg(not(any(windows, target_arch = "wasm32")))]
+pub fn home_dir() -> Option<PathBuf> {
+    var_os(E_HOME)
+        .map(PathBuf::from)
+        .filter(|home| home.is_absolute())
+        .or_else(|| crate::home_fallback::fallback_home(user_database_home))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(not(any(windows, target_arch = "wasm32")))]
+const E_HOME: &str = "HOME";
+
+#[cfg(unix)]
+fn user_database_home() -> Option<PathBuf> {
+    crate::passwd::current_user_home()
+}
+
+#[cfg(not(any(unix, windows, target_arch = "wasm32")))]
+fn user_database_home() -> Option<PathBuf> {
+    None
+}
+
+fn snapshot() -> &'static RwLock<HashMap<OsString, OsString>> {
+    static SNAPSHOT: OnceLock<RwLock<HashMap<OsString, OsString>>> = OnceLock::new();
+    SNAPSHOT.get_or_init(|| RwLock::new(capture()))
+}
+
+fn capture() -> HashMap<OsString, OsString> {
+    env::vars_os()
+        .map(|(name, value)| (key(&name), value))
+        .collect()
+}
+
+///
+/// The key under which the variable `name` is held in the snapshot; variable names are
+/// case-insensitive on Windows, and so are folded to upper case there.
+///
+#[cfg(windows)]
+fn key(name: &OsStr) -> OsString {
+    match name.to_str() {
+        Some(name) => OsString::from(name.to_uppercase()),
+        None => name.to_os_string(),
+    }
+}
+
+#[cfg(not(windows))]
+fn key(name: &OsStr) -> OsString {
+    name.to_os_string()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Support
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Serializes the unit tests that modify the process environment, and restores each variable
+/// they set or remove, and the directories resolved from them, when dropped.
+///
+#[cfg(test)]
+pub(crate) struct EnvGuard {
+    saved: Vec<(String, Option<OsString>)>,
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+
+#[cfg(test)]
+impl EnvGuard {
+    pub(crate) fn lock() -> Self {
+        static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+        Self {
+            saved: Default::default(),
+            _lock: match LOCK.lock() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            },
+        }
+    }
+
+    pub(crate) fn set<V: AsRef<OsStr>>(&mut self, name: &str, value: V) {
+        self.save(name);
+        env::set_var(name, value);
+    }
+
+    pub(crate) fn remove(&mut self, name: &str) {
+        self.save(name);
+        env::remove_var(name);
+    }
+
+    fn save(&mut self, name: &str) {
+        if !self.saved.iter().any(|(saved, _)| saved == name) {
+            self.saved.push((name.to_string(), env::var_os(name)));
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        for (name, value) in self.saved.drain(..) {
+            match value {
+                Some(value) => env::set_var(name, value),
+                None => env::remove_var(name),
+            }
+        }
+        crate::refresh();
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reload_env() {
+        let mut env = EnvGuard::lock();
+        env.set("XDIRS_ENVIRONMENT_TEST", "value");
+        reload_env();
+        assert_eq!(
+            var_os("XDIRS_ENVIRONMENT_TEST"),
+            Some(OsString::from("value"))
+        );
+        env.set("XDIRS_ENVIRONMENT_TEST", "");
+        assert_eq!(
+            non_empty_var("XDIRS_ENVIRONMENT_TEST"),
+            Some(String::from("value"))
+        );
+        reload_env();
+        assert_eq!(non_empty_var("XDIRS_ENVIRONMENT_TEST"), None);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_case_insensitive() {
+        let mut env = EnvGuard::lock();
+        env.set("Xdirs_Environment_Case", "value");
+        reload_env();
+        assert_eq!(
+            var_os("XDIRS_ENVIRONMENT_CASE"),
+            Some(OsString::from("value"))
+        );
+        assert_eq!(
+            var_os("xdirs_environment_case"),
+            var_os("Xdirs_Environment_Case")
+        );
+    }
+
+    #[test]
+    fn test_resolve_in() {
+        let home = if cfg!(windows) {
+            "C:\\Users\\bob"
+        } else {
+            "/home/bob"
+        };
+        let env = Environment::new(home);
+        let cache = resolve_in(&env, DirKind::Cache, "Chrome").unwrap();
+        assert!(cache.starts_with(home));
+        let elsewhere = if cfg!(windows) {
+            "D:\\Chrome"
+        } else {
+            "/srv/chrome"
+        };
+        let env = env
+            .with_var("CHROME_CACHE_DIR", elsewhere)
+            .with_var("CHROME_CONFIG_DIR", "relative");
+        assert_eq!(
+            resolve_in(&env, DirKind::Cache, "Chrome"),
+            Some(PathBuf::from(elsewhere))
+        );
+        assert!(resolve_in(&env, DirKind::Config, "Chrome")
+            .unwrap()
+            .starts_with(home));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_resolve_in_xdg() {
+        let env = Environment::new("/home/bob")
+            .with_var("XDG_CONFIG_HOME", "/etc/bob")
+            .with_var("XDG_DATA_HOME", "");
+        assert_eq!(
+            resolve_in(&env, DirKind::Config, "Chrome"),
+            Some(PathBuf::from("/etc/bob/Chrome"))
+        );
+        assert_eq!(
+            resolve_in(&env, DirKind::Data, "Chrome"),
+            Some(PathBuf::from("/home/bob/.local/share/Chrome"))
+        );
+    }
+}
@@ -0,0 +1,234 @@
+/*!
+Provides variants of the application-specific functions that only return a directory if it
+already exists, for consumers that only read from it, such as a configuration loader, and need
+to distinguish where a directory would be from where one actually is.
+*/
+
+use crate::DirKind;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the generic form of the directory `kind`, if it has a value on the current platform
+/// and that directory already exists.
+///
+pub fn existing_dir(kind: DirKind) -> Option<PathBuf> {
+    existing(kind.dir())
+}
+
+///
+/// Returns the application-specific form of the directory `kind`, if it has a value on the
+/// current platform and that directory already exists.
+///
+/// ```rust
+/// use xdirs::{existing_dir_for, DirKind};
+///
+/// match existing_dir_for(DirKind::Config, "MyApp") {
+///     Some(config) => println!("loading configuration from {:?}", config),
+///     None => println!("using the default configuration"),
+/// }
+/// ```
+///
+pub fn existing_dir_for(kind: DirKind, app: &str) -> Option<PathBuf> {
+    existing(kind.dir_for(app))
+}
+
+///
+/// Returns the path to the user's cache directory for a specific application, if it already
+/// exists. See [`cache_dir_for`](fn.cache_dir_for.html).
+///
+pub fn cache_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Cache, app)
+}
+
+///
+/// Returns the path to the user's config directory for a specific application, if it already
+/// exists. See [`config_dir_for`](fn.config_dir_for.html).
+///
+pub fn config_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Config, app)
+}
+
+///
+/// Returns the path to the user's local config directory for a specific application, if it already
+/// exists. See [`config_local_dir_for`](fn.config_local_dir_for.html).
+///
+pub fn config_local_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::ConfigLocal, app)
+}
+
+///
+/// Returns the path to the user's data directory for a specific application, if it already exists.
+/// See [`data_dir_for`](fn.data_dir_for.html).
+///
+pub fn data_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Data, app)
+}
+
+///
+/// Returns the path to the user's local data directory for a specific application, if it already
+/// exists. See [`data_local_dir_for`](fn.data_local_dir_for.html).
+///
+pub fn data_local_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::DataLocal, app)
+}
+
+///
+/// Returns the path to the installation directory for a specific application, if it already
+/// exists. See [`application_dir_for`](fn.application_dir_for.html).
+///
+pub fn application_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Application, app)
+}
+
+///
+/// Returns the path to the user's installation directory for a specific application, if it already
+/// exists. See [`user_application_dir_for`](fn.user_application_dir_for.html).
+///
+pub fn user_application_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::UserApplication, app)
+}
+
+///
+/// Returns the directory in which a specific application is installed, for all users or for the
+/// user only, if it can be found. The conventional installation directories, as returned by
+/// [`application_dir_for`](fn.application_dir_for.html) and
+/// [`user_application_dir_for`](fn.user_application_dir_for.html), are searched in that order,
+/// each followed by the directory named for the application in its parent, so that on macOS both
+/// `/Applications/{app}.app` and `/Applications/{app}` are found. The first that exists is
+/// returned. On Windows, with the `registry` feature, an application found in none of these is
+/// looked for by name, or display name, in the registry's `Uninstall` entries, for those that
+/// install to a vendor-specific directory.
+///
+/// ```rust
+/// use xdirs::installed_application_dir_for;
+///
+/// match installed_application_dir_for("MyApp") {
+///     Some(path) => println!("MyApp is installed in {:?}", path),
+///     None => println!("MyApp is not installed"),
+/// }
+/// ```
+///
+pub fn installed_application_dir_for(app: &str) -> Option<PathBuf> {
+    let name = crate::sanitize_app_name(app);
+    let named = |parent: Option<PathBuf>| parent.map(|path| path.join(name.as_ref()));
+    vec![
+        crate::application_dir_for(app),
+        named(crate::application_dir()),
+        crate::user_application_dir_for(app),
+        named(crate::user_application_dir()),
+    ]
+    .into_iter()
+    .find_map(existing)
+    .or_else(|| existing(crate::sys::registered_install_dir_for(app)))
+}
+
+///
+/// Returns the path to the user's favorites directory for a specific application, if it already
+/// exists. See [`favorites_dir_for`](fn.favorites_dir_for.html).
+///
+pub fn favorites_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Favorites, app)
+}
+
+///
+/// Returns the path to the user's log file directory for a specific application, if it already
+/// exists. See [`log_dir_for`](fn.log_dir_for.html).
+///
+pub fn log_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Log, app)
+}
+
+///
+/// Returns the path to the system-wide log directory for a specific application, if it already
+/// exists. See [`system_log_dir_for`](fn.system_log_dir_for.html).
+///
+pub fn system_log_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::SystemLog, app)
+}
+
+///
+/// Returns the path to the user's preference file directory for a specific application, if it
+/// already exists. See [`preference_dir_for`](fn.preference_dir_for.html).
+///
+pub fn preference_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Preference, app)
+}
+
+///
+/// Returns the path to the user's template directory for a specific application, if it already
+/// exists. See [`template_dir_for`](fn.template_dir_for.html).
+///
+pub fn template_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Template, app)
+}
+
+///
+/// Returns the path to the user's saved games directory for a specific application, if it already
+/// exists. See [`saved_games_dir_for`](fn.saved_games_dir_for.html).
+///
+pub fn saved_games_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::SavedGames, app)
+}
+
+///
+/// Returns the path to the user's icons directory for a specific application, if it already
+/// exists. See [`icons_dir_for`](fn.icons_dir_for.html).
+///
+pub fn icons_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Icons, app)
+}
+
+///
+/// Returns the path to the user's themes directory for a specific application, if it already
+/// exists. See [`themes_dir_for`](fn.themes_dir_for.html).
+///
+pub fn themes_dir_for_existing(app: &str) -> Option<PathBuf> {
+    existing_dir_for(DirKind::Themes, app)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn existing(path: Option<PathBuf>) -> Option<PathBuf> {
+    path.filter(|path| path.is_dir())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_existing() {
+        let path = std::env::temp_dir();
+        assert_eq!(existing(Some(path.clone())), Some(path.clone()));
+        assert_eq!(existing(Some(path.join("xdirs-no-such-dir"))), None);
+        assert_eq!(existing(None), None);
+    }
+
+    #[test]
+    fn test_installed_application_dir_for() {
+        let root = std::env::temp_dir().join(format!("xdirs-installed-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        assert_eq!(installed_application_dir_for("Chrome"), None);
+        if let Some(path) = crate::user_application_dir_for("Chrome") {
+            std::fs::create_dir_all(&path).unwrap();
+            assert_eq!(installed_application_dir_for("Chrome"), Some(path));
+            std::fs::remove_dir_all(&root).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_existing_dir_for() {
+        assert_eq!(cache_dir_for_existing("xdirs-no-such-app"), None);
+        assert_eq!(existing_dir_for(DirKind::Config, "xdirs-no-such-app"), None);
+    }
+}
@@ -0,0 +1,146 @@
+/*!
+Provides an explanation of where a resolved directory came from, for diagnostics and for
+installers that need to know whether a platform's primary source was used.
+*/
+
+use crate::resolve::{platform_dir, platform_dir_for};
+use crate::{env_override, override_root, sys, warm, DirKind};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A resolved directory, and the source that produced it.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    /// The resolved directory, as returned by the corresponding directory function.
+    pub path: Option<PathBuf>,
+    /// The source of `path`.
+    pub source: Source,
+}
+
+///
+/// The source of a resolved directory.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Source {
+    /// The application's override environment variable, which is named here; see
+    /// [`env_override_var`](fn.env_override_var.html).
+    Environment(String),
+    /// A value cached by [`warm`](fn.warm.html).
+    Warmed,
+    /// The platform's primary source, such as the XDG environment variables or the Known Folder
+    /// API; if the explained path is `None` the platform has no value.
+    Platform,
+    /// A documented fallback, described here, used because the platform's primary source had no
+    /// value.
+    Fallback(&'static str),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the generic form of the directory `kind`, and its source.
+///
+/// ```rust
+/// use xdirs::{explain_dir, DirKind};
+///
+/// let explanation = explain_dir(DirKind::UserApplication);
+/// println!("{:?} from {}", explanation.path, explanation.source);
+/// ```
+///
+pub fn explain_dir(kind: DirKind) -> Explanation {
+    Explanation {
+        path: override_root::apply(platform_dir(kind)),
+        source: platform_source(kind),
+    }
+}
+
+///
+/// Returns the application-specific form of the directory `kind` for `app`, and its source.
+///
+pub fn explain_dir_for(kind: DirKind, app: &str) -> Explanation {
+    let (path, source) = if let Some(path) = env_override::dir_for(kind, app) {
+        let variable = env_override::env_override_var(kind, app).unwrap_or_default();
+        (Some(path), Source::Environment(variable))
+    } else if let Some(path) = warm::cached(kind, app) {
+        (path, Source::Warmed)
+    } else {
+        (platform_dir_for(kind, app), platform_source(kind))
+    };
+    Explanation {
+        path: override_root::apply(path),
+        source,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Environment(variable) => write!(f, "environment variable {}", variable),
+            Source::Warmed => write!(f, "pre-resolved cache"),
+            Source::Platform => write!(f, "platform"),
+            Source::Fallback(description) => write!(f, "fallback {}", description),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn platform_source(kind: DirKind) -> Source {
+    match sys::fallback_source(kind) {
+        Some(description) => Source::Fallback(description),
+        None => Source::Platform,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_dir() {
+        for kind in DirKind::all() {
+            assert_eq!(explain_dir(kind).path, kind.dir());
+        }
+    }
+
+    #[test]
+    fn test_explain_dir_for() {
+        let explanation = explain_dir_for(DirKind::Config, "Chrome");
+        assert_eq!(explanation.path, crate::config_dir_for("Chrome"));
+        assert_eq!(explanation.source, Source::Platform);
+    }
+
+    #[test]
+    fn test_explain_environment() {
+        let mut env = crate::environment::EnvGuard::lock();
+        let path = std::env::temp_dir().join("xdirs-explain");
+        env.set("XDIRS_EXPLAIN_TEST_DATA_DIR", &path);
+        crate::reload_env();
+        assert_eq!(
+            explain_dir_for(DirKind::Data, "xdirs-explain-test"),
+            Explanation {
+                path: Some(path),
+                source: Source::Environment(String::from("XDIRS_EXPLAIN_TEST_DATA_DIR")),
+            }
+        );
+    }
+}
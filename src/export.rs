@@ -0,0 +1,252 @@
+/*!
+Provides the copying of an application's settings and data to, and from, a portable directory,
+so that they may be moved between machines or users, or synchronized, whatever the layout of
+each platform.
+
+An export holds one subdirectory for each kind of directory copied, named for the kind, such as
+`config` or `data_local`; on import each is copied into the current platform's directory of that
+kind. Caches, logs, secrets, and other directories specific to the machine are not copied, even
+where a platform keeps them within the data directories.
+
+Each directory created is reported to the sink set with [`set_audit_sink`](fn.set_audit_sink.html).
+*/
+
+use crate::audit::{self, Operation};
+use crate::{DirKind, Error};
+use std::fs::{copy, create_dir_all, metadata, read_dir, set_permissions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Copy the existing config, local config, data, local data, and preference directories of a
+/// specific application into `dest`, each in a subdirectory named for its kind, and return the
+/// subdirectories written. Where one directory is the same as, or within, another only the first
+/// is copied; so on Linux, where preferences are kept in the config directory, there is no
+/// `preference` subdirectory.
+///
+/// Files in `dest` are replaced by those of the same name; symbolic links are not copied. Each
+/// directory written is given the permissions of the directory copied. Directories of other
+/// kinds nested within those copied, such as the log directory within the local data directory
+/// on Linux, and the secrets directory, are skipped.
+///
+/// ```rust,no_run
+/// use xdirs::{export_app_dirs, import_app_dirs};
+///
+/// let bundle = std::env::temp_dir().join("my-app-settings");
+/// export_app_dirs("MyApp", &bundle).unwrap();
+/// // ... and on the other machine
+/// import_app_dirs("MyApp", &bundle).unwrap();
+/// ```
+///
+pub fn export_app_dirs<P: AsRef<Path>>(app: &str, dest: P) -> io::Result<Vec<PathBuf>> {
+    let dest = dest.as_ref();
+    let excluded = excluded_dirs_for(app);
+    let mut written = Vec::new();
+    for (kind, path) in app_dirs_for(app) {
+        let to = dest.join(kind.name());
+        copy_tree(&path, &to, &excluded, None, "export_app_dirs")?;
+        written.push(to);
+    }
+    Ok(written)
+}
+
+///
+/// Copy each subdirectory of `src`, as written by [`export_app_dirs`](fn.export_app_dirs.html),
+/// into the current platform's directory of that kind for a specific application, creating it if
+/// necessary, and return the directories written. Subdirectories not named for a kind that is
+/// exported are ignored.
+///
+/// Files already in the application's directories are replaced by those of the same name;
+/// symbolic links are not copied, and each directory written is given the permissions of the
+/// directory copied. If a directory has no value on the current platform the
+/// returned error has the kind `NotFound`, and wraps an
+/// [`Error::Unavailable`](enum.Error.html#variant.Unavailable).
+///
+pub fn import_app_dirs<P: AsRef<Path>>(app: &str, src: P) -> io::Result<Vec<PathBuf>> {
+    let src = src.as_ref();
+    let mut written = Vec::new();
+    for kind in EXPORTED_KINDS {
+        let from = src.join(kind.name());
+        if !from.is_dir() {
+            continue;
+        }
+        let to = kind
+            .dir_for(app)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, Error::Unavailable(*kind)))?;
+        copy_tree(&from, &to, &[], Some(*kind), "import_app_dirs")?;
+        if !written.contains(&to) {
+            written.push(to);
+        }
+    }
+    Ok(written)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const EXPORTED_KINDS: &[DirKind] = &[
+    DirKind::Config,
+    DirKind::ConfigLocal,
+    DirKind::Data,
+    DirKind::DataLocal,
+    DirKind::Preference,
+];
+
+///
+/// Returns the existing exported directories of `app`, omitting any that is the same as, or
+/// within, one before it.
+///
+fn app_dirs_for(app: &str) -> Vec<(DirKind, PathBuf)> {
+    let mut dirs: Vec<(DirKind, PathBuf)> = Vec::new();
+    for kind in EXPORTED_KINDS {
+        if let Some(path) = kind.dir_for(app).filter(|path| path.is_dir()) {
+            if !dirs.iter().any(|(_, dir)| path.starts_with(dir)) {
+                dirs.push((*kind, path));
+            }
+        }
+    }
+    dirs
+}
+
+///
+/// Returns the directories of `app`, other than those exported, that may be nested within an
+/// exported directory and must not be copied with it.
+///
+fn excluded_dirs_for(app: &str) -> Vec<PathBuf> {
+    DirKind::all()
+        .filter(|kind| kind.has_app_form() && !EXPORTED_KINDS.contains(kind))
+        .filter_map(|kind| kind.dir_for(app))
+        .collect()
+}
+
+///
+/// Copy the directory `from` to `to`, skipping any directory in `excluded`, and giving each
+/// directory created the permissions of its source; the permissions are set before any file is
+/// copied into it.
+///
+fn copy_tree(
+    from: &Path,
+    to: &Path,
+    excluded: &[PathBuf],
+    kind: Option<DirKind>,
+    reason: &'static str,
+) -> io::Result<()> {
+    let missing = audit::missing_dirs(to);
+    create_dir_all(to)?;
+    set_permissions(to, metadata(from)?.permissions())?;
+    for path in &missing {
+        audit::record(Operation::Create, path, kind, reason);
+    }
+    for entry in read_dir(from)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        if file_type.is_dir() {
+            let path = entry.path();
+            if excluded.contains(&path) {
+                continue;
+            }
+            copy_tree(&path, &target, excluded, kind, reason)?;
+        } else if file_type.is_file() {
+            copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{read_to_string, remove_dir_all, write};
+
+    #[test]
+    fn test_export_and_import() {
+        let root = std::env::temp_dir().join(format!("xdirs-export-{}", std::process::id()));
+        let bundle = root.join("bundle");
+        let config = {
+            let _guard = crate::override_root(root.join("from"));
+            let config = crate::config_dir_for("Chrome").unwrap();
+            create_dir_all(config.join("profiles")).unwrap();
+            write(config.join("profiles").join("default"), "theme = 1\n").unwrap();
+            let cache = crate::cache_dir_for("Chrome").unwrap();
+            create_dir_all(&cache).unwrap();
+            assert_eq!(
+                export_app_dirs("Chrome", &bundle).unwrap(),
+                vec![bundle.join("config")]
+            );
+            config
+        };
+        assert!(!bundle.join("cache").exists());
+
+        let _guard = crate::override_root(root.join("to"));
+        let imported = import_app_dirs("Chrome", &bundle).unwrap();
+        let target = crate::config_dir_for("Chrome").unwrap();
+        assert_ne!(target, config);
+        assert_eq!(imported, vec![target.clone()]);
+        assert_eq!(
+            read_to_string(target.join("profiles").join("default")).unwrap(),
+            "theme = 1\n"
+        );
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_export_skips_nested_kinds() {
+        let root = std::env::temp_dir().join(format!("xdirs-export-nested-{}", std::process::id()));
+        let bundle = root.join("bundle");
+        let _guard = crate::override_root(root.join("from"));
+        let data_local = crate::data_local_dir_for("Chrome").unwrap();
+        create_dir_all(&data_local).unwrap();
+        write(data_local.join("history.db"), "1\n").unwrap();
+        let nested: Vec<PathBuf> = excluded_dirs_for("Chrome")
+            .into_iter()
+            .filter(|path| path.starts_with(&data_local))
+            .collect();
+        for path in &nested {
+            create_dir_all(path).unwrap();
+            write(path.join("private"), "2\n").unwrap();
+        }
+
+        // on Linux the local data directory is exported as `data`.
+        let written = export_app_dirs("Chrome", &bundle).unwrap();
+        let exported = written
+            .iter()
+            .find(|path| path.join("history.db").is_file())
+            .unwrap();
+        for path in &nested {
+            let relative = path.strip_prefix(&data_local).unwrap();
+            assert!(!exported.join(relative).exists(), "{:?}", relative);
+        }
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_export_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = std::env::temp_dir().join(format!("xdirs-export-mode-{}", std::process::id()));
+        let bundle = root.join("bundle");
+        let _guard = crate::override_root(root.join("from"));
+        let config = crate::config_dir_for("Chrome").unwrap();
+        create_dir_all(&config).unwrap();
+        set_permissions(&config, std::fs::Permissions::from_mode(0o700)).unwrap();
+
+        let _ = export_app_dirs("Chrome", &bundle).unwrap();
+        let mode = metadata(bundle.join("config"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o700);
+        remove_dir_all(&root).unwrap();
+    }
+}
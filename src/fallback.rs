@@ -0,0 +1,143 @@
+/*!
+Provides total variants of the directory functions, which return a path beneath a base chosen by
+the caller wherever the current platform has no value, so that cross-platform applications need
+not handle each platform's gaps.
+*/
+
+use crate::{sanitize_app_name, DirKind};
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Resolves directories as the functions of this crate do, but where a directory has no value on
+/// the current platform returns the subdirectory of a base directory named for the kind, such as
+/// `{base}/template` or, for an application, `{base}/template/{app}`; see
+/// [`with_fallback`](fn.with_fallback.html).
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WithFallback {
+    base: PathBuf,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns a resolver whose directories are never `None`, with those the current platform does
+/// not provide placed beneath `base`.
+///
+/// ```rust
+/// use xdirs::{with_fallback, DirKind};
+///
+/// let dirs = with_fallback(std::env::temp_dir().join("my-app"));
+/// let templates = dirs.dir_for(DirKind::Template, "MyApp");
+/// assert!(templates.is_absolute());
+/// ```
+///
+pub fn with_fallback<P: Into<PathBuf>>(base: P) -> WithFallback {
+    WithFallback { base: base.into() }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl WithFallback {
+    ///
+    /// Returns the base directory beneath which directories the platform does not provide are
+    /// placed.
+    ///
+    pub fn base(&self) -> &Path {
+        &self.base
+    }
+
+    ///
+    /// Returns the generic form of `kind`, as from [`DirKind::dir`](enum.DirKind.html#method.dir),
+    /// or `{base}/{kind}` if it has no value, where `{kind}` is the kind's name.
+    ///
+    pub fn dir(&self, kind: DirKind) -> PathBuf {
+        kind.dir().unwrap_or_else(|| self.fallback(kind))
+    }
+
+    ///
+    /// Returns the application-specific form of `kind`, as from
+    /// [`DirKind::dir_for`](enum.DirKind.html#method.dir_for), or `{base}/{kind}/{app}` if it has
+    /// no value.
+    ///
+    pub fn dir_for(&self, kind: DirKind, app: &str) -> PathBuf {
+        kind.dir_for(app)
+            .unwrap_or_else(|| self.fallback(kind).join(sanitize_app_name(app).as_ref()))
+    }
+
+    ///
+    /// Returns the path to the user's cache directory for a specific application; see
+    /// [`dir_for`](#method.dir_for).
+    ///
+    pub fn cache_dir_for(&self, app: &str) -> PathBuf {
+        self.dir_for(DirKind::Cache, app)
+    }
+
+    ///
+    /// Returns the path to the user's config directory for a specific application; see
+    /// [`dir_for`](#method.dir_for).
+    ///
+    pub fn config_dir_for(&self, app: &str) -> PathBuf {
+        self.dir_for(DirKind::Config, app)
+    }
+
+    ///
+    /// Returns the path to the user's data directory for a specific application; see
+    /// [`dir_for`](#method.dir_for).
+    ///
+    pub fn data_dir_for(&self, app: &str) -> PathBuf {
+        self.dir_for(DirKind::Data, app)
+    }
+
+    ///
+    /// Returns the path to the user's log directory for a specific application; see
+    /// [`dir_for`](#method.dir_for).
+    ///
+    pub fn log_dir_for(&self, app: &str) -> PathBuf {
+        self.dir_for(DirKind::Log, app)
+    }
+
+    fn fallback(&self, kind: DirKind) -> PathBuf {
+        self.base.join(kind.name())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_fallback() {
+        let base = std::env::temp_dir().join("xdirs-fallback");
+        let dirs = with_fallback(&base);
+        assert_eq!(dirs.base(), base.as_path());
+        for kind in DirKind::all() {
+            match kind.dir_for("Chrome") {
+                Some(path) => assert_eq!(dirs.dir_for(kind, "Chrome"), path),
+                None => assert_eq!(
+                    dirs.dir_for(kind, "Chrome"),
+                    base.join(kind.name()).join("Chrome")
+                ),
+            }
+            if kind.dir().is_none() {
+                assert_eq!(dirs.dir(kind), base.join(kind.name()));
+            }
+        }
+        assert_eq!(
+            dirs.log_dir_for("../Chrome"),
+            crate::log_dir_for("../Chrome").unwrap_or_else(|| base.join("log").join(".._Chrome"))
+        );
+    }
+}
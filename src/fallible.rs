@@ -0,0 +1,186 @@
+/*!
+Provides fallible variants of the directory functions which report why a directory could not be
+resolved; the infallible functions are built on the same resolution, discarding the reason.
+*/
+
+use crate::{resolve, validate_app_name, DirKind};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The reason a directory could not be resolved by one of the fallible functions.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The directory kind has no value, in the requested form, on the current platform.
+    Unavailable(DirKind),
+    /// The application name is not valid, for the reason given; see
+    /// [`validate_app_name`](fn.validate_app_name.html).
+    InvalidAppName { name: String, reason: &'static str },
+    /// The process is running under `sudo`, but its home directory is not that of the invoking
+    /// user, and so the user's directories would be resolved beneath the target user's home; see
+    /// [`check_sudo_home`](fn.check_sudo_home.html).
+    SudoHomeMismatch {
+        user: String,
+        home: PathBuf,
+        expected: PathBuf,
+    },
+    /// The directory kind is beneath the home directory, which could not be found; `$HOME` is
+    /// not set, and the [`HomeFallback`](enum.HomeFallback.html) strategy gave no directory.
+    HomeUnavailable(DirKind),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the generic form of the directory `kind`, or the reason it could not be resolved; this
+/// is [`Error::HomeUnavailable`](enum.Error.html#variant.HomeUnavailable) if the directory is
+/// beneath a home directory that could not be found, otherwise
+/// [`Error::Unavailable`](enum.Error.html#variant.Unavailable).
+///
+pub fn try_dir(kind: DirKind) -> Result<PathBuf, Error> {
+    if kind.has_generic_form() {
+        resolve::try_dir(kind)
+    } else {
+        Err(Error::Unavailable(kind))
+    }
+}
+
+///
+/// Returns the application-specific form of the directory `kind`, or the reason it could not be
+/// resolved.
+///
+/// Unlike the infallible functions, which sanitize the application name, this rejects any name
+/// that is not valid with [`Error::InvalidAppName`](enum.Error.html#variant.InvalidAppName).
+/// Otherwise errors are reported as for [`try_dir`](fn.try_dir.html).
+///
+pub fn try_dir_for(kind: DirKind, app: &str) -> Result<PathBuf, Error> {
+    validate_app_name(app).map_err(|reason| Error::InvalidAppName {
+        name: app.to_string(),
+        reason,
+    })?;
+    if kind.has_app_form() {
+        resolve::try_dir_for(kind, app)
+    } else {
+        Err(Error::Unavailable(kind))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Unavailable(kind) => write!(
+                f,
+                "the {} directory is not available on this platform",
+                kind
+            ),
+            Error::InvalidAppName { name, reason } => {
+                write!(
+                    f,
+                    "the application name {:?} is not valid: {}",
+                    name, reason
+                )
+            }
+            Error::SudoHomeMismatch {
+                user,
+                home,
+                expected,
+            } => write!(
+                f,
+                "running under sudo with home {:?}, rather than {:?} for the invoking user {}",
+                home, expected, user
+            ),
+            Error::HomeUnavailable(kind) => write!(
+                f,
+                "the {} directory is beneath the home directory, which could not be found; set \
+                 HOME, or a fallback with set_home_fallback",
+                kind
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::catch_unwind;
+
+    const HOSTILE_NAMES: &[&str] = &[
+        "",
+        ".",
+        "..",
+        "../../etc",
+        "/",
+        "/absolute",
+        "\\",
+        "C:\\Windows",
+        "nul\0byte",
+        "CON",
+        "a/b/c",
+        "\u{202e}reversed",
+        "emoji-\u{1f600}",
+        "trailing.",
+        "trailing ",
+    ];
+
+    #[test]
+    fn test_no_function_panics() {
+        let long_name = "x".repeat(4096);
+        let names = HOSTILE_NAMES
+            .iter()
+            .copied()
+            .chain(Some(long_name.as_str()));
+        for name in names {
+            for kind in DirKind::all() {
+                assert!(catch_unwind(|| kind.dir()).is_ok());
+                assert!(catch_unwind(|| kind.dir_for(name)).is_ok());
+                assert!(catch_unwind(|| try_dir(kind)).is_ok());
+                assert!(catch_unwind(|| try_dir_for(kind, name)).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_infallible() {
+        for kind in DirKind::all() {
+            assert_eq!(try_dir(kind).ok(), kind.dir());
+            assert_eq!(try_dir_for(kind, "Chrome").ok(), kind.dir_for("Chrome"));
+        }
+    }
+
+    #[test]
+    fn test_unavailable() {
+        assert_eq!(
+            try_dir(DirKind::AppContainer),
+            Err(Error::Unavailable(DirKind::AppContainer))
+        );
+    }
+
+    #[test]
+    fn test_invalid_app_name() {
+        assert_eq!(
+            try_dir_for(DirKind::Cache, "../evil"),
+            Err(Error::InvalidAppName {
+                name: String::from("../evil"),
+                reason: "the name contains a path separator"
+            })
+        );
+    }
+}
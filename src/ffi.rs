@@ -0,0 +1,266 @@
+/*!
+Provides a C-compatible interface to this crate, so that applications written in other languages,
+such as C, Swift, or Python using `ctypes`, can use the same directories as those written in Rust.
+
+The crate is built as a C dynamic library, with these functions exported, by:
+
+```bash
+$ cargo rustc --release --features ffi --crate-type cdylib
+```
+
+and the declarations are in `include/xdirs.h`. Each function writes the path, encoded as UTF-8
+and terminated by a NUL, into the caller's buffer, and returns the length of the path in bytes,
+not including the NUL. If the buffer is too small nothing is written, and the length returned is
+at least `len`, so that the caller may allocate a buffer of the returned length plus one and call
+again; a negative value is one of the `XDIRS_` error codes.
+
+```c
+char buf[4096];
+if (xdirs_config_dir_for("MyApp", buf, sizeof buf) >= 0) {
+    printf("%s\n", buf);
+}
+```
+
+Directory kinds are named as for the `FromStr` implementation of [`DirKind`](../enum.DirKind.html),
+such as `"config"` or `"cache"`. Unlike the Rust functions, which sanitize the application name,
+a name that is not valid is rejected, as by [`try_dir_for`](../fn.try_dir_for.html). No function
+in this module will unwind across the FFI boundary.
+*/
+
+use crate::{try_dir, try_dir_for, DirKind, Error};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::panic::catch_unwind;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The directory has no value on the current platform.
+pub const XDIRS_UNAVAILABLE: isize = -1;
+
+/// An argument is null, is not valid UTF-8, or is not a valid kind or application name.
+pub const XDIRS_INVALID_ARGUMENT: isize = -2;
+
+/// The path cannot be represented as UTF-8, or the resolution failed unexpectedly.
+pub const XDIRS_FAILED: isize = -3;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Write the generic form of the directory named `kind` into `buf`; see the
+/// [module documentation](index.html) for the use of `buf`, `len`, and the returned value.
+///
+/// # Safety
+///
+/// `kind` must be null or a valid NUL-terminated string, and `buf` must be null or valid for
+/// writes of `len` bytes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn xdirs_dir(kind: *const c_char, buf: *mut c_char, len: usize) -> isize {
+    guarded(buf, len, || {
+        let kind = dir_kind(kind)?;
+        resolved(try_dir(kind))
+    })
+}
+
+///
+/// Write the application-specific form of the directory named `kind` for `app` into `buf`; see
+/// the [module documentation](index.html) for the use of `buf`, `len`, and the returned value.
+///
+/// # Safety
+///
+/// `kind` and `app` must each be null or a valid NUL-terminated string, and `buf` must be null or
+/// valid for writes of `len` bytes.
+///
+#[no_mangle]
+pub unsafe extern "C" fn xdirs_dir_for(
+    kind: *const c_char,
+    app: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> isize {
+    guarded(buf, len, || {
+        let kind = dir_kind(kind)?;
+        resolved(try_dir_for(kind, string(app)?))
+    })
+}
+
+///
+/// Write the path to the user's cache directory for `app` into `buf`, as for
+/// [`xdirs_dir_for`](fn.xdirs_dir_for.html).
+///
+/// # Safety
+///
+/// As for [`xdirs_dir_for`](fn.xdirs_dir_for.html).
+///
+#[no_mangle]
+pub unsafe extern "C" fn xdirs_cache_dir_for(
+    app: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> isize {
+    kind_dir_for(DirKind::Cache, app, buf, len)
+}
+
+///
+/// Write the path to the user's config directory for `app` into `buf`, as for
+/// [`xdirs_dir_for`](fn.xdirs_dir_for.html).
+///
+/// # Safety
+///
+/// As for [`xdirs_dir_for`](fn.xdirs_dir_for.html).
+///
+#[no_mangle]
+pub unsafe extern "C" fn xdirs_config_dir_for(
+    app: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> isize {
+    kind_dir_for(DirKind::Config, app, buf, len)
+}
+
+///
+/// Write the path to the user's data directory for `app` into `buf`, as for
+/// [`xdirs_dir_for`](fn.xdirs_dir_for.html).
+///
+/// # Safety
+///
+/// As for [`xdirs_dir_for`](fn.xdirs_dir_for.html).
+///
+#[no_mangle]
+pub unsafe extern "C" fn xdirs_data_dir_for(
+    app: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> isize {
+    kind_dir_for(DirKind::Data, app, buf, len)
+}
+
+///
+/// Write the path to the user's log directory for `app` into `buf`, as for
+/// [`xdirs_dir_for`](fn.xdirs_dir_for.html).
+///
+/// # Safety
+///
+/// As for [`xdirs_dir_for`](fn.xdirs_dir_for.html).
+///
+#[no_mangle]
+pub unsafe extern "C" fn xdirs_log_dir_for(
+    app: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> isize {
+    kind_dir_for(DirKind::Log, app, buf, len)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+unsafe fn kind_dir_for(kind: DirKind, app: *const c_char, buf: *mut c_char, len: usize) -> isize {
+    guarded(buf, len, || resolved(try_dir_for(kind, string(app)?)))
+}
+
+///
+/// Resolve a path, containing any panic, and copy it into `buf`.
+///
+unsafe fn guarded<F>(buf: *mut c_char, len: usize, resolver: F) -> isize
+where
+    F: FnOnce() -> Result<PathBuf, isize> + std::panic::UnwindSafe,
+{
+    let path = match catch_unwind(resolver) {
+        Ok(Ok(path)) => path,
+        Ok(Err(code)) => return code,
+        Err(_) => return XDIRS_FAILED,
+    };
+    match path.to_str() {
+        Some(path) => copy(path.as_bytes(), buf, len),
+        None => XDIRS_FAILED,
+    }
+}
+
+unsafe fn copy(bytes: &[u8], buf: *mut c_char, len: usize) -> isize {
+    if bytes.len() >= isize::MAX as usize {
+        return XDIRS_FAILED;
+    }
+    if !buf.is_null() && bytes.len() < len {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), buf.cast::<u8>(), bytes.len());
+        *buf.add(bytes.len()) = 0;
+    }
+    bytes.len() as isize
+}
+
+unsafe fn string<'a>(value: *const c_char) -> Result<&'a str, isize> {
+    if value.is_null() {
+        return Err(XDIRS_INVALID_ARGUMENT);
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|_| XDIRS_INVALID_ARGUMENT)
+}
+
+unsafe fn dir_kind(kind: *const c_char) -> Result<DirKind, isize> {
+    DirKind::from_str(string(kind)?).map_err(|_| XDIRS_INVALID_ARGUMENT)
+}
+
+fn resolved(result: Result<PathBuf, Error>) -> Result<PathBuf, isize> {
+    result.map_err(|error| match error {
+        Error::Unavailable(_) | Error::HomeUnavailable(_) => XDIRS_UNAVAILABLE,
+        Error::InvalidAppName { .. } => XDIRS_INVALID_ARGUMENT,
+        _ => XDIRS_FAILED,
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::ptr::{null, null_mut};
+
+    #[test]
+    fn test_config_dir_for() {
+        let app = CString::new("Chrome").unwrap();
+        let expected = crate::config_dir_for("Chrome").unwrap();
+        let expected = expected.to_str().unwrap();
+
+        let required = unsafe { xdirs_config_dir_for(app.as_ptr(), null_mut(), 0) };
+        assert_eq!(required, expected.len() as isize);
+
+        let mut buf: Vec<c_char> = vec![0; required as usize + 1];
+        let written = unsafe { xdirs_config_dir_for(app.as_ptr(), buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(written, required);
+        let path = unsafe { CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(path.to_str().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut buf: [c_char; 8] = [0; 8];
+        let kind = CString::new("no-such-kind").unwrap();
+        let app = CString::new("../evil").unwrap();
+        unsafe {
+            assert_eq!(
+                xdirs_config_dir_for(null(), buf.as_mut_ptr(), buf.len()),
+                XDIRS_INVALID_ARGUMENT
+            );
+            assert_eq!(
+                xdirs_config_dir_for(app.as_ptr(), buf.as_mut_ptr(), buf.len()),
+                XDIRS_INVALID_ARGUMENT
+            );
+            assert_eq!(
+                xdirs_dir(kind.as_ptr(), buf.as_mut_ptr(), buf.len()),
+                XDIRS_INVALID_ARGUMENT
+            );
+        }
+        assert_eq!(buf[0], 0);
+    }
+}
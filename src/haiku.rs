@@ -0,0 +1,162 @@
+pub use crate::environment::home_dir;
+pub use crate::unsupported::{
+    application_scripts_dir_for, application_shared_dir, crash_dumps_dir, crash_reports_dir,
+    download_dir, executable_dir, extra_plugin_dirs_for, fallback_source, favorites_dir,
+    flatpak_id, glib_schemas_dir, group_container_dir_for, icloud_container_dir_for,
+    icloud_drive_dir, icons_dir, installed_container_dir_for, keyfile_backend_dir,
+    known_folder_dir, launch_agents_dir, launch_daemons_dir, man_dir, mime_dir, package_dir_for,
+    package_family_name, pipe_name_for, public_data_dir, public_dir, recent_dir,
+    registered_install_dir_for, runtime_dir, sandbox_container_dir_for, sandbox_container_id,
+    sandboxed_app_dir, saved_games_dir, shared_documents_dir, snap_name, snap_user_common_dir,
+    system_autostart_dir, system_crash_reports_dir, system_desktop_entry_dir,
+    system_glib_schemas_dir, system_launch_agents_dir, system_locale_dirs, system_mime_dir,
+    system_template_dir, system_wallpaper_dir, themes_dir, trash_dir, user_application_shared_dir,
+    user_id, wallpaper_dir,
+};
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn application_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_APPS))
+}
+
+pub fn user_application_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_NON_PACKAGED_APPS))
+}
+
+pub fn cache_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_CACHE))
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_SETTINGS))
+}
+
+pub fn config_local_dir() -> Option<PathBuf> {
+    config_dir()
+}
+
+pub fn data_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_DATA))
+}
+
+pub fn data_local_dir() -> Option<PathBuf> {
+    data_dir()
+}
+
+pub fn document_dir() -> Option<PathBuf> {
+    home_dir()
+}
+
+pub fn desktop_dir() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(D_DESKTOP))
+}
+
+pub fn log_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_LOG))
+}
+
+pub fn system_log_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_LOG))
+}
+
+pub fn preference_dir() -> Option<PathBuf> {
+    config_dir()
+}
+
+pub fn state_dir() -> Option<PathBuf> {
+    data_local_dir()
+}
+
+pub fn template_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_TEMPLATES))
+}
+
+pub fn is_remote(_path: &Path) -> bool {
+    false
+}
+
+pub fn temp_dir() -> Option<PathBuf> {
+    Some(std::env::temp_dir())
+}
+
+pub fn system_config_dirs() -> Vec<PathBuf> {
+    vec![PathBuf::from(D_SYSTEM_SETTINGS)]
+}
+
+pub fn system_data_dirs() -> Vec<PathBuf> {
+    vec![PathBuf::from(D_SYSTEM_DATA)]
+}
+
+pub fn system_domain_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn service_dirs_for(_: &str) -> crate::ServiceDirs {
+    crate::ServiceDirs::default()
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub fn host_home_dir() -> Option<PathBuf> {
+    home_dir()
+}
+
+pub fn autostart_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_LAUNCH))
+}
+
+pub fn desktop_entry_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_DESKBAR_MENU))
+}
+
+pub fn font_dir() -> Option<PathBuf> {
+    user_config_dir().map(|path| path.join(D_NON_PACKAGED_FONTS))
+}
+
+pub fn system_font_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_FONTS))
+}
+
+pub fn system_man_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_MAN))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub fn uses_system_journal() -> bool {
+    false
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_CONFIG: &str = "config";
+const D_CACHE: &str = "cache";
+const D_DATA: &str = "data";
+const D_DESKTOP: &str = "Desktop";
+const D_DESKBAR_MENU: &str = "settings/deskbar/menu";
+const D_LAUNCH: &str = "settings/boot/launch";
+const D_LOG: &str = "var/log";
+const D_NON_PACKAGED_APPS: &str = "non-packaged/apps";
+const D_NON_PACKAGED_FONTS: &str = "non-packaged/data/fonts";
+const D_SETTINGS: &str = "settings";
+const D_TEMPLATES: &str = "settings/Tracker/Tracker New Templates";
+const D_SYSTEM_APPS: &str = "/boot/system/apps";
+const D_SYSTEM_DATA: &str = "/boot/system/data";
+const D_SYSTEM_FONTS: &str = "/boot/system/data/fonts";
+const D_SYSTEM_LOG: &str = "/boot/system/var/log";
+const D_SYSTEM_MAN: &str = "/boot/system/documentation/man";
+const D_SYSTEM_SETTINGS: &str = "/boot/system/settings";
+
+///
+/// Returns the user's configuration directory, `B_USER_CONFIG_DIRECTORY`, beneath which all of the
+/// other user directories are found.
+///
+fn user_config_dir() -> Option<PathBuf> {
+    home_dir().map(|path| path.join(D_CONFIG))
+}
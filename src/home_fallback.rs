@@ -0,0 +1,112 @@
+/*!
+Provides the strategy by which the home directory is found when `$HOME` is not set, as in minimal
+containers and some CI runners, so that headless deployments behave predictably.
+*/
+
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// How the home directory is found, on Unix-like platforms and WASI, when `$HOME` is not set, is
+/// empty, or is not an absolute path; see [`set_home_fallback`](fn.set_home_fallback.html).
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HomeFallback {
+    /// There is no home directory, and so no directory beneath it; the fallible functions, such
+    /// as [`try_dir`](fn.try_dir.html), return
+    /// [`Error::HomeUnavailable`](enum.Error.html#variant.HomeUnavailable).
+    None,
+    /// The home directory recorded for the current user in the user database, as by
+    /// `getpwuid_r`; this is the default.
+    #[default]
+    UserDatabase,
+    /// The given directory, which must be an absolute path.
+    Dir(PathBuf),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Set the strategy by which the home directory is found when `$HOME` is not set. This has no
+/// effect on Windows, where the home directory is a Known Folder. Directories already resolved
+/// are not affected, so this should be called first, or followed by
+/// [`refresh`](fn.refresh.html).
+///
+/// ```rust
+/// use xdirs::{set_home_fallback, HomeFallback};
+///
+/// // in a container, keep the application's files out of the image.
+/// set_home_fallback(HomeFallback::Dir("/srv/app-home".into()));
+/// # set_home_fallback(HomeFallback::default());
+/// ```
+///
+pub fn set_home_fallback(fallback: HomeFallback) {
+    let mut current = match setting().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    *current = fallback;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the home directory given by the current fallback strategy, where `user_database`
+/// looks the current user up in the user database.
+///
+#[cfg(not(windows))]
+pub(crate) fn fallback_home<F>(user_database: F) -> Option<PathBuf>
+where
+    F: FnOnce() -> Option<PathBuf>,
+{
+    let fallback = match setting().read() {
+        Ok(guard) => guard.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+    match fallback {
+        HomeFallback::None => None,
+        HomeFallback::UserDatabase => user_database(),
+        HomeFallback::Dir(path) => Some(path),
+    }
+    .filter(|home| home.is_absolute())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn setting() -> &'static RwLock<HomeFallback> {
+    static SETTING: OnceLock<RwLock<HomeFallback>> = OnceLock::new();
+    SETTING.get_or_init(Default::default)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallback_home() {
+        let passwd = || Some(PathBuf::from("/home/alice"));
+        assert_eq!(fallback_home(passwd), passwd());
+        set_home_fallback(HomeFallback::None);
+        assert_eq!(fallback_home(passwd), None);
+        set_home_fallback(HomeFallback::Dir(PathBuf::from("/srv/home")));
+        assert_eq!(fallback_home(passwd), Some(PathBuf::from("/srv/home")));
+        set_home_fallback(HomeFallback::Dir(PathBuf::from("relative")));
+        assert_eq!(fallback_home(passwd), None);
+        set_home_fallback(HomeFallback::default());
+    }
+}
@@ -0,0 +1,132 @@
+/*!
+Provides the locations of the Homebrew package manager, so that command-line tools installed by a
+Homebrew formula can find the data installed with them.
+*/
+
+use crate::override_root;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the prefix of the Homebrew installation, if there is one. This is `$HOMEBREW_PREFIX`,
+/// if it is set to an existing directory; otherwise it is the first of the default prefixes for
+/// the platform in which `bin/brew` exists.
+///
+/// |Platform      | Value                                              |
+/// | ------------ | -------------------------------------------------- |
+/// | Linux        | /home/linuxbrew/.linuxbrew                         |
+/// | macOS, Apple | /opt/homebrew, /usr/local                          |
+/// | macOS, Intel | /usr/local, /opt/homebrew                          |
+/// | Windows      | –                                                  |
+///
+/// ```rust
+/// use xdirs::homebrew_prefix;
+///
+/// if let Some(prefix) = homebrew_prefix() {
+///     assert!(prefix.is_dir());
+/// }
+/// ```
+///
+pub fn homebrew_prefix() -> Option<PathBuf> {
+    if !cfg!(unix) {
+        return None;
+    }
+    let from_env = crate::environment::non_empty_var(E_HOMEBREW_PREFIX)
+        .map(PathBuf::from)
+        .filter(|prefix| prefix.is_absolute());
+    match override_root::apply(from_env) {
+        Some(prefix) if prefix.is_dir() => Some(prefix),
+        _ => default_prefixes()
+            .iter()
+            .filter_map(|prefix| override_root::apply(Some(PathBuf::from(prefix))))
+            .find(|prefix| prefix.join(D_BIN).join(F_BREW).is_file()),
+    }
+}
+
+///
+/// Returns the path to the directory in which the Homebrew formula `formula` installs its shared
+/// data, `{prefix}/share/{formula}`; this is linked into the prefix from the formula's keg, and
+/// so does not depend on the installed version. This does not check that the formula is
+/// installed.
+///
+/// ```rust
+/// use xdirs::brew_share_dir_for;
+///
+/// if let Some(share) = brew_share_dir_for("ripgrep") {
+///     println!("ripgrep's data is in {:?}", share);
+/// }
+/// ```
+///
+pub fn brew_share_dir_for(formula: &str) -> Option<PathBuf> {
+    prefix_dir_for(D_SHARE, formula)
+}
+
+///
+/// Returns the path to the directory in the Homebrew Cellar, `{prefix}/Cellar/{formula}`, that
+/// holds a directory for each installed version of the formula `formula`. This does not check
+/// that the formula is installed.
+///
+pub fn brew_cellar_dir_for(formula: &str) -> Option<PathBuf> {
+    prefix_dir_for(D_CELLAR, formula)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const E_HOMEBREW_PREFIX: &str = "HOMEBREW_PREFIX";
+const D_BIN: &str = "bin";
+const D_CELLAR: &str = "Cellar";
+const D_SHARE: &str = "share";
+const F_BREW: &str = "brew";
+
+fn default_prefixes() -> &'static [&'static str] {
+    if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+        &["/opt/homebrew", "/usr/local"]
+    } else if cfg!(target_os = "macos") {
+        &["/usr/local", "/opt/homebrew"]
+    } else {
+        &["/home/linuxbrew/.linuxbrew"]
+    }
+}
+
+fn prefix_dir_for(dir: &str, formula: &str) -> Option<PathBuf> {
+    homebrew_prefix().map(|prefix| {
+        prefix
+            .join(dir)
+            .join(crate::sanitize_app_name(formula).as_ref())
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_homebrew_prefix() {
+        let root = std::env::temp_dir().join(format!("xdirs-homebrew-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let prefix = root.join(&default_prefixes()[0][1..]);
+        std::fs::create_dir_all(prefix.join(D_BIN)).unwrap();
+        std::fs::write(prefix.join(D_BIN).join(F_BREW), "").unwrap();
+        if crate::environment::var_os(E_HOMEBREW_PREFIX).is_none() {
+            assert_eq!(homebrew_prefix(), Some(prefix.clone()));
+            assert_eq!(
+                brew_share_dir_for("ripgrep"),
+                Some(prefix.join("share").join("ripgrep"))
+            );
+            assert_eq!(
+                brew_cellar_dir_for("../ripgrep"),
+                Some(prefix.join("Cellar").join(".._ripgrep"))
+            );
+        }
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
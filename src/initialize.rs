@@ -0,0 +1,324 @@
+/*!
+Provides the creation, in a single call, of the directories and initial files an application
+needs on its first run, from a declarative description of its layout.
+
+Each directory created is reported to the sink set with [`set_audit_sink`](fn.set_audit_sink.html).
+*/
+
+use crate::{create, DirKind, Error};
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The directories, and initial files within them, that an application needs; see
+/// [`initialize_app_dirs`](fn.initialize_app_dirs.html).
+///
+/// ```rust
+/// use xdirs::{AppLayout, DirKind};
+///
+/// let layout = AppLayout::new()
+///     .dir(DirKind::Cache)
+///     .dir(DirKind::Data)
+///     .dir(DirKind::Log)
+///     .file(DirKind::Config, "settings.toml", "# defaults\n");
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AppLayout {
+    dirs: Vec<DirKind>,
+    files: Vec<SeedFile>,
+}
+
+///
+/// The outcome of [`initialize_app_dirs`](fn.initialize_app_dirs.html).
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InitReport {
+    /// The directories and files of the layout created by this call.
+    pub created: Vec<PathBuf>,
+    /// The directories and files of the layout that already existed, and were left unchanged.
+    pub existing: Vec<PathBuf>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Create each directory of `layout` for a specific application, with the same semantics as
+/// [`create_dir_for`](fn.create_dir_for.html), and each of its files that does not already
+/// exist. Existing files are never replaced, so this may be called on every start of the
+/// application; [`InitReport::is_first_run`](struct.InitReport.html#method.is_first_run) tells
+/// whether anything already existed.
+///
+/// The name of a file may include subdirectories of its directory, which are created as
+/// needed, but if it is empty, absolute, or has a `.` or `..` component an error with the kind
+/// `InvalidInput` is returned before anything is created.
+///
+/// If a directory has no value on the current platform the returned error has the kind
+/// `NotFound`, and wraps an [`Error::Unavailable`](enum.Error.html#variant.Unavailable). An
+/// error stops the initialization, leaving anything already created in place.
+///
+/// ```rust,no_run
+/// use xdirs::{initialize_app_dirs, AppLayout, DirKind};
+///
+/// let layout = AppLayout::new()
+///     .dir(DirKind::Cache)
+///     .file_from(DirKind::Config, "settings.toml", "/usr/share/my-app/settings.toml");
+/// let report = initialize_app_dirs("MyApp", &layout).unwrap();
+/// if report.is_first_run() {
+///     println!("welcome to MyApp");
+/// }
+/// ```
+///
+pub fn initialize_app_dirs(app: &str, layout: &AppLayout) -> io::Result<InitReport> {
+    for file in &layout.files {
+        check_file_name(&file.name)?;
+    }
+    let mut report = InitReport::default();
+    let mut dirs = HashMap::new();
+    for kind in &layout.dirs {
+        let path = kind.dir_for(app);
+        let existed = path.as_ref().is_some_and(|path| path.is_dir());
+        let path = create::ensure(*kind, path, kind.is_private(), REASON)?;
+        let _ = dirs.insert(*kind, path.clone());
+        report.add(path, existed);
+    }
+    for file in &layout.files {
+        // the file's directory was created above.
+        let path = dirs
+            .get(&file.kind)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, Error::Unavailable(file.kind)))?
+            .join(&file.name);
+        let existed = path.exists();
+        if !existed {
+            if let Some(parent) = path.parent() {
+                let _ = create::ensure(
+                    file.kind,
+                    Some(parent.to_path_buf()),
+                    file.kind.is_private(),
+                    REASON,
+                )?;
+            }
+            file.source.write_to(&path)?;
+        }
+        report.add(path, existed);
+    }
+    Ok(report)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SeedFile {
+    kind: DirKind,
+    name: String,
+    source: Source,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Source {
+    Contents(Vec<u8>),
+    Template(PathBuf),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl AppLayout {
+    ///
+    /// Returns an empty layout.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Add the application-specific form of the directory `kind`.
+    ///
+    pub fn dir(mut self, kind: DirKind) -> Self {
+        if !self.dirs.contains(&kind) {
+            self.dirs.push(kind);
+        }
+        self
+    }
+
+    ///
+    /// Add the file `name`, with `contents`, in the directory `kind`, which is also added.
+    ///
+    pub fn file<C: Into<Vec<u8>>>(self, kind: DirKind, name: &str, contents: C) -> Self {
+        self.seed(kind, name, Source::Contents(contents.into()))
+    }
+
+    ///
+    /// Add the file `name` in the directory `kind`, which is also added, as a copy of the
+    /// file `template`.
+    ///
+    pub fn file_from<P: AsRef<Path>>(self, kind: DirKind, name: &str, template: P) -> Self {
+        self.seed(
+            kind,
+            name,
+            Source::Template(template.as_ref().to_path_buf()),
+        )
+    }
+
+    ///
+    /// Returns the kinds of directory in this layout, in the order they are created.
+    ///
+    pub fn dirs(&self) -> &[DirKind] {
+        &self.dirs
+    }
+
+    fn seed(mut self, kind: DirKind, name: &str, source: Source) -> Self {
+        self = self.dir(kind);
+        self.files.push(SeedFile {
+            kind,
+            name: name.to_string(),
+            source,
+        });
+        self
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl InitReport {
+    ///
+    /// Returns `true` if none of the layout existed before the call, as on an application's first
+    /// run.
+    ///
+    pub fn is_first_run(&self) -> bool {
+        self.existing.is_empty()
+    }
+
+    fn add(&mut self, path: PathBuf, existed: bool) {
+        if existed {
+            self.existing.push(path);
+        } else {
+            self.created.push(path);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Source {
+    fn write_to(&self, path: &Path) -> io::Result<()> {
+        let contents = match self {
+            Source::Contents(contents) => contents.clone(),
+            Source::Template(template) => std::fs::read(template)?,
+        };
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)?
+            .write_all(&contents)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const REASON: &str = "initialize_app_dirs";
+
+///
+/// Returns an error unless `name` is a relative path that stays within its directory.
+///
+fn check_file_name(name: &str) -> io::Result<()> {
+    let path = Path::new(name);
+    if name.is_empty()
+        || !path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{:?} is not a relative file name", name),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize_app_dirs() {
+        let root = std::env::temp_dir().join(format!("xdirs-initialize-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let layout = AppLayout::new().dir(DirKind::Data).file(
+            DirKind::Config,
+            "settings.toml",
+            "theme = 1\n",
+        );
+        assert_eq!(layout.dirs(), &[DirKind::Data, DirKind::Config]);
+
+        let config = crate::config_dir_for("Chrome").unwrap();
+        let settings = config.join("settings.toml");
+        let report = initialize_app_dirs("Chrome", &layout).unwrap();
+        assert!(report.is_first_run());
+        assert!(report.created.contains(&settings));
+        assert_eq!(std::fs::read_to_string(&settings).unwrap(), "theme = 1\n");
+
+        std::fs::write(&settings, "theme = 2\n").unwrap();
+        let report = initialize_app_dirs("Chrome", &layout).unwrap();
+        assert!(!report.is_first_run());
+        assert!(report.created.is_empty());
+        assert!(report.existing.contains(&settings));
+        assert_eq!(std::fs::read_to_string(&settings).unwrap(), "theme = 2\n");
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_initialize_nested_file() {
+        let root = std::env::temp_dir().join(format!("xdirs-init-nested-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let layout = AppLayout::new().file(DirKind::Config, "profiles/default.toml", "");
+        let report = initialize_app_dirs("Chrome", &layout).unwrap();
+        let profile = crate::config_dir_for("Chrome")
+            .unwrap()
+            .join("profiles")
+            .join("default.toml");
+        assert!(profile.is_file());
+        assert!(report.created.contains(&profile));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_initialize_rejects_escaping_names() {
+        let root = std::env::temp_dir().join(format!("xdirs-init-escape-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let escaping = std::env::temp_dir().join("escaped.toml");
+        for name in [
+            "",
+            "../escaped.toml",
+            "a/../../escaped.toml",
+            "./settings.toml",
+        ]
+        .iter()
+        .copied()
+        .chain(escaping.to_str())
+        {
+            let layout = AppLayout::new().file(DirKind::Config, name, "");
+            let error = initialize_app_dirs("Chrome", &layout).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{:?}", name);
+        }
+        assert!(!root.exists());
+    }
+}
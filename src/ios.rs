@@ -0,0 +1,98 @@
+pub use crate::environment::home_dir;
+pub use crate::unsupported::{
+    application_dir, application_scripts_dir_for, application_shared_dir, autostart_dir,
+    crash_dumps_dir, crash_reports_dir, desktop_dir, desktop_entry_dir, download_dir,
+    executable_dir, extra_plugin_dirs_for, fallback_source, favorites_dir, flatpak_id, font_dir,
+    glib_schemas_dir, group_container_dir_for, host_home_dir, icloud_container_dir_for,
+    icloud_drive_dir, icons_dir, installed_container_dir_for, keyfile_backend_dir,
+    known_folder_dir, launch_agents_dir, launch_daemons_dir, man_dir, mime_dir, package_dir_for,
+    package_family_name, pipe_name_for, public_data_dir, public_dir, recent_dir,
+    registered_install_dir_for, runtime_dir, sandbox_container_dir_for, sandbox_container_id,
+    sandboxed_app_dir, saved_games_dir, shared_documents_dir, snap_name, snap_user_common_dir,
+    system_autostart_dir, system_crash_reports_dir, system_desktop_entry_dir, system_font_dir,
+    system_glib_schemas_dir, system_launch_agents_dir, system_locale_dirs, system_log_dir,
+    system_man_dir, system_mime_dir, system_template_dir, system_wallpaper_dir, template_dir,
+    themes_dir, trash_dir, user_application_dir, user_application_shared_dir, user_id,
+    wallpaper_dir,
+};
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn cache_dir() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(D_CACHES))
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(D_APPLICATION_SUPPORT))
+}
+
+pub fn config_local_dir() -> Option<PathBuf> {
+    config_dir()
+}
+
+pub fn data_dir() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(D_APPLICATION_SUPPORT))
+}
+
+pub fn data_local_dir() -> Option<PathBuf> {
+    data_dir()
+}
+
+pub fn document_dir() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(D_DOCUMENTS))
+}
+
+pub fn log_dir() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(D_LOGS))
+}
+
+pub fn preference_dir() -> Option<PathBuf> {
+    home_dir().map(|h| h.join(D_PREFERENCES))
+}
+
+pub fn state_dir() -> Option<PathBuf> {
+    data_local_dir()
+}
+
+pub fn is_remote(_path: &Path) -> bool {
+    false
+}
+
+pub fn temp_dir() -> Option<PathBuf> {
+    Some(std::env::temp_dir())
+}
+
+pub fn system_config_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn system_data_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn system_domain_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn service_dirs_for(_: &str) -> crate::ServiceDirs {
+    crate::ServiceDirs::default()
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub fn uses_system_journal() -> bool {
+    true
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_APPLICATION_SUPPORT: &str = "Library/Application Support";
+const D_CACHES: &str = "Library/Caches";
+const D_DOCUMENTS: &str = "Documents";
+const D_LOGS: &str = "Library/Logs";
+const D_PREFERENCES: &str = "Library/Preferences";
@@ -0,0 +1,138 @@
+/*!
+Provides the resolution of an application's directory once, for code that then forms many paths
+within it, such as a cache mapping thousands of keys to files, without repeating the resolution
+for each.
+*/
+
+use crate::DirKind;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A resolved directory that forms paths within it; each call to [`join`](#method.join) makes a
+/// single allocation of the required size, and [`join_into`](#method.join_into) reuses the
+/// caller's buffer. The directory is not resolved again, so a joiner does not see later changes
+/// to the environment, or an [`override_root`](fn.override_root.html) made after it was created.
+///
+/// ```rust
+/// use xdirs::{dir_joiner_for, DirKind};
+///
+/// if let Some(cache) = dir_joiner_for(DirKind::Cache, "MyApp") {
+///     let mut path = std::path::PathBuf::new();
+///     for key in ["a1f0", "b7c3", "e9d2"] {
+///         cache.join_into(key, &mut path);
+///         assert!(path.ends_with(key));
+///     }
+/// }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DirJoiner {
+    base: PathBuf,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns a joiner for the application-specific form of `kind`, as returned by
+/// [`DirKind::dir_for`](enum.DirKind.html#method.dir_for), or `None` if it has no value on the
+/// current platform.
+///
+pub fn dir_joiner_for(kind: DirKind, app: &str) -> Option<DirJoiner> {
+    kind.dir_for(app).map(DirJoiner::new)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl DirJoiner {
+    ///
+    /// Returns a joiner for the directory `base`.
+    ///
+    pub fn new<P: Into<PathBuf>>(base: P) -> Self {
+        Self { base: base.into() }
+    }
+
+    ///
+    /// Returns the directory that paths are formed within.
+    ///
+    pub fn base(&self) -> &Path {
+        &self.base
+    }
+
+    ///
+    /// Returns the path of `child` within the directory, as `Path::join` does. `child` is not
+    /// checked, so a name from an untrusted source should first be made safe with
+    /// [`sanitize_app_name`](fn.sanitize_app_name.html).
+    ///
+    pub fn join<P: AsRef<Path>>(&self, child: P) -> PathBuf {
+        let child = child.as_ref();
+        let capacity = self.base.as_os_str().len() + child.as_os_str().len() + 1;
+        let mut path = PathBuf::with_capacity(capacity);
+        path.push(&self.base);
+        path.push(child);
+        path
+    }
+
+    ///
+    /// Replace the contents of `buffer` with the path of `child` within the directory, as for
+    /// [`join`](#method.join); once `buffer` has grown to the size of the longest path no
+    /// further allocation is made.
+    ///
+    pub fn join_into<P: AsRef<Path>>(&self, child: P, buffer: &mut PathBuf) {
+        let mut path = std::mem::take(buffer).into_os_string();
+        path.clear();
+        path.push(self.base.as_os_str());
+        *buffer = PathBuf::from(path);
+        buffer.push(child);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<PathBuf> for DirJoiner {
+    fn from(base: PathBuf) -> Self {
+        Self::new(base)
+    }
+}
+
+impl AsRef<Path> for DirJoiner {
+    fn as_ref(&self) -> &Path {
+        &self.base
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir_joiner() {
+        let cache = dir_joiner_for(DirKind::Cache, "Chrome");
+        assert_eq!(
+            cache.as_ref().map(|cache| cache.base().to_path_buf()),
+            crate::cache_dir_for("Chrome")
+        );
+
+        let joiner = DirJoiner::new("/var/cache/chrome");
+        assert_eq!(
+            joiner.join("a1f0"),
+            Path::new("/var/cache/chrome").join("a1f0")
+        );
+        let mut buffer = PathBuf::from("/a/much/longer/path/than/the/joined/path/to/reuse");
+        let capacity = buffer.capacity();
+        joiner.join_into("b7c3", &mut buffer);
+        assert_eq!(buffer, Path::new("/var/cache/chrome").join("b7c3"));
+        assert_eq!(buffer.capacity(), capacity);
+    }
+}
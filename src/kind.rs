@@ -0,0 +1,548 @@
+/*!
+Provides an enumeration of the directory categories supported by this crate, allowing callers to
+select a directory by value rather than by function name.
+*/
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Identifies one of the directory categories supported by this crate. Each kind corresponds to a
+/// generic form (`{name}_dir`), an application-specific form (`{name}_dir_for`), or both.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum DirKind {
+    /// See [`application_dir`](fn.application_dir.html).
+    Application,
+    /// See [`application_shared_dir`](fn.application_shared_dir.html).
+    ApplicationShared,
+    /// See [`user_application_dir`](fn.user_application_dir.html).
+    UserApplication,
+    /// See [`user_application_shared_dir`](fn.user_application_shared_dir.html).
+    UserApplicationShared,
+    /// See [`app_container_dir_for`](fn.app_container_dir_for.html).
+    AppContainer,
+    /// See [`app_container_executable_dir_for`](fn.app_container_executable_dir_for.html).
+    AppContainerExecutable,
+    /// See [`app_container_resources_dir_for`](fn.app_container_resources_dir_for.html).
+    AppContainerResources,
+    /// See [`app_container_frameworks_dir_for`](fn.app_container_frameworks_dir_for.html).
+    AppContainerFrameworks,
+    /// See [`app_container_plugins_dir_for`](fn.app_container_plugins_dir_for.html).
+    AppContainerPlugins,
+    /// See [`user_app_container_dir_for`](fn.user_app_container_dir_for.html).
+    UserAppContainer,
+    /// See [`user_app_container_executable_dir_for`](fn.user_app_container_executable_dir_for.html).
+    UserAppContainerExecutable,
+    /// See [`user_app_container_resources_dir_for`](fn.user_app_container_resources_dir_for.html).
+    UserAppContainerResources,
+    /// See [`user_app_container_frameworks_dir_for`](fn.user_app_container_frameworks_dir_for.html).
+    UserAppContainerFrameworks,
+    /// See [`user_app_container_plugins_dir_for`](fn.user_app_container_plugins_dir_for.html).
+    UserAppContainerPlugins,
+    /// See [`cache_dir`](fn.cache_dir.html) and [`cache_dir_for`](fn.cache_dir_for.html).
+    Cache,
+    /// See [`config_dir`](fn.config_dir.html) and [`config_dir_for`](fn.config_dir_for.html).
+    Config,
+    /// See [`config_local_dir`](fn.config_local_dir.html) and
+    /// [`config_local_dir_for`](fn.config_local_dir_for.html).
+    ConfigLocal,
+    /// See [`data_dir`](fn.data_dir.html) and [`data_dir_for`](fn.data_dir_for.html).
+    Data,
+    /// See [`data_local_dir`](fn.data_local_dir.html) and
+    /// [`data_local_dir_for`](fn.data_local_dir_for.html).
+    DataLocal,
+    /// See [`favorites_dir`](fn.favorites_dir.html) and
+    /// [`favorites_dir_for`](fn.favorites_dir_for.html).
+    Favorites,
+    /// See [`log_dir`](fn.log_dir.html) and [`log_dir_for`](fn.log_dir_for.html).
+    Log,
+    /// See [`system_log_dir`](fn.system_log_dir.html) and
+    /// [`system_log_dir_for`](fn.system_log_dir_for.html).
+    SystemLog,
+    /// See [`preference_dir`](fn.preference_dir.html) and
+    /// [`preference_dir_for`](fn.preference_dir_for.html).
+    Preference,
+    /// See [`state_dir`](fn.state_dir.html) and [`state_dir_for`](fn.state_dir_for.html).
+    State,
+    /// See [`template_dir`](fn.template_dir.html) and
+    /// [`template_dir_for`](fn.template_dir_for.html).
+    Template,
+    /// See [`saved_games_dir`](fn.saved_games_dir.html) and
+    /// [`saved_games_dir_for`](fn.saved_games_dir_for.html).
+    SavedGames,
+    /// See [`icons_dir`](fn.icons_dir.html) and
+    /// [`icons_dir_for`](fn.icons_dir_for.html).
+    Icons,
+    /// See [`themes_dir`](fn.themes_dir.html) and
+    /// [`themes_dir_for`](fn.themes_dir_for.html).
+    Themes,
+    /// See [`mime_dir`](fn.mime_dir.html).
+    Mime,
+    /// See [`public_data_dir`](fn.public_data_dir.html) and
+    /// [`public_data_dir_for`](fn.public_data_dir_for.html).
+    PublicData,
+    /// See [`shared_documents_dir`](fn.shared_documents_dir.html) and
+    /// [`shared_documents_dir_for`](fn.shared_documents_dir_for.html).
+    SharedDocuments,
+    /// See [`wallpaper_dir`](fn.wallpaper_dir.html).
+    Wallpaper,
+    /// See [`glib_schemas_dir`](fn.glib_schemas_dir.html).
+    GlibSchemas,
+    /// See [`keyfile_backend_dir`](fn.keyfile_backend_dir.html).
+    KeyfileBackend,
+    /// See [`crash_dumps_dir`](fn.crash_dumps_dir.html) and
+    /// [`crash_dumps_dir_for`](fn.crash_dumps_dir_for.html).
+    CrashDumps,
+    /// See [`temp_dir`](fn.temp_dir.html) and [`temp_dir_for`](fn.temp_dir_for.html).
+    Temp,
+    /// See [`socket_dir_for`](fn.socket_dir_for.html).
+    Socket,
+    /// See [`lock_dir_for`](fn.lock_dir_for.html).
+    Lock,
+    /// See [`document_dir`](fn.document_dir.html) and
+    /// [`document_dir_for`](fn.document_dir_for.html).
+    Document,
+    /// See [`download_dir`](fn.download_dir.html) and
+    /// [`download_dir_for`](fn.download_dir_for.html).
+    Download,
+    /// See [`desktop_dir`](fn.desktop_dir.html) and
+    /// [`desktop_dir_for`](fn.desktop_dir_for.html).
+    Desktop,
+    /// See [`public_dir`](fn.public_dir.html) and
+    /// [`public_share_dir_for`](fn.public_share_dir_for.html).
+    PublicShare,
+    /// See [`history_dir_for`](fn.history_dir_for.html).
+    History,
+    /// See [`backup_dir_for`](fn.backup_dir_for.html).
+    Backup,
+    /// See [`secrets_dir_for`](fn.secrets_dir_for.html).
+    Secrets,
+    /// See [`plugins_dir_for`](fn.plugins_dir_for.html).
+    Plugins,
+    /// See [`system_plugins_dir_for`](fn.system_plugins_dir_for.html).
+    SystemPlugins,
+    /// See [`locale_dir_for`](fn.locale_dir_for.html).
+    Locale,
+    /// See [`system_template_dir`](fn.system_template_dir.html) and
+    /// [`system_template_dir_for`](fn.system_template_dir_for.html).
+    SystemTemplate,
+}
+
+///
+/// The error returned when parsing a string that does not name a [`DirKind`](enum.DirKind.html).
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDirKindError(String);
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns an iterator over the generic form of every kind that has one, in the order of
+/// [`DirKind::all`](enum.DirKind.html#method.all), paired with its value on the current platform.
+/// Each directory is resolved as the iterator reaches it.
+///
+/// ```rust
+/// for (kind, path) in xdirs::all_dirs() {
+///     println!("{:<24} {:?}", kind, path);
+/// }
+/// ```
+///
+pub fn all_dirs() -> impl Iterator<Item = (DirKind, Option<PathBuf>)> {
+    DirKind::all()
+        .filter(DirKind::has_generic_form)
+        .map(|kind| (kind, kind.dir()))
+}
+
+///
+/// Returns an iterator over the application-specific form of every kind that has one, for
+/// `app`, paired with its value on the current platform, as for [`all_dirs`](fn.all_dirs.html).
+///
+/// ```rust
+/// let existing: Vec<_> = xdirs::all_dirs_for("MyApp")
+///     .filter_map(|(_, path)| path)
+///     .filter(|path| path.is_dir())
+///     .collect();
+/// ```
+///
+pub fn all_dirs_for(app: &str) -> impl Iterator<Item = (DirKind, Option<PathBuf>)> + '_ {
+    DirKind::all()
+        .filter(DirKind::has_app_form)
+        .map(move |kind| (kind, kind.dir_for(app)))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+const ALL_KINDS: &[DirKind] = &[
+    DirKind::Application,
+    DirKind::ApplicationShared,
+    DirKind::UserApplication,
+    DirKind::UserApplicationShared,
+    DirKind::AppContainer,
+    DirKind::AppContainerExecutable,
+    DirKind::AppContainerResources,
+    DirKind::AppContainerFrameworks,
+    DirKind::AppContainerPlugins,
+    DirKind::UserAppContainer,
+    DirKind::UserAppContainerExecutable,
+    DirKind::UserAppContainerResources,
+    DirKind::UserAppContainerFrameworks,
+    DirKind::UserAppContainerPlugins,
+    DirKind::Cache,
+    DirKind::Config,
+    DirKind::ConfigLocal,
+    DirKind::Data,
+    DirKind::DataLocal,
+    DirKind::Favorites,
+    DirKind::Log,
+    DirKind::SystemLog,
+    DirKind::Preference,
+    DirKind::State,
+    DirKind::Template,
+    DirKind::SavedGames,
+    DirKind::Icons,
+    DirKind::Themes,
+    DirKind::Mime,
+    DirKind::PublicData,
+    DirKind::SharedDocuments,
+    DirKind::Wallpaper,
+    DirKind::GlibSchemas,
+    DirKind::KeyfileBackend,
+    DirKind::CrashDumps,
+    DirKind::Temp,
+    DirKind::Socket,
+    DirKind::Lock,
+    DirKind::Document,
+    DirKind::Download,
+    DirKind::Desktop,
+    DirKind::PublicShare,
+    DirKind::History,
+    DirKind::Backup,
+    DirKind::Secrets,
+    DirKind::Plugins,
+    DirKind::SystemPlugins,
+    DirKind::Locale,
+    DirKind::SystemTemplate,
+];
+
+impl Display for DirKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for DirKind {
+    type Err = ParseDirKindError;
+
+    ///
+    /// Parse a kind from its name, as returned by [`name`](enum.DirKind.html#method.name); dashes
+    /// are accepted in place of underscores, so both `data_local` and `data-local` are valid.
+    ///
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.replace('-', "_");
+        Self::all()
+            .find(|kind| kind.name() == name)
+            .ok_or_else(|| ParseDirKindError(s.to_string()))
+    }
+}
+
+impl DirKind {
+    ///
+    /// Returns an iterator over all directory kinds, in the order they are documented in the
+    /// crate root.
+    ///
+    pub fn all() -> impl Iterator<Item = DirKind> {
+        ALL_KINDS.iter().copied()
+    }
+
+    ///
+    /// Returns the name of this kind; this is the name of the corresponding function without the
+    /// `_dir` or `_dir_for` suffix, e.g. `data_local` for `data_local_dir`.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            DirKind::Application => "application",
+            DirKind::ApplicationShared => "application_shared",
+            DirKind::UserApplication => "user_application",
+            DirKind::UserApplicationShared => "user_application_shared",
+            DirKind::AppContainer => "app_container",
+            DirKind::AppContainerExecutable => "app_container_executable",
+            DirKind::AppContainerResources => "app_container_resources",
+            DirKind::AppContainerFrameworks => "app_container_frameworks",
+            DirKind::AppContainerPlugins => "app_container_plugins",
+            DirKind::UserAppContainer => "user_app_container",
+            DirKind::UserAppContainerExecutable => "user_app_container_executable",
+            DirKind::UserAppContainerResources => "user_app_container_resources",
+            DirKind::UserAppContainerFrameworks => "user_app_container_frameworks",
+            DirKind::UserAppContainerPlugins => "user_app_container_plugins",
+            DirKind::Cache => "cache",
+            DirKind::Config => "config",
+            DirKind::ConfigLocal => "config_local",
+            DirKind::Data => "data",
+            DirKind::DataLocal => "data_local",
+            DirKind::Favorites => "favorites",
+            DirKind::Log => "log",
+            DirKind::SystemLog => "system_log",
+            DirKind::Preference => "preference",
+            DirKind::State => "state",
+            DirKind::Template => "template",
+            DirKind::SavedGames => "saved_games",
+            DirKind::Icons => "icons",
+            DirKind::Themes => "themes",
+            DirKind::Mime => "mime",
+            DirKind::PublicData => "public_data",
+            DirKind::SharedDocuments => "shared_documents",
+            DirKind::Wallpaper => "wallpaper",
+            DirKind::GlibSchemas => "glib_schemas",
+            DirKind::KeyfileBackend => "keyfile_backend",
+            DirKind::CrashDumps => "crash_dumps",
+            DirKind::Temp => "temp",
+            DirKind::Socket => "socket",
+            DirKind::Lock => "lock",
+            DirKind::Document => "document",
+            DirKind::Download => "download",
+            DirKind::Desktop => "desktop",
+            DirKind::PublicShare => "public_share",
+            DirKind::History => "history",
+            DirKind::Backup => "backup",
+            DirKind::Secrets => "secrets",
+            DirKind::Plugins => "plugins",
+            DirKind::SystemPlugins => "system_plugins",
+            DirKind::Locale => "locale",
+            DirKind::SystemTemplate => "system_template",
+        }
+    }
+
+    ///
+    /// Returns `true` if this kind has a generic form, `{name}_dir()`.
+    ///
+    pub fn has_generic_form(&self) -> bool {
+        !matches!(
+            self,
+            DirKind::AppContainer
+                | DirKind::AppContainerExecutable
+                | DirKind::AppContainerResources
+                | DirKind::AppContainerFrameworks
+                | DirKind::AppContainerPlugins
+                | DirKind::UserAppContainer
+                | DirKind::UserAppContainerExecutable
+                | DirKind::UserAppContainerResources
+                | DirKind::UserAppContainerFrameworks
+                | DirKind::UserAppContainerPlugins
+                | DirKind::Socket
+                | DirKind::Lock
+                | DirKind::History
+                | DirKind::Backup
+                | DirKind::Secrets
+                | DirKind::Plugins
+                | DirKind::SystemPlugins
+                | DirKind::Locale
+        )
+    }
+
+    ///
+    /// Returns `true` if this kind has an application-specific form, `{name}_dir_for(app)`.
+    ///
+    pub fn has_app_form(&self) -> bool {
+        !matches!(
+            self,
+            DirKind::ApplicationShared
+                | DirKind::UserApplicationShared
+                | DirKind::Mime
+                | DirKind::Wallpaper
+                | DirKind::GlibSchemas
+                | DirKind::KeyfileBackend
+        )
+    }
+
+    ///
+    /// Returns `true` if the application-specific form of this kind is created such that it is
+    /// only accessible by the current user. This is the secrets directory, and the cache
+    /// directory, which may hold copies of sensitive content, such as downloaded documents or
+    /// session data, and which on some platforms is beneath a directory that other users may read.
+    ///
+    pub fn is_private(&self) -> bool {
+        matches!(self, DirKind::Cache | DirKind::Secrets)
+    }
+
+    ///
+    /// Returns the generic form of this directory kind, or `None` if either the kind has no
+    /// generic form or it has no value on the current platform.
+    ///
+    pub fn dir(&self) -> Option<PathBuf> {
+        match self {
+            DirKind::Application => crate::application_dir(),
+            DirKind::ApplicationShared => crate::application_shared_dir(),
+            DirKind::UserApplication => crate::user_application_dir(),
+            DirKind::UserApplicationShared => crate::user_application_shared_dir(),
+            DirKind::Cache => crate::cache_dir(),
+            DirKind::Config => crate::config_dir(),
+            DirKind::ConfigLocal => crate::config_local_dir(),
+            DirKind::Data => crate::data_dir(),
+            DirKind::DataLocal => crate::data_local_dir(),
+            DirKind::Favorites => crate::favorites_dir(),
+            DirKind::Log => crate::log_dir(),
+            DirKind::SystemLog => crate::system_log_dir(),
+            DirKind::Preference => crate::preference_dir(),
+            DirKind::State => crate::state_dir(),
+            DirKind::Template => crate::template_dir(),
+            DirKind::SavedGames => crate::saved_games_dir(),
+            DirKind::Icons => crate::icons_dir(),
+            DirKind::Themes => crate::themes_dir(),
+            DirKind::Mime => crate::mime_dir(),
+            DirKind::PublicData => crate::public_data_dir(),
+            DirKind::SharedDocuments => crate::shared_documents_dir(),
+            DirKind::Wallpaper => crate::wallpaper_dir(),
+            DirKind::GlibSchemas => crate::glib_schemas_dir(),
+            DirKind::KeyfileBackend => crate::keyfile_backend_dir(),
+            DirKind::CrashDumps => crate::crash_dumps_dir(),
+            DirKind::Temp => crate::temp_dir(),
+            DirKind::Document => crate::document_dir(),
+            DirKind::Download => crate::download_dir(),
+            DirKind::Desktop => crate::desktop_dir(),
+            DirKind::PublicShare => crate::public_dir(),
+            DirKind::SystemTemplate => crate::system_template_dir(),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the application-specific form of this directory kind, or `None` if either the kind
+    /// has no application-specific form or it has no value on the current platform.
+    ///
+    pub fn dir_for(&self, app: &str) -> Option<PathBuf> {
+        match self {
+            DirKind::Application => crate::application_dir_for(app),
+            DirKind::UserApplication => crate::user_application_dir_for(app),
+            DirKind::AppContainer => crate::app_container_dir_for(app),
+            DirKind::AppContainerExecutable => crate::app_container_executable_dir_for(app),
+            DirKind::AppContainerResources => crate::app_container_resources_dir_for(app),
+            DirKind::AppContainerFrameworks => crate::app_container_frameworks_dir_for(app),
+            DirKind::AppContainerPlugins => crate::app_container_plugins_dir_for(app),
+            DirKind::UserAppContainer => crate::user_app_container_dir_for(app),
+            DirKind::UserAppContainerExecutable => {
+                crate::user_app_container_executable_dir_for(app)
+            }
+            DirKind::UserAppContainerResources => crate::user_app_container_resources_dir_for(app),
+            DirKind::UserAppContainerFrameworks => {
+                crate::user_app_container_frameworks_dir_for(app)
+            }
+            DirKind::UserAppContainerPlugins => crate::user_app_container_plugins_dir_for(app),
+            DirKind::Cache => crate::cache_dir_for(app),
+            DirKind::Config => crate::config_dir_for(app),
+            DirKind::ConfigLocal => crate::config_local_dir_for(app),
+            DirKind::Data => crate::data_dir_for(app),
+            DirKind::DataLocal => crate::data_local_dir_for(app),
+            DirKind::Favorites => crate::favorites_dir_for(app),
+            DirKind::Log => crate::log_dir_for(app),
+            DirKind::SystemLog => crate::system_log_dir_for(app),
+            DirKind::Preference => crate::preference_dir_for(app),
+            DirKind::State => crate::state_dir_for(app),
+            DirKind::Template => crate::template_dir_for(app),
+            DirKind::SavedGames => crate::saved_games_dir_for(app),
+            DirKind::Icons => crate::icons_dir_for(app),
+            DirKind::Themes => crate::themes_dir_for(app),
+            DirKind::PublicData => crate::public_data_dir_for(app),
+            DirKind::SharedDocuments => crate::shared_documents_dir_for(app),
+            DirKind::CrashDumps => crate::crash_dumps_dir_for(app),
+            DirKind::Temp => crate::temp_dir_for(app),
+            DirKind::Socket => crate::socket_dir_for(app),
+            DirKind::Lock => crate::lock_dir_for(app),
+            DirKind::Document => crate::document_dir_for(app),
+            DirKind::Download => crate::download_dir_for(app),
+            DirKind::Desktop => crate::desktop_dir_for(app),
+            DirKind::PublicShare => crate::public_share_dir_for(app),
+            DirKind::History => crate::history_dir_for(app),
+            DirKind::Backup => crate::backup_dir_for(app),
+            DirKind::Secrets => crate::secrets_dir_for(app),
+            DirKind::Plugins => crate::plugins_dir_for(app),
+            DirKind::SystemPlugins => crate::system_plugins_dir_for(app),
+            DirKind::Locale => crate::locale_dir_for(app),
+            DirKind::SystemTemplate => crate::system_template_dir_for(app),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ParseDirKindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a known directory kind", self.0)
+    }
+}
+
+impl Error for ParseDirKindError {}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::DirKind;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_name_round_trip() {
+        for kind in DirKind::all() {
+            assert_eq!(DirKind::from_str(kind.name()), Ok(kind));
+            assert_eq!(DirKind::from_str(&kind.name().replace('_', "-")), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn test_is_private() {
+        assert_eq!(
+            DirKind::all()
+                .filter(DirKind::is_private)
+                .collect::<Vec<_>>(),
+            vec![DirKind::Cache, DirKind::Secrets]
+        );
+    }
+
+    #[test]
+    fn test_unknown_name() {
+        assert!(DirKind::from_str("nonesuch").is_err());
+    }
+
+    #[test]
+    fn test_all_dirs() {
+        assert!(super::all_dirs().all(|(kind, _)| kind.has_generic_form()));
+        assert!(super::all_dirs()
+            .any(|(kind, path)| kind == DirKind::Cache && path == crate::cache_dir()));
+        let kinds: Vec<DirKind> = super::all_dirs_for("Chrome")
+            .map(|(kind, _)| kind)
+            .collect();
+        assert!(!kinds.contains(&DirKind::ApplicationShared));
+        assert!(kinds.contains(&DirKind::AppContainer));
+        assert_eq!(
+            super::all_dirs_for("Chrome").find(|(kind, _)| *kind == DirKind::Config),
+            Some((DirKind::Config, crate::config_dir_for("Chrome")))
+        );
+    }
+
+    #[test]
+    fn test_forms_match_functions() {
+        assert_eq!(DirKind::Cache.dir(), crate::cache_dir());
+        assert_eq!(
+            DirKind::Cache.dir_for("Chrome"),
+            crate::cache_dir_for("Chrome")
+        );
+        assert_eq!(DirKind::AppContainer.dir(), None);
+        assert_eq!(DirKind::ApplicationShared.dir_for("Chrome"), None);
+        assert_eq!(DirKind::Mime.dir(), crate::mime_dir());
+        assert_eq!(DirKind::Mime.dir_for("Chrome"), None);
+    }
+}
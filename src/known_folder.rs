@@ -0,0 +1,144 @@
+/*!
+Provides access to the Windows known folders that this crate has no function of its own for, such
+as the user's ringtones, without a direct dependency on the Windows API.
+*/
+
+use crate::{override_root, sys};
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Identifies a Windows known folder, named as its `FOLDERID_` constant without the prefix.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FolderId {
+    /// The user's account pictures. (`FOLDERID_AccountPictures`)
+    AccountPictures,
+    /// The user's administrative tools, in the Start menu. (`FOLDERID_AdminTools`)
+    AdminTools,
+    /// The user's camera roll, beneath their pictures. (`FOLDERID_CameraRoll`)
+    CameraRoll,
+    /// The staging area for files to be written to CD. (`FOLDERID_CDBurning`)
+    CDBurning,
+    /// The administrative tools shared by all users, in the Start menu. (`FOLDERID_CommonAdminTools`)
+    CommonAdminTools,
+    /// The Start menu shared by all users. (`FOLDERID_CommonStartMenu`)
+    CommonStartMenu,
+    /// The document templates shared by all users. (`FOLDERID_CommonTemplates`)
+    CommonTemplates,
+    /// The user's contacts. (`FOLDERID_Contacts`)
+    Contacts,
+    /// The user's Internet Explorer cookies. (`FOLDERID_Cookies`)
+    Cookies,
+    /// The system's fonts. (`FOLDERID_Fonts`)
+    Fonts,
+    /// The user's Internet Explorer history. (`FOLDERID_History`)
+    History,
+    /// The user's temporary Internet files. (`FOLDERID_InternetCache`)
+    InternetCache,
+    /// The user's links, shown in the navigation pane of Explorer. (`FOLDERID_Links`)
+    Links,
+    /// The user's local application data, writable by low integrity processes. (`FOLDERID_LocalAppDataLow`)
+    LocalAppDataLow,
+    /// The user's music. (`FOLDERID_Music`)
+    Music,
+    /// The user's network shortcuts. (`FOLDERID_NetHood`)
+    NetHood,
+    /// The user's 3D objects. (`FOLDERID_Objects3D`)
+    Objects3D,
+    /// The root of the user's OneDrive, if it is configured. (`FOLDERID_OneDrive`)
+    OneDrive,
+    /// The user's pictures. (`FOLDERID_Pictures`)
+    Pictures,
+    /// The user's playlists, beneath their music. (`FOLDERID_Playlists`)
+    Playlists,
+    /// The user's printer shortcuts. (`FOLDERID_PrintHood`)
+    PrintHood,
+    /// The 32-bit program files, on a 64-bit system. (`FOLDERID_ProgramFilesX86`)
+    ProgramFilesX86,
+    /// The 32-bit common program files, on a 64-bit system. (`FOLDERID_ProgramFilesCommonX86`)
+    ProgramFilesCommonX86,
+    /// The desktop shared by all users. (`FOLDERID_PublicDesktop`)
+    PublicDesktop,
+    /// The documents shared by all users. (`FOLDERID_PublicDocuments`)
+    PublicDocuments,
+    /// The downloads shared by all users. (`FOLDERID_PublicDownloads`)
+    PublicDownloads,
+    /// The music shared by all users. (`FOLDERID_PublicMusic`)
+    PublicMusic,
+    /// The pictures shared by all users. (`FOLDERID_PublicPictures`)
+    PublicPictures,
+    /// The ringtones shared by all users. (`FOLDERID_PublicRingtones`)
+    PublicRingtones,
+    /// The videos shared by all users. (`FOLDERID_PublicVideos`)
+    PublicVideos,
+    /// The user's Quick Launch shortcuts. (`FOLDERID_QuickLaunch`)
+    QuickLaunch,
+    /// The user's ringtones. (`FOLDERID_Ringtones`)
+    Ringtones,
+    /// The user's saved pictures. (`FOLDERID_SavedPictures`)
+    SavedPictures,
+    /// The user's saved searches. (`FOLDERID_SavedSearches`)
+    SavedSearches,
+    /// The user's screenshots, beneath their pictures. (`FOLDERID_Screenshots`)
+    Screenshots,
+    /// The user's Send To menu. (`FOLDERID_SendTo`)
+    SendTo,
+    /// The user's Start menu. (`FOLDERID_StartMenu`)
+    StartMenu,
+    /// The Windows system directory, such as `C:\Windows\System32`. (`FOLDERID_System`)
+    System,
+    /// The 32-bit Windows system directory, on a 64-bit system. (`FOLDERID_SystemX86`)
+    SystemX86,
+    /// The user's items pinned to the taskbar. (`FOLDERID_UserPinned`)
+    UserPinned,
+    /// The directory containing every user's profile, such as `C:\Users`. (`FOLDERID_UserProfiles`)
+    UserProfiles,
+    /// The user's videos. (`FOLDERID_Videos`)
+    Videos,
+    /// The Windows directory, such as `C:\Windows`. (`FOLDERID_Windows`)
+    Windows,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path of the Windows known folder `id`, as returned by `SHGetKnownFolderPath`.
+/// Returns `None` if the folder is not defined on this system, or on any platform other than
+/// Windows.
+///
+/// ```rust
+/// use xdirs::{known_folder, FolderId};
+///
+/// if let Some(ringtones) = known_folder(FolderId::Ringtones) {
+///     println!("ringtones are in {:?}", ringtones);
+/// }
+/// ```
+///
+pub fn known_folder(id: FolderId) -> Option<PathBuf> {
+    override_root::apply(sys::known_folder_dir(id))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_folder() {
+        if cfg!(windows) {
+            assert!(known_folder(FolderId::Windows).is_some());
+        } else {
+            assert_eq!(known_folder(FolderId::Ringtones), None);
+        }
+    }
+}
@@ -0,0 +1,374 @@
+/*!
+The layout of application directories on Linux, and other Unix-like platforms, following the XDG base directory specification.
+*/
+
+use crate::{override_root, DirKind, DirectoryProvider, NestingScheme};
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+// The default locations of the generic directories, used where they are resolved for a home
+// directory other than the current user's; home directories are created beneath HOME_PARENT,
+// HOME_DIRS are relative to the home directory, and SYSTEM_DIRS to the file system root.
+
+pub(crate) const HOME_PARENT: &[&str] = &["home"];
+
+pub(crate) const HOME_DIRS: &[(DirKind, &[&str])] = &[
+    (DirKind::UserApplication, &[".local", "opt"]),
+    (DirKind::Cache, &[".cache"]),
+    (DirKind::Config, &[".config"]),
+    (DirKind::ConfigLocal, &[".config"]),
+    (DirKind::Data, &[".local", "share"]),
+    (DirKind::DataLocal, &[".local", "share"]),
+    (DirKind::Preference, &[".config"]),
+    (DirKind::State, &[".local", "state"]),
+    (DirKind::Favorites, &[".local", "share", "favorites"]),
+    (DirKind::Template, &["Templates"]),
+    (DirKind::Icons, &[".local", "share", "icons"]),
+    (DirKind::Themes, &[".local", "share", "themes"]),
+    (DirKind::Mime, &[".local", "share", "mime"]),
+    (DirKind::Wallpaper, &[".local", "share", "backgrounds"]),
+    (
+        DirKind::GlibSchemas,
+        &[".local", "share", "glib-2.0", "schemas"],
+    ),
+    (
+        DirKind::KeyfileBackend,
+        &[".config", "glib-2.0", "settings"],
+    ),
+    (DirKind::CrashDumps, &[".local", "state"]),
+    (DirKind::Document, &["Documents"]),
+    (DirKind::Download, &["Downloads"]),
+    (DirKind::Desktop, &["Desktop"]),
+    (DirKind::PublicShare, &["Public"]),
+];
+
+pub(crate) const SYSTEM_DIRS: &[(DirKind, &[&str])] = &[
+    (DirKind::Application, &["opt"]),
+    (DirKind::SystemLog, &["var", "log"]),
+    (DirKind::PublicData, &["usr", "share"]),
+    (DirKind::Temp, &["tmp"]),
+    (DirKind::SystemTemplate, &["usr", "share", "templates"]),
+];
+
+// The environment variables that relocate the generic directories, where they are resolved
+// against an explicit environment; each is relative to the variable's value.
+pub(crate) const ENV_DIRS: &[(DirKind, &str, &[&str])] = &[
+    (DirKind::Cache, "XDG_CACHE_HOME", &[]),
+    (DirKind::Config, "XDG_CONFIG_HOME", &[]),
+    (DirKind::ConfigLocal, "XDG_CONFIG_HOME", &[]),
+    (DirKind::Data, "XDG_DATA_HOME", &[]),
+    (DirKind::DataLocal, "XDG_DATA_HOME", &[]),
+    (DirKind::Preference, "XDG_CONFIG_HOME", &[]),
+    (DirKind::State, "XDG_STATE_HOME", &[]),
+    (DirKind::Favorites, "XDG_DATA_HOME", &["favorites"]),
+    (DirKind::Icons, "XDG_DATA_HOME", &["icons"]),
+    (DirKind::Themes, "XDG_DATA_HOME", &["themes"]),
+    (DirKind::Mime, "XDG_DATA_HOME", &["mime"]),
+    (DirKind::Wallpaper, "XDG_DATA_HOME", &["backgrounds"]),
+    (
+        DirKind::GlibSchemas,
+        "XDG_DATA_HOME",
+        &["glib-2.0", "schemas"],
+    ),
+    (
+        DirKind::KeyfileBackend,
+        "XDG_CONFIG_HOME",
+        &["glib-2.0", "settings"],
+    ),
+    (DirKind::CrashDumps, "XDG_STATE_HOME", &[]),
+    (DirKind::Temp, "TMPDIR", &[]),
+];
+
+///
+/// Returns the application-specific form of `kind` for the application directory name `app`,
+/// laid out beneath the generic directories of `dirs`.
+///
+pub(crate) fn dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    kind: DirKind,
+    app: &Path,
+    scheme: NestingScheme,
+) -> Option<PathBuf> {
+    match kind {
+        DirKind::AppContainer => app_container_dir_for(dirs, app),
+        DirKind::AppContainerExecutable => app_container_executable_dir_for(dirs, app),
+        DirKind::UserAppContainer => user_app_container_dir_for(dirs, app),
+        DirKind::UserAppContainerExecutable => user_app_container_executable_dir_for(dirs, app),
+        DirKind::Cache => cache_dir_for(dirs, app),
+        DirKind::Config => config_dir_for(dirs, app),
+        DirKind::ConfigLocal => config_local_dir_for(dirs, app),
+        DirKind::Data => data_dir_for(dirs, app),
+        DirKind::DataLocal => data_local_dir_for(dirs, app),
+        DirKind::Favorites => favorites_dir_for(dirs, app, scheme),
+        DirKind::Log => log_dir_for(dirs, app),
+        DirKind::SystemLog => system_log_dir_for(dirs, app),
+        DirKind::Preference => preference_dir_for(dirs, app),
+        DirKind::State => state_dir_for(dirs, app),
+        DirKind::Template => template_dir_for(dirs, app),
+        DirKind::SavedGames => saved_games_dir_for(dirs, app),
+        DirKind::Icons => icons_dir_for(dirs, app),
+        DirKind::Themes => themes_dir_for(dirs, app),
+        DirKind::PublicData => public_data_dir_for(dirs, app),
+        DirKind::SharedDocuments => shared_documents_dir_for(dirs, app),
+        DirKind::CrashDumps => crash_dumps_dir_for(dirs, app),
+        DirKind::Temp => temp_dir_for(dirs, app),
+        DirKind::Socket => socket_dir_for(dirs, app),
+        DirKind::Lock => lock_dir_for(dirs, app),
+        DirKind::Document => document_dir_for(dirs, app),
+        DirKind::Download => download_dir_for(dirs, app),
+        DirKind::Desktop => desktop_dir_for(dirs, app),
+        DirKind::PublicShare => public_share_dir_for(dirs, app),
+        DirKind::History => history_dir_for(dirs, app),
+        DirKind::Backup => backup_dir_for(dirs, app),
+        DirKind::Secrets => secrets_dir_for(dirs, app),
+        DirKind::Plugins => plugins_dir_for(dirs, app),
+        DirKind::SystemPlugins => system_plugins_dir_for(dirs, app),
+        DirKind::Locale => locale_dir_for(dirs, app),
+        DirKind::SystemTemplate => system_template_dir_for(dirs, app),
+        DirKind::Application => application_dir_for(dirs, app),
+        DirKind::UserApplication => user_application_dir_for(dirs, app),
+        DirKind::ApplicationShared
+        | DirKind::UserApplicationShared
+        | DirKind::Mime
+        | DirKind::Wallpaper
+        | DirKind::GlibSchemas
+        | DirKind::KeyfileBackend
+        | DirKind::AppContainerResources
+        | DirKind::AppContainerFrameworks
+        | DirKind::AppContainerPlugins
+        | DirKind::UserAppContainerResources
+        | DirKind::UserAppContainerFrameworks
+        | DirKind::UserAppContainerPlugins => None,
+    }
+}
+
+pub(crate) fn minidump_path_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|path| path.join(D_CRASHES))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_BACKUPS: &str = "backups";
+const D_BIN: &str = "bin";
+const D_CRASHES: &str = "crashes";
+const D_FAVORITES: &str = "favorites";
+const D_HISTORY: &str = "history";
+const D_ICONS: &str = "icons";
+const D_LOCALE: &str = "locale";
+const D_LOGS: &str = "logs";
+const D_PLUGINS: &str = "plugins";
+const D_SAVES: &str = "saves";
+const D_SECRETS: &str = "secrets";
+const D_TEMPLATES: &str = "templates";
+
+fn application_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.application_dir().map(|path| path.join(app))
+}
+
+fn user_application_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    dirs.user_application_dir().map(|path| path.join(app))
+}
+
+fn app_container_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    application_dir_for(dirs, app)
+}
+
+fn app_container_executable_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    app_container_dir_for(dirs, app).map(|path| path.join(D_BIN))
+}
+
+fn user_app_container_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    user_application_dir_for(dirs, app)
+}
+
+fn user_app_container_executable_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    user_app_container_dir_for(dirs, app).map(|path| path.join(D_BIN))
+}
+
+fn cache_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.cache_dir().map(|path| path.join(app))
+}
+
+fn config_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.config_dir().map(|path| path.join(app))
+}
+
+fn config_local_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.config_local_dir().map(|path| path.join(app))
+}
+
+fn data_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.data_dir().map(|path| path.join(app))
+}
+
+fn data_local_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.data_local_dir().map(|path| path.join(app))
+}
+
+fn favorites_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+    scheme: NestingScheme,
+) -> Option<PathBuf> {
+    match scheme {
+        NestingScheme::PlatformDefault => {
+            // before version 0.2.0 the favorites were nested beneath the application's data, and
+            // are found there until they are moved.
+            let current = favorites_dir_for(dirs, app, NestingScheme::AppUnderKind);
+            let legacy = favorites_dir_for(dirs, app, NestingScheme::KindUnderApp);
+            match (current, legacy) {
+                (Some(current), Some(legacy))
+                    if !override_root::is_dir(&current) && override_root::is_dir(&legacy) =>
+                {
+                    Some(legacy)
+                }
+                (current, _) => current,
+            }
+        }
+        NestingScheme::KindUnderApp => {
+            data_local_dir_for(dirs, app).map(|path| path.join(D_FAVORITES))
+        }
+        NestingScheme::AppUnderKind => dirs
+            .favorites_dir()
+            .or_else(|| dirs.data_local_dir().map(|path| path.join(D_FAVORITES)))
+            .map(|path| path.join(app)),
+    }
+}
+
+fn log_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|path| path.join(D_LOGS))
+}
+
+fn system_log_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.system_log_dir().map(|path| path.join(app))
+}
+
+fn preference_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    config_dir_for(dirs, app)
+}
+
+fn state_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.state_dir().map(|path| path.join(app))
+}
+
+fn template_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    config_dir_for(dirs, app).map(|d| d.join(D_TEMPLATES))
+}
+
+fn saved_games_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|path| path.join(D_SAVES))
+}
+
+fn icons_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, _: &Path) -> Option<PathBuf> {
+    // applications install their icons into the themes they extend, such as hicolor, rather than
+    // into a directory of their own.
+    dirs.data_dir().map(|path| path.join(D_ICONS))
+}
+
+fn themes_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.themes_dir().map(|path| path.join(app))
+}
+
+fn public_data_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.public_data_dir().map(|path| path.join(app))
+}
+
+fn shared_documents_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    dirs.shared_documents_dir().map(|path| path.join(app))
+}
+
+fn crash_dumps_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.crash_dumps_dir()
+        .map(|path| path.join(app).join(D_CRASHES))
+}
+
+fn temp_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    // the temporary directory is shared by all users, and so the directory is named for the user
+    // as well as the application.
+    dirs.temp_dir().map(|path| match crate::sys::user_id() {
+        Some(user) => {
+            let mut name = app.as_os_str().to_os_string();
+            name.push(format!("-{}", user));
+            path.join(name)
+        }
+        None => path.join(app),
+    })
+}
+
+fn socket_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.runtime_dir()
+        .map(|path| path.join(app))
+        .or_else(|| temp_dir_for(dirs, app))
+}
+
+fn lock_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    socket_dir_for(dirs, app)
+}
+
+fn document_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.document_dir().map(|path| path.join(app))
+}
+
+fn download_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.download_dir().map(|path| path.join(app))
+}
+
+fn desktop_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.desktop_dir().map(|path| path.join(app))
+}
+
+fn public_share_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.public_dir().map(|path| path.join(app))
+}
+
+fn history_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    state_dir_for(dirs, app).map(|path| path.join(D_HISTORY))
+}
+
+fn backup_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    state_dir_for(dirs, app).map(|path| path.join(D_BACKUPS))
+}
+
+fn secrets_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|path| path.join(D_SECRETS))
+}
+
+fn plugins_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|path| path.join(D_PLUGINS))
+}
+
+fn system_plugins_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.system_data_dir()
+        .map(|path| path.join(app).join(D_PLUGINS))
+}
+
+fn locale_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|path| path.join(D_LOCALE))
+}
+
+fn system_template_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.system_template_dir().map(|path| path.join(app))
+}
@@ -0,0 +1,338 @@
+/*!
+The layout of application directories on macOS, following Apple's File System Programming Guide.
+*/
+
+use crate::{DirKind, DirectoryProvider, NestingScheme};
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+// The default locations of the generic directories, used where they are resolved for a home
+// directory other than the current user's; home directories are created beneath HOME_PARENT,
+// HOME_DIRS are relative to the home directory, and SYSTEM_DIRS to the file system root.
+
+pub(crate) const HOME_PARENT: &[&str] = &["Users"];
+
+pub(crate) const HOME_DIRS: &[(DirKind, &[&str])] = &[
+    (DirKind::UserApplication, &["Applications"]),
+    (DirKind::UserApplicationShared, &["Library", "Frameworks"]),
+    (DirKind::Cache, &["Library", "Caches"]),
+    (DirKind::Config, &["Library", "Application Support"]),
+    (DirKind::ConfigLocal, &["Library", "Application Support"]),
+    (DirKind::Data, &["Library", "Application Support"]),
+    (DirKind::DataLocal, &["Library", "Application Support"]),
+    (DirKind::Favorites, &["Library", "Favorites"]),
+    (DirKind::Log, &["Library", "Logs"]),
+    (DirKind::Preference, &["Library", "Preferences"]),
+    (DirKind::State, &["Library", "Application Support"]),
+    (DirKind::Wallpaper, &["Library", "Desktop Pictures"]),
+    (
+        DirKind::CrashDumps,
+        &["Library", "Logs", "DiagnosticReports"],
+    ),
+    (DirKind::Document, &["Documents"]),
+    (DirKind::Download, &["Downloads"]),
+    (DirKind::Desktop, &["Desktop"]),
+    (DirKind::PublicShare, &["Public"]),
+];
+
+pub(crate) const SYSTEM_DIRS: &[(DirKind, &[&str])] = &[
+    (DirKind::Application, &["Applications"]),
+    (DirKind::ApplicationShared, &["Library", "Frameworks"]),
+    (DirKind::SystemLog, &["Library", "Logs"]),
+    (DirKind::PublicData, &["Users", "Shared"]),
+    (DirKind::SharedDocuments, &["Users", "Shared"]),
+];
+
+// No environment variables relocate the generic directories on macOS.
+pub(crate) const ENV_DIRS: &[(DirKind, &str, &[&str])] = &[];
+
+///
+/// Returns the application-specific form of `kind` for the application directory name `app`,
+/// laid out beneath the generic directories of `dirs`.
+///
+pub(crate) fn dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    kind: DirKind,
+    app: &Path,
+    scheme: NestingScheme,
+) -> Option<PathBuf> {
+    match kind {
+        DirKind::AppContainer => app_container_dir_for(dirs, app),
+        DirKind::AppContainerExecutable => app_container_executable_dir_for(dirs, app),
+        DirKind::AppContainerResources => app_container_resources_dir_for(dirs, app),
+        DirKind::AppContainerFrameworks => app_container_frameworks_dir_for(dirs, app),
+        DirKind::AppContainerPlugins => app_container_plugins_dir_for(dirs, app),
+        DirKind::UserAppContainer => user_app_container_dir_for(dirs, app),
+        DirKind::UserAppContainerExecutable => user_app_container_executable_dir_for(dirs, app),
+        DirKind::UserAppContainerResources => user_app_container_resources_dir_for(dirs, app),
+        DirKind::UserAppContainerFrameworks => user_app_container_frameworks_dir_for(dirs, app),
+        DirKind::UserAppContainerPlugins => user_app_container_plugins_dir_for(dirs, app),
+        DirKind::Cache => cache_dir_for(dirs, app),
+        DirKind::Config => config_dir_for(dirs, app),
+        DirKind::ConfigLocal => config_local_dir_for(dirs, app),
+        DirKind::Data => data_dir_for(dirs, app),
+        DirKind::DataLocal => data_local_dir_for(dirs, app),
+        DirKind::Favorites => favorites_dir_for(dirs, app, scheme),
+        DirKind::Log => log_dir_for(dirs, app),
+        DirKind::SystemLog => system_log_dir_for(dirs, app),
+        DirKind::Preference => preference_dir_for(dirs, app),
+        DirKind::State => state_dir_for(dirs, app),
+        DirKind::Template => template_dir_for(dirs, app),
+        DirKind::SavedGames => saved_games_dir_for(dirs, app),
+        DirKind::Icons => icons_dir_for(dirs, app),
+        DirKind::Themes => themes_dir_for(dirs, app),
+        DirKind::PublicData => public_data_dir_for(dirs, app),
+        DirKind::SharedDocuments => shared_documents_dir_for(dirs, app),
+        DirKind::CrashDumps => crash_dumps_dir_for(dirs, app),
+        DirKind::Temp => temp_dir_for(dirs, app),
+        DirKind::Socket => socket_dir_for(dirs, app),
+        DirKind::Lock => lock_dir_for(dirs, app),
+        DirKind::Document => document_dir_for(dirs, app),
+        DirKind::Download => download_dir_for(dirs, app),
+        DirKind::Desktop => desktop_dir_for(dirs, app),
+        DirKind::PublicShare => public_share_dir_for(dirs, app),
+        DirKind::History => history_dir_for(dirs, app),
+        DirKind::Backup => backup_dir_for(dirs, app),
+        DirKind::Secrets => secrets_dir_for(dirs, app),
+        DirKind::Plugins => plugins_dir_for(dirs, app),
+        DirKind::SystemPlugins => system_plugins_dir_for(dirs, app),
+        DirKind::Locale => app_container_resources_dir_for(dirs, app),
+        DirKind::SystemTemplate => system_template_dir_for(dirs, app),
+        DirKind::Application => app_container_dir_for(dirs, app),
+        DirKind::UserApplication => user_app_container_dir_for(dirs, app),
+        DirKind::ApplicationShared
+        | DirKind::UserApplicationShared
+        | DirKind::Mime
+        | DirKind::Wallpaper
+        | DirKind::GlibSchemas
+        | DirKind::KeyfileBackend => None,
+    }
+}
+
+pub(crate) fn minidump_path_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|d| d.join("Crashes"))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_FAVORITES: &str = "Favorites";
+fn app_container_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.application_dir()
+        .map(|path| path.join(bundle_name(app)))
+}
+
+fn app_container_executable_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    app_container_dir_for(dirs, app).map(|a| a.join("Contents/MacOS"))
+}
+
+fn app_container_resources_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    app_container_dir_for(dirs, app).map(|a| a.join("Contents/Resources"))
+}
+
+fn app_container_frameworks_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    app_container_dir_for(dirs, app).map(|a| a.join("Contents/Frameworks"))
+}
+
+fn app_container_plugins_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    app_container_dir_for(dirs, app).map(|a| a.join("Contents/PlugIns"))
+}
+
+fn user_app_container_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    dirs.user_application_dir()
+        .map(|path| path.join(bundle_name(app)))
+}
+
+fn user_app_container_executable_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    user_app_container_dir_for(dirs, app).map(|a| a.join("Contents/MacOS"))
+}
+
+fn user_app_container_resources_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    user_app_container_dir_for(dirs, app).map(|a| a.join("Contents/Resources"))
+}
+
+fn user_app_container_frameworks_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    user_app_container_dir_for(dirs, app).map(|a| a.join("Contents/Frameworks"))
+}
+
+fn user_app_container_plugins_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    user_app_container_dir_for(dirs, app).map(|a| a.join("Contents/PlugIns"))
+}
+
+fn cache_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.cache_dir().map(|path| path.join(app))
+}
+
+fn config_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.config_dir().map(|path| path.join(app))
+}
+
+fn config_local_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.config_local_dir().map(|path| path.join(app))
+}
+
+fn data_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.data_dir().map(|path| path.join(app))
+}
+
+fn data_local_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.data_local_dir().map(|path| path.join(app))
+}
+
+fn favorites_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+    scheme: NestingScheme,
+) -> Option<PathBuf> {
+    match scheme {
+        NestingScheme::PlatformDefault | NestingScheme::AppUnderKind => {
+            dirs.favorites_dir().map(|path| path.join(app))
+        }
+        NestingScheme::KindUnderApp => {
+            data_local_dir_for(dirs, app).map(|path| path.join(D_FAVORITES))
+        }
+    }
+}
+
+fn log_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.log_dir().map(|path| path.join(app))
+}
+
+fn system_log_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.system_log_dir().map(|path| path.join(app))
+}
+
+fn preference_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.preference_dir().map(|path| path.join(app))
+}
+
+fn state_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.state_dir().map(|path| path.join(app))
+}
+
+fn template_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|d| d.join("Templates"))
+}
+
+fn saved_games_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|d| d.join("Saves"))
+}
+
+fn icons_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|d| d.join("Icons"))
+}
+
+fn themes_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|d| d.join("Themes"))
+}
+
+fn public_data_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.public_data_dir().map(|path| path.join(app))
+}
+
+fn shared_documents_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    dirs.shared_documents_dir().map(|path| path.join(app))
+}
+
+fn crash_dumps_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, _: &Path) -> Option<PathBuf> {
+    // the system's crash reporter writes the reports of every application to the one directory.
+    dirs.crash_dumps_dir()
+}
+
+fn temp_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.temp_dir().map(|path| path.join(app))
+}
+
+fn socket_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    temp_dir_for(dirs, app)
+}
+
+fn lock_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app)
+}
+
+fn document_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.document_dir().map(|path| path.join(app))
+}
+
+fn download_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.download_dir().map(|path| path.join(app))
+}
+
+fn desktop_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.desktop_dir().map(|path| path.join(app))
+}
+
+fn public_share_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.public_dir().map(|path| path.join(app))
+}
+
+fn history_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|d| d.join("History"))
+}
+
+fn backup_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|d| d.join("Backups"))
+}
+
+fn secrets_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|d| d.join("Secrets"))
+}
+
+fn plugins_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|d| d.join("PlugIns"))
+}
+
+fn system_plugins_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.system_data_dir().map(|d| d.join(app).join("PlugIns"))
+}
+
+fn system_template_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    // there is no shared template directory, and so an application's templates are kept with
+    // its other data shared by all users.
+    dirs.system_data_dir()
+        .map(|d| d.join(app).join("Templates"))
+}
+
+fn bundle_name(app: &Path) -> PathBuf {
+    let mut name = app.as_os_str().to_os_string();
+    name.push(".app");
+    PathBuf::from(name)
+}
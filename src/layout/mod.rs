@@ -0,0 +1,786 @@
+/*!
+Provides the platforms' rules for laying out application directories, independently of the
+operating system calls that locate the generic directories, so that every platform's layout is
+compiled, and may be tested, on every host.
+*/
+
+use crate::{DirKind, DirectoryProvider, Naming, NestingScheme};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A platform whose directory layout rules may be applied to any
+/// [`DirectoryProvider`](trait.DirectoryProvider.html), regardless of the platform this crate
+/// was compiled for; for example, to test the Windows layout on a Linux build machine.
+///
+/// ```rust
+/// use xdirs::{DirKind, Platform, PlatformProvider};
+///
+/// // The Windows layout, applied to this host's generic directories.
+/// let cache = Platform::Windows.dir_for(&PlatformProvider, DirKind::Cache, "MyApp");
+/// if let Some(cache) = cache {
+///     assert!(cache.ends_with("MyApp/Cache"));
+/// }
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Platform {
+    /// Linux, and other Unix-like platforms, following the XDG base directory specification.
+    Linux,
+    /// macOS.
+    MacOS,
+    /// Windows.
+    Windows,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Platform {
+    ///
+    /// Returns the platform this crate was compiled for.
+    ///
+    pub const fn current() -> Self {
+        if cfg!(any(target_os = "macos", target_os = "ios")) {
+            Self::MacOS
+        } else if cfg!(windows) {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+
+    ///
+    /// Returns the application-specific form of `kind` for `app`, laid out according to this
+    /// platform's rules beneath the generic directories of `dirs`. The application name is first
+    /// made safe by [`sanitize_app_name`](fn.sanitize_app_name.html).
+    ///
+    pub fn dir_for<P: DirectoryProvider + ?Sized>(
+        self,
+        dirs: &P,
+        kind: DirKind,
+        app: &str,
+    ) -> Option<PathBuf> {
+        self.named_dir_for(
+            dirs,
+            kind,
+            app,
+            &Naming::Verbatim,
+            NestingScheme::PlatformDefault,
+        )
+    }
+
+    ///
+    /// Returns the favorites directory for `app`, laid out according to this platform's rules
+    /// beneath the generic directories of `dirs`, and nested according to `scheme`.
+    ///
+    pub fn favorites_dir_for<P: DirectoryProvider + ?Sized>(
+        self,
+        dirs: &P,
+        app: &str,
+        scheme: NestingScheme,
+    ) -> Option<PathBuf> {
+        self.named_dir_for(dirs, DirKind::Favorites, app, &Naming::Verbatim, scheme)
+    }
+
+    ///
+    /// Returns the platform that a build script's target has, given the value of
+    /// `CARGO_CFG_TARGET_OS`, such as `"linux"` or `"windows"`; or `None` if this crate does not
+    /// support the operating system.
+    ///
+    pub fn for_target_os(os: &str) -> Option<Self> {
+        match os {
+            "macos" | "ios" => Some(Self::MacOS),
+            "windows" => Some(Self::Windows),
+            "linux" | "android" | "freebsd" | "openbsd" | "netbsd" | "dragonfly" | "solaris"
+            | "illumos" | "haiku" | "wasi" | "unknown" => Some(Self::Linux),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns this platform's default location for the generic form of `kind` relative to the
+    /// home directory, with components separated by the platform's separator, such as
+    /// `Library/Logs` or `AppData\Local`; or `None` if it is not beneath the home directory.
+    ///
+    pub fn home_relative_dir(self, kind: DirKind) -> Option<String> {
+        let (home_dirs, _) = self.default_dirs();
+        self.relative(home_dirs, kind)
+    }
+
+    ///
+    /// Returns this platform's default location for the generic form of `kind` relative to the
+    /// root of the file system, as for [`home_relative_dir`](#method.home_relative_dir), such as
+    /// `Program Files`; or `None` if it is not a directory shared by all users.
+    ///
+    pub fn system_relative_dir(self, kind: DirKind) -> Option<String> {
+        let (_, system_dirs) = self.default_dirs();
+        self.relative(system_dirs, kind)
+    }
+
+    ///
+    /// Returns this platform's default location for the generic form of `kind`, for a user
+    /// with the home directory `home` on a file system rooted at `root`; this ignores any
+    /// environment variables or user configuration that would relocate it.
+    ///
+    pub fn default_dir(self, root: &Path, home: &Path, kind: DirKind) -> Option<PathBuf> {
+        let (home_dirs, system_dirs) = self.default_dirs();
+        find(home_dirs, home, kind).or_else(|| find(system_dirs, root, kind))
+    }
+
+    ///
+    /// Returns `true` if the generic form of `kind` is, by default, beneath the home directory on
+    /// this platform.
+    ///
+    pub(crate) fn is_in_home(self, kind: DirKind) -> bool {
+        let (home_dirs, _) = self.default_dirs();
+        home_dirs.iter().any(|(k, _)| *k == kind)
+    }
+
+    ///
+    /// Returns this platform's default location of the home directory for `user`, on a file
+    /// system rooted at `root`.
+    ///
+    pub fn default_home_dir(self, root: &Path, user: &str) -> PathBuf {
+        let parent = match self {
+            Self::Linux => linux::HOME_PARENT,
+            Self::MacOS => macos::HOME_PARENT,
+            Self::Windows => windows::HOME_PARENT,
+        };
+        join_all(root, parent).join(user)
+    }
+
+    ///
+    /// As for `dir_for`, but with the application's directory named according to `naming`, and
+    /// favorites nested according to `scheme`. Application containers are always named for the
+    /// application itself.
+    ///
+    pub(crate) fn named_dir_for<P: DirectoryProvider + ?Sized>(
+        self,
+        dirs: &P,
+        kind: DirKind,
+        app: &str,
+        naming: &Naming,
+        scheme: NestingScheme,
+    ) -> Option<PathBuf> {
+        let name = if is_container(kind) {
+            Naming::Verbatim.dir_name(self, app)
+        } else {
+            naming.dir_name(self, app)
+        };
+        match self {
+            Self::Linux => linux::dir_for(dirs, kind, &name, scheme),
+            Self::MacOS => macos::dir_for(dirs, kind, &name, scheme),
+            Self::Windows => windows::dir_for(dirs, kind, &name, scheme),
+        }
+    }
+
+    ///
+    /// Returns the location of the generic form of `kind` given by this platform's environment
+    /// variables in `vars`, such as `XDG_CONFIG_HOME` or `APPDATA`, if the variable is set to an
+    /// absolute path.
+    ///
+    pub(crate) fn env_dir(
+        self,
+        vars: &HashMap<String, OsString>,
+        kind: DirKind,
+    ) -> Option<PathBuf> {
+        let env_dirs = match self {
+            Self::Linux => linux::ENV_DIRS,
+            Self::MacOS => macos::ENV_DIRS,
+            Self::Windows => windows::ENV_DIRS,
+        };
+        env_dirs
+            .iter()
+            .find(|(k, _, _)| *k == kind)
+            .and_then(|(_, var, components)| {
+                vars.get(*var)
+                    .filter(|value| !value.is_empty())
+                    .map(|value| join_all(Path::new(value), components))
+            })
+            .filter(|path| path.is_absolute())
+    }
+
+    pub(crate) fn minidump_path_for<P: DirectoryProvider + ?Sized>(
+        self,
+        dirs: &P,
+        app: &str,
+        naming: &Naming,
+    ) -> Option<PathBuf> {
+        let name = naming.dir_name(self, app);
+        match self {
+            Self::Linux => linux::minidump_path_for(dirs, &name),
+            Self::MacOS => macos::minidump_path_for(dirs, &name),
+            Self::Windows => windows::minidump_path_for(dirs, &name),
+        }
+    }
+
+    fn default_dirs(self) -> (DefaultDirs, DefaultDirs) {
+        match self {
+            Self::Linux => (linux::HOME_DIRS, linux::SYSTEM_DIRS),
+            Self::MacOS => (macos::HOME_DIRS, macos::SYSTEM_DIRS),
+            Self::Windows => (windows::HOME_DIRS, windows::SYSTEM_DIRS),
+        }
+    }
+
+    fn relative(self, dirs: DefaultDirs, kind: DirKind) -> Option<String> {
+        let separator = if self == Self::Windows { "\\" } else { "/" };
+        dirs.iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, components)| components.join(separator))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+type DefaultDirs = &'static [(DirKind, &'static [&'static str])];
+
+fn is_container(kind: DirKind) -> bool {
+    matches!(
+        kind,
+        DirKind::AppContainer
+            | DirKind::AppContainerExecutable
+            | DirKind::AppContainerResources
+            | DirKind::AppContainerFrameworks
+            | DirKind::AppContainerPlugins
+            | DirKind::UserAppContainer
+            | DirKind::UserAppContainerExecutable
+            | DirKind::UserAppContainerResources
+            | DirKind::UserAppContainerFrameworks
+            | DirKind::UserAppContainerPlugins
+    )
+}
+
+fn find(dirs: &[(DirKind, &[&str])], base: &Path, kind: DirKind) -> Option<PathBuf> {
+    dirs.iter()
+        .find(|(k, _)| *k == kind)
+        .map(|(_, components)| join_all(base, components))
+}
+
+fn join_all(base: &Path, components: &[&str]) -> PathBuf {
+    components
+        .iter()
+        .fold(base.to_path_buf(), |path, component| path.join(component))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Platform Modules
+// ------------------------------------------------------------------------------------------------
+
+mod linux;
+mod macos;
+mod windows;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Defaults(Platform);
+
+    impl Defaults {
+        fn get(&self, kind: DirKind) -> Option<PathBuf> {
+            self.0
+                .default_dir(Path::new("/"), Path::new("/home/user"), kind)
+        }
+    }
+
+    impl DirectoryProvider for Defaults {
+        fn home_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/home/user"))
+        }
+        fn application_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Application)
+        }
+        fn application_shared_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::ApplicationShared)
+        }
+        fn user_application_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::UserApplication)
+        }
+        fn user_application_shared_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::UserApplicationShared)
+        }
+        fn cache_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Cache)
+        }
+        fn config_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Config)
+        }
+        fn config_local_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::ConfigLocal)
+        }
+        fn data_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Data)
+        }
+        fn data_local_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::DataLocal)
+        }
+        fn favorites_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Favorites)
+        }
+        fn log_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Log)
+        }
+        fn system_log_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::SystemLog)
+        }
+        fn preference_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Preference)
+        }
+        fn state_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::State)
+        }
+        fn template_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Template)
+        }
+        fn saved_games_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::SavedGames)
+        }
+        fn icons_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Icons)
+        }
+        fn themes_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Themes)
+        }
+
+        fn mime_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Mime)
+        }
+
+        fn public_data_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::PublicData)
+        }
+
+        fn shared_documents_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::SharedDocuments)
+        }
+
+        fn wallpaper_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Wallpaper)
+        }
+
+        fn glib_schemas_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::GlibSchemas)
+        }
+
+        fn keyfile_backend_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::KeyfileBackend)
+        }
+
+        fn crash_dumps_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::CrashDumps)
+        }
+
+        fn temp_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Temp)
+        }
+
+        fn runtime_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/run/user/1000"))
+        }
+
+        fn system_data_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from(match self.0 {
+                Platform::Linux => "/usr/local/share",
+                Platform::MacOS => "/Library/Application Support",
+                Platform::Windows => "/ProgramData",
+            }))
+        }
+
+        fn document_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Document)
+        }
+
+        fn download_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Download)
+        }
+
+        fn desktop_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::Desktop)
+        }
+
+        fn public_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::PublicShare)
+        }
+
+        fn system_template_dir(&self) -> Option<PathBuf> {
+            self.get(DirKind::SystemTemplate)
+        }
+    }
+
+    fn test_layout(platform: Platform, kind: DirKind, expected: Option<&str>) {
+        let path = platform.dir_for(&Defaults(platform), kind, "Chrome");
+        assert_eq!(path, expected.map(PathBuf::from));
+    }
+
+    #[test]
+    fn test_linux_layout() {
+        let p = Platform::Linux;
+        test_layout(p, DirKind::Application, Some("/opt/Chrome"));
+        test_layout(
+            p,
+            DirKind::UserApplication,
+            Some("/home/user/.local/opt/Chrome"),
+        );
+        test_layout(p, DirKind::AppContainer, Some("/opt/Chrome"));
+        test_layout(
+            p,
+            DirKind::UserAppContainerExecutable,
+            Some("/home/user/.local/opt/Chrome/bin"),
+        );
+        test_layout(p, DirKind::Cache, Some("/home/user/.cache/Chrome"));
+        test_layout(p, DirKind::Config, Some("/home/user/.config/Chrome"));
+        test_layout(p, DirKind::Data, Some("/home/user/.local/share/Chrome"));
+        test_layout(
+            p,
+            DirKind::Favorites,
+            Some("/home/user/.local/share/favorites/Chrome"),
+        );
+        test_layout(p, DirKind::Log, Some("/home/user/.local/share/Chrome/logs"));
+        test_layout(p, DirKind::SystemLog, Some("/var/log/Chrome"));
+        test_layout(p, DirKind::Preference, Some("/home/user/.config/Chrome"));
+        test_layout(p, DirKind::State, Some("/home/user/.local/state/Chrome"));
+        test_layout(
+            p,
+            DirKind::Template,
+            Some("/home/user/.config/Chrome/templates"),
+        );
+        test_layout(
+            p,
+            DirKind::SavedGames,
+            Some("/home/user/.local/share/Chrome/saves"),
+        );
+        test_layout(p, DirKind::Icons, Some("/home/user/.local/share/icons"));
+        test_layout(
+            p,
+            DirKind::Themes,
+            Some("/home/user/.local/share/themes/Chrome"),
+        );
+        test_layout(p, DirKind::PublicData, Some("/usr/share/Chrome"));
+        test_layout(
+            p,
+            DirKind::CrashDumps,
+            Some("/home/user/.local/state/Chrome/crashes"),
+        );
+        let temp = match crate::sys::user_id() {
+            Some(user) => format!("/tmp/Chrome-{}", user),
+            None => String::from("/tmp/Chrome"),
+        };
+        test_layout(p, DirKind::Temp, Some(&temp));
+        test_layout(p, DirKind::Socket, Some("/run/user/1000/Chrome"));
+        test_layout(p, DirKind::Lock, Some("/run/user/1000/Chrome"));
+        test_layout(p, DirKind::Document, Some("/home/user/Documents/Chrome"));
+        test_layout(p, DirKind::Download, Some("/home/user/Downloads/Chrome"));
+        test_layout(p, DirKind::Desktop, Some("/home/user/Desktop/Chrome"));
+        test_layout(p, DirKind::PublicShare, Some("/home/user/Public/Chrome"));
+        test_layout(
+            p,
+            DirKind::History,
+            Some("/home/user/.local/state/Chrome/history"),
+        );
+        test_layout(
+            p,
+            DirKind::Backup,
+            Some("/home/user/.local/state/Chrome/backups"),
+        );
+        test_layout(
+            p,
+            DirKind::Secrets,
+            Some("/home/user/.local/share/Chrome/secrets"),
+        );
+        test_layout(
+            p,
+            DirKind::Plugins,
+            Some("/home/user/.local/share/Chrome/plugins"),
+        );
+        test_layout(
+            p,
+            DirKind::Locale,
+            Some("/home/user/.local/share/Chrome/locale"),
+        );
+        test_layout(
+            p,
+            DirKind::SystemTemplate,
+            Some("/usr/share/templates/Chrome"),
+        );
+        test_layout(
+            p,
+            DirKind::SystemPlugins,
+            Some("/usr/local/share/Chrome/plugins"),
+        );
+        test_layout(p, DirKind::SharedDocuments, None);
+    }
+
+    #[test]
+    fn test_macos_layout() {
+        let p = Platform::MacOS;
+        test_layout(p, DirKind::Application, Some("/Applications/Chrome.app"));
+        test_layout(p, DirKind::AppContainer, Some("/Applications/Chrome.app"));
+        test_layout(
+            p,
+            DirKind::UserAppContainerExecutable,
+            Some("/home/user/Applications/Chrome.app/Contents/MacOS"),
+        );
+        test_layout(
+            p,
+            DirKind::AppContainerFrameworks,
+            Some("/Applications/Chrome.app/Contents/Frameworks"),
+        );
+        test_layout(p, DirKind::Cache, Some("/home/user/Library/Caches/Chrome"));
+        test_layout(p, DirKind::Log, Some("/home/user/Library/Logs/Chrome"));
+        test_layout(p, DirKind::SystemLog, Some("/Library/Logs/Chrome"));
+        test_layout(
+            p,
+            DirKind::Template,
+            Some("/home/user/Library/Application Support/Chrome/Templates"),
+        );
+        test_layout(
+            p,
+            DirKind::SavedGames,
+            Some("/home/user/Library/Application Support/Chrome/Saves"),
+        );
+        test_layout(
+            p,
+            DirKind::Icons,
+            Some("/home/user/Library/Application Support/Chrome/Icons"),
+        );
+        test_layout(p, DirKind::SharedDocuments, Some("/Users/Shared/Chrome"));
+        test_layout(
+            p,
+            DirKind::CrashDumps,
+            Some("/home/user/Library/Logs/DiagnosticReports"),
+        );
+        test_layout(p, DirKind::Temp, None);
+        test_layout(
+            p,
+            DirKind::Lock,
+            Some("/home/user/Library/Application Support/Chrome"),
+        );
+        test_layout(p, DirKind::Document, Some("/home/user/Documents/Chrome"));
+        test_layout(p, DirKind::Download, Some("/home/user/Downloads/Chrome"));
+        test_layout(p, DirKind::Desktop, Some("/home/user/Desktop/Chrome"));
+        test_layout(p, DirKind::PublicShare, Some("/home/user/Public/Chrome"));
+        test_layout(
+            p,
+            DirKind::History,
+            Some("/home/user/Library/Application Support/Chrome/History"),
+        );
+        test_layout(
+            p,
+            DirKind::Backup,
+            Some("/home/user/Library/Application Support/Chrome/Backups"),
+        );
+        test_layout(
+            p,
+            DirKind::Secrets,
+            Some("/home/user/Library/Application Support/Chrome/Secrets"),
+        );
+        test_layout(
+            p,
+            DirKind::Plugins,
+            Some("/home/user/Library/Application Support/Chrome/PlugIns"),
+        );
+        test_layout(
+            p,
+            DirKind::Locale,
+            Some("/Applications/Chrome.app/Contents/Resources"),
+        );
+        test_layout(
+            p,
+            DirKind::SystemTemplate,
+            Some("/Library/Application Support/Chrome/Templates"),
+        );
+        test_layout(
+            p,
+            DirKind::SystemPlugins,
+            Some("/Library/Application Support/Chrome/PlugIns"),
+        );
+    }
+
+    #[test]
+    fn test_windows_layout() {
+        let p = Platform::Windows;
+        test_layout(p, DirKind::Application, Some("/Program Files/Chrome"));
+        test_layout(
+            p,
+            DirKind::UserApplication,
+            Some("/home/user/AppData/Local/Programs/Chrome"),
+        );
+        test_layout(p, DirKind::AppContainer, Some("/Program Files/Chrome"));
+        test_layout(
+            p,
+            DirKind::UserAppContainerExecutable,
+            Some("/home/user/AppData/Local/Programs/Chrome"),
+        );
+        test_layout(p, DirKind::AppContainerResources, None);
+        test_layout(
+            p,
+            DirKind::Cache,
+            Some("/home/user/AppData/Local/Chrome/Cache"),
+        );
+        test_layout(
+            p,
+            DirKind::Config,
+            Some("/home/user/AppData/Roaming/Chrome"),
+        );
+        test_layout(
+            p,
+            DirKind::ConfigLocal,
+            Some("/home/user/AppData/Local/Chrome"),
+        );
+        test_layout(
+            p,
+            DirKind::Log,
+            Some("/home/user/AppData/Local/Logs/Chrome"),
+        );
+        test_layout(p, DirKind::SystemLog, Some("/ProgramData/Chrome/Logs"));
+        test_layout(
+            p,
+            DirKind::Template,
+            Some("/home/user/AppData/Roaming/Microsoft/Windows/Templates/Chrome"),
+        );
+        test_layout(
+            p,
+            DirKind::SavedGames,
+            Some("/home/user/Saved Games/Chrome"),
+        );
+        test_layout(
+            p,
+            DirKind::Themes,
+            Some("/home/user/AppData/Roaming/Chrome/Themes"),
+        );
+        test_layout(p, DirKind::PublicData, Some("/ProgramData/Chrome"));
+        test_layout(
+            p,
+            DirKind::SharedDocuments,
+            Some("/Users/Public/Documents/Chrome"),
+        );
+        test_layout(
+            p,
+            DirKind::CrashDumps,
+            Some("/home/user/AppData/Local/CrashDumps"),
+        );
+        test_layout(
+            p,
+            DirKind::Temp,
+            Some("/home/user/AppData/Local/Temp/Chrome"),
+        );
+        test_layout(p, DirKind::Socket, Some("/home/user/AppData/Local/Chrome"));
+        test_layout(p, DirKind::Lock, Some("/home/user/AppData/Local/Chrome"));
+        test_layout(p, DirKind::Document, Some("/home/user/Documents/Chrome"));
+        test_layout(p, DirKind::Download, Some("/home/user/Downloads/Chrome"));
+        test_layout(p, DirKind::Desktop, Some("/home/user/Desktop/Chrome"));
+        test_layout(p, DirKind::PublicShare, Some("/Users/Public/Chrome"));
+        test_layout(
+            p,
+            DirKind::History,
+            Some("/home/user/AppData/Local/Chrome/History"),
+        );
+        test_layout(
+            p,
+            DirKind::Backup,
+            Some("/home/user/AppData/Roaming/Chrome/Backups"),
+        );
+        test_layout(
+            p,
+            DirKind::Secrets,
+            Some("/home/user/AppData/Local/Chrome/Secrets"),
+        );
+        test_layout(
+            p,
+            DirKind::Plugins,
+            Some("/home/user/AppData/Roaming/Chrome/Plugins"),
+        );
+        test_layout(
+            p,
+            DirKind::Locale,
+            Some("/home/user/AppData/Roaming/Chrome/locale"),
+        );
+        test_layout(
+            p,
+            DirKind::SystemTemplate,
+            Some("/ProgramData/Microsoft/Windows/Templates/Chrome"),
+        );
+        test_layout(
+            p,
+            DirKind::SystemPlugins,
+            Some("/ProgramData/Chrome/Plugins"),
+        );
+    }
+
+    #[test]
+    fn test_config_local_matches_config() {
+        for p in [Platform::Linux, Platform::MacOS] {
+            assert_eq!(
+                p.dir_for(&Defaults(p), DirKind::ConfigLocal, "Chrome"),
+                p.dir_for(&Defaults(p), DirKind::Config, "Chrome")
+            );
+        }
+    }
+
+    #[test]
+    fn test_nesting_scheme() {
+        let app_under_kind =
+            |p: Platform| p.favorites_dir_for(&Defaults(p), "Chrome", NestingScheme::AppUnderKind);
+        let kind_under_app =
+            |p: Platform| p.favorites_dir_for(&Defaults(p), "Chrome", NestingScheme::KindUnderApp);
+        assert_eq!(
+            app_under_kind(Platform::Linux),
+            Some(PathBuf::from("/home/user/.local/share/favorites/Chrome"))
+        );
+        assert_eq!(
+            kind_under_app(Platform::MacOS),
+            Some(PathBuf::from(
+                "/home/user/Library/Application Support/Chrome/Favorites"
+            ))
+        );
+        assert_eq!(
+            app_under_kind(Platform::Windows),
+            Some(PathBuf::from("/home/user/Favorites/Chrome"))
+        );
+    }
+
+    #[test]
+    fn test_cache_apart_from_local_dirs() {
+        let schemes = [
+            NestingScheme::PlatformDefault,
+            NestingScheme::AppUnderKind,
+            NestingScheme::KindUnderApp,
+        ];
+        for p in [Platform::Linux, Platform::MacOS, Platform::Windows] {
+            for scheme in schemes {
+                let dir_for =
+                    |kind| p.named_dir_for(&Defaults(p), kind, "Chrome", &Naming::Verbatim, scheme);
+                let cache = dir_for(DirKind::Cache);
+                assert!(cache.is_some());
+                assert_ne!(cache, dir_for(DirKind::DataLocal), "{:?} {:?}", p, scheme);
+                assert_ne!(cache, dir_for(DirKind::ConfigLocal), "{:?} {:?}", p, scheme);
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_home_dir() {
+        assert_eq!(
+            Platform::Linux.default_home_dir(Path::new("/"), "alice"),
+            PathBuf::from("/home/alice")
+        );
+        assert_eq!(
+            Platform::MacOS.default_home_dir(Path::new("/"), "alice"),
+            PathBuf::from("/Users/alice")
+        );
+    }
+}
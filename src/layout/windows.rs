@@ -0,0 +1,370 @@
+/*!
+The layout of application directories on Windows, following the conventions for Known Folders.
+*/
+
+use crate::{DirKind, DirectoryProvider, NestingScheme};
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+// The default locations of the generic directories, used where they are resolved for a home
+// directory other than the current user's; home directories are created beneath HOME_PARENT,
+// HOME_DIRS are relative to the home directory, and SYSTEM_DIRS to the file system root.
+
+pub(crate) const HOME_PARENT: &[&str] = &["Users"];
+
+pub(crate) const HOME_DIRS: &[(DirKind, &[&str])] = &[
+    (DirKind::UserApplication, &["AppData", "Local", "Programs"]),
+    (
+        DirKind::UserApplicationShared,
+        &["AppData", "Local", "Programs", "Common"],
+    ),
+    (DirKind::Cache, &["AppData", "Local"]),
+    (DirKind::Config, &["AppData", "Roaming"]),
+    (DirKind::ConfigLocal, &["AppData", "Local"]),
+    (DirKind::Data, &["AppData", "Roaming"]),
+    (DirKind::DataLocal, &["AppData", "Local"]),
+    (DirKind::Favorites, &["Favorites"]),
+    (DirKind::Log, &["AppData", "Local", "Logs"]),
+    (DirKind::Preference, &["AppData", "Roaming"]),
+    (DirKind::State, &["AppData", "Local"]),
+    (
+        DirKind::Template,
+        &["AppData", "Roaming", "Microsoft", "Windows", "Templates"],
+    ),
+    (DirKind::SavedGames, &["Saved Games"]),
+    (
+        DirKind::Themes,
+        &["AppData", "Local", "Microsoft", "Windows", "Themes"],
+    ),
+    (DirKind::CrashDumps, &["AppData", "Local", "CrashDumps"]),
+    (DirKind::Temp, &["AppData", "Local", "Temp"]),
+    (DirKind::Document, &["Documents"]),
+    (DirKind::Download, &["Downloads"]),
+    (DirKind::Desktop, &["Desktop"]),
+];
+
+pub(crate) const SYSTEM_DIRS: &[(DirKind, &[&str])] = &[
+    (DirKind::Application, &["Program Files"]),
+    (
+        DirKind::ApplicationShared,
+        &["Program Files", "Common Files"],
+    ),
+    (DirKind::SystemLog, &["ProgramData"]),
+    (DirKind::PublicData, &["ProgramData"]),
+    (DirKind::SharedDocuments, &["Users", "Public", "Documents"]),
+    (DirKind::PublicShare, &["Users", "Public"]),
+    (
+        DirKind::SystemTemplate,
+        &["ProgramData", "Microsoft", "Windows", "Templates"],
+    ),
+];
+
+// The environment variables that relocate the generic directories, where they are resolved
+// against an explicit environment; each is relative to the variable's value.
+pub(crate) const ENV_DIRS: &[(DirKind, &str, &[&str])] = &[
+    (DirKind::Application, "ProgramFiles", &[]),
+    (DirKind::ApplicationShared, "CommonProgramFiles", &[]),
+    (DirKind::UserApplication, "LOCALAPPDATA", &["Programs"]),
+    (
+        DirKind::UserApplicationShared,
+        "LOCALAPPDATA",
+        &["Programs", "Common"],
+    ),
+    (DirKind::Cache, "LOCALAPPDATA", &[]),
+    (DirKind::Config, "APPDATA", &[]),
+    (DirKind::ConfigLocal, "LOCALAPPDATA", &[]),
+    (DirKind::Data, "APPDATA", &[]),
+    (DirKind::DataLocal, "LOCALAPPDATA", &[]),
+    (DirKind::Log, "LOCALAPPDATA", &["Logs"]),
+    (DirKind::SystemLog, "ProgramData", &[]),
+    (DirKind::PublicData, "ProgramData", &[]),
+    (DirKind::SharedDocuments, "PUBLIC", &["Documents"]),
+    (DirKind::Preference, "APPDATA", &[]),
+    (DirKind::State, "LOCALAPPDATA", &[]),
+    (
+        DirKind::Template,
+        "APPDATA",
+        &["Microsoft", "Windows", "Templates"],
+    ),
+    (
+        DirKind::Themes,
+        "LOCALAPPDATA",
+        &["Microsoft", "Windows", "Themes"],
+    ),
+    (DirKind::CrashDumps, "LOCALAPPDATA", &["CrashDumps"]),
+    (DirKind::Temp, "TEMP", &[]),
+    (DirKind::PublicShare, "PUBLIC", &[]),
+    (
+        DirKind::SystemTemplate,
+        "ProgramData",
+        &["Microsoft", "Windows", "Templates"],
+    ),
+];
+
+///
+/// Returns the application-specific form of `kind` for the application directory name `app`,
+/// laid out beneath the generic directories of `dirs`.
+///
+pub(crate) fn dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    kind: DirKind,
+    app: &Path,
+    scheme: NestingScheme,
+) -> Option<PathBuf> {
+    match kind {
+        DirKind::AppContainer => app_container_dir_for(dirs, app),
+        DirKind::AppContainerExecutable => app_container_executable_dir_for(dirs, app),
+        DirKind::UserAppContainer => user_app_container_dir_for(dirs, app),
+        DirKind::UserAppContainerExecutable => user_app_container_executable_dir_for(dirs, app),
+        DirKind::Cache => cache_dir_for(dirs, app),
+        DirKind::Config => config_dir_for(dirs, app),
+        DirKind::ConfigLocal => config_local_dir_for(dirs, app),
+        DirKind::Data => data_dir_for(dirs, app),
+        DirKind::DataLocal => data_local_dir_for(dirs, app),
+        DirKind::Favorites => favorites_dir_for(dirs, app, scheme),
+        DirKind::Log => log_dir_for(dirs, app),
+        DirKind::SystemLog => system_log_dir_for(dirs, app),
+        DirKind::Preference => preference_dir_for(dirs, app),
+        DirKind::State => state_dir_for(dirs, app),
+        DirKind::Template => template_dir_for(dirs, app),
+        DirKind::SavedGames => saved_games_dir_for(dirs, app),
+        DirKind::Icons => icons_dir_for(dirs, app),
+        DirKind::Themes => themes_dir_for(dirs, app),
+        DirKind::PublicData => public_data_dir_for(dirs, app),
+        DirKind::SharedDocuments => shared_documents_dir_for(dirs, app),
+        DirKind::CrashDumps => crash_dumps_dir_for(dirs, app),
+        DirKind::Temp => temp_dir_for(dirs, app),
+        DirKind::Socket => socket_dir_for(dirs, app),
+        DirKind::Lock => lock_dir_for(dirs, app),
+        DirKind::Document => document_dir_for(dirs, app),
+        DirKind::Download => download_dir_for(dirs, app),
+        DirKind::Desktop => desktop_dir_for(dirs, app),
+        DirKind::PublicShare => public_share_dir_for(dirs, app),
+        DirKind::History => history_dir_for(dirs, app),
+        DirKind::Backup => backup_dir_for(dirs, app),
+        DirKind::Secrets => secrets_dir_for(dirs, app),
+        DirKind::Plugins => plugins_dir_for(dirs, app),
+        DirKind::SystemPlugins => system_plugins_dir_for(dirs, app),
+        DirKind::Locale => locale_dir_for(dirs, app),
+        DirKind::SystemTemplate => system_template_dir_for(dirs, app),
+        DirKind::Application => application_dir_for(dirs, app),
+        DirKind::UserApplication => user_application_dir_for(dirs, app),
+        DirKind::ApplicationShared
+        | DirKind::UserApplicationShared
+        | DirKind::Mime
+        | DirKind::Wallpaper
+        | DirKind::GlibSchemas
+        | DirKind::KeyfileBackend
+        | DirKind::AppContainerResources
+        | DirKind::AppContainerFrameworks
+        | DirKind::AppContainerPlugins
+        | DirKind::UserAppContainerResources
+        | DirKind::UserAppContainerFrameworks
+        | DirKind::UserAppContainerPlugins => None,
+    }
+}
+
+pub(crate) fn minidump_path_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|d| d.join(D_CRASH_DUMPS))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_BACKUPS: &str = "Backups";
+const D_CACHE: &str = "Cache";
+const D_CRASH_DUMPS: &str = "CrashDumps";
+const D_FAVORITES: &str = "Favorites";
+const D_HISTORY: &str = "History";
+const D_ICONS: &str = "Icons";
+const D_LOCALE: &str = "locale";
+const D_LOGS: &str = "Logs";
+const D_PLUGINS: &str = "Plugins";
+const D_SECRETS: &str = "Secrets";
+const D_THEMES: &str = "Themes";
+
+fn application_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.application_dir().map(|path| path.join(app))
+}
+
+fn user_application_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    dirs.user_application_dir().map(|path| path.join(app))
+}
+
+fn app_container_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    application_dir_for(dirs, app)
+}
+
+fn app_container_executable_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    app_container_dir_for(dirs, app)
+}
+
+fn user_app_container_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    user_application_dir_for(dirs, app)
+}
+
+fn user_app_container_executable_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    user_app_container_dir_for(dirs, app)
+}
+
+fn cache_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    // the generic cache directory is also the local data and local config directory, so the
+    // cache is kept apart from them whatever the nesting scheme.
+    dirs.cache_dir().map(|path| path.join(app).join(D_CACHE))
+}
+
+fn config_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.config_dir().map(|path| path.join(app))
+}
+
+fn config_local_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.config_local_dir().map(|path| path.join(app))
+}
+
+fn data_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.data_dir().map(|path| path.join(app))
+}
+
+fn data_local_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.data_local_dir().map(|path| path.join(app))
+}
+
+fn favorites_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+    scheme: NestingScheme,
+) -> Option<PathBuf> {
+    match scheme {
+        NestingScheme::PlatformDefault | NestingScheme::AppUnderKind => {
+            dirs.favorites_dir().map(|path| path.join(app))
+        }
+        NestingScheme::KindUnderApp => {
+            data_local_dir_for(dirs, app).map(|path| path.join(D_FAVORITES))
+        }
+    }
+}
+
+fn log_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.log_dir().map(|path| path.join(app))
+}
+
+fn system_log_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.system_log_dir()
+        .map(|path| path.join(app).join(D_LOGS))
+}
+
+fn preference_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    config_dir_for(dirs, app)
+}
+
+fn state_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.state_dir().map(|path| path.join(app))
+}
+
+fn template_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.template_dir().map(|d| d.join(app))
+}
+
+fn saved_games_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.saved_games_dir().map(|path| path.join(app))
+}
+
+fn icons_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|d| d.join(D_ICONS))
+}
+
+fn themes_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|d| d.join(D_THEMES))
+}
+
+fn public_data_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.public_data_dir().map(|path| path.join(app))
+}
+
+fn shared_documents_dir_for<P: DirectoryProvider + ?Sized>(
+    dirs: &P,
+    app: &Path,
+) -> Option<PathBuf> {
+    dirs.shared_documents_dir().map(|path| path.join(app))
+}
+
+fn crash_dumps_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, _: &Path) -> Option<PathBuf> {
+    // Windows Error Reporting writes the dumps of every application to the one directory.
+    dirs.crash_dumps_dir()
+}
+
+fn temp_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.temp_dir().map(|path| path.join(app))
+}
+
+fn socket_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app)
+}
+
+fn lock_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app)
+}
+
+fn document_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.document_dir().map(|path| path.join(app))
+}
+
+fn download_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.download_dir().map(|path| path.join(app))
+}
+
+fn desktop_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.desktop_dir().map(|path| path.join(app))
+}
+
+fn public_share_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.public_dir().map(|path| path.join(app))
+}
+
+fn history_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|path| path.join(D_HISTORY))
+}
+
+fn backup_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|path| path.join(D_BACKUPS))
+}
+
+fn secrets_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_local_dir_for(dirs, app).map(|path| path.join(D_SECRETS))
+}
+
+fn plugins_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|path| path.join(D_PLUGINS))
+}
+
+fn system_plugins_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.system_data_dir()
+        .map(|path| path.join(app).join(D_PLUGINS))
+}
+
+fn locale_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    data_dir_for(dirs, app).map(|path| path.join(D_LOCALE))
+}
+
+fn system_template_dir_for<P: DirectoryProvider + ?Sized>(dirs: &P, app: &Path) -> Option<PathBuf> {
+    dirs.system_template_dir().map(|path| path.join(app))
+}
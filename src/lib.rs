@@ -9,29 +9,110 @@ ensure the correct construction of the application-specific path.
 
 | Generic Form                  | Application-Specific Form |
 | ----------------------------- | ------------------------- |
-| `cache_dir`                   | [`cache_dir_for`](fn.cache_dir_for.html)           |
-| `config_dir`                  | [`config_dir_for`](fn.config_dir_for.html)         |
-| `data_dir`                    | [`data_dir_for`](fn.data_dir_for.html)             |
-| `data_local_dir`              | [`data_local_dir_for`](fn.data_local_dir_for.html) |
+| [`cache_dir`](fn.cache_dir.html)           | [`cache_dir_for`](fn.cache_dir_for.html)           |
+| [`config_dir`](fn.config_dir.html)         | [`config_dir_for`](fn.config_dir_for.html)         |
+| [`config_local_dir`](fn.config_local_dir.html) | [`config_local_dir_for`](fn.config_local_dir_for.html) |
+| [`data_dir`](fn.data_dir.html)             | [`data_dir_for`](fn.data_dir_for.html)             |
+| [`data_local_dir`](fn.data_local_dir.html) | [`data_local_dir_for`](fn.data_local_dir_for.html) |
 | [`favorites_dir`](fn.favorites_dir.html)   | [`favorites_dir_for`](fn.favorites_dir_for.html)   |
 | [`log_dir`](fn.log_dir.html)  | [`log_dir_for`](fn.log_dir_for.html) |
+| [`system_log_dir`](fn.system_log_dir.html) | [`system_log_dir_for`](fn.system_log_dir_for.html) |
 | [`preference_dir`](fn.preference_dir.html) | [`preference_dir_for`](fn.preference_dir_for.html) |
-| [`cache_dir`](fn.cache_dir.html)           | [`cache_dir_for`](fn.cache_dir_for.html)           |
-| `template_dir`               | [`template_dir_for`](fn.template_dir_for.html)     |
+| [`template_dir`](fn.template_dir.html)     | [`template_dir_for`](fn.template_dir_for.html)     |
+| [`saved_games_dir`](fn.saved_games_dir.html) | [`saved_games_dir_for`](fn.saved_games_dir_for.html) |
+| [`icons_dir`](fn.icons_dir.html) | [`icons_dir_for`](fn.icons_dir_for.html) |
+| [`themes_dir`](fn.themes_dir.html) | [`themes_dir_for`](fn.themes_dir_for.html) |
 
 Additionally the following may be used to determine the location for installed applications.
 
 * [`application_dir`](fn.application_dir.html)
 * [`application_shared_dir`](fn.application_shared_dir.html)
 * [`user_application_dir`](fn.user_application_dir.html)
+* [`user_application_shared_dir`](fn.user_application_shared_dir.html)
+* [`executable_dir`](fn.executable_dir.html)
+
+and, for the installation directory of a specific application,
+
+* [`application_dir_for`](fn.application_dir_for.html)
+* [`user_application_dir_for`](fn.user_application_dir_for.html)
+
+of which [`installed_application_dir_for`](fn.installed_application_dir_for.html) returns the one
+the application is actually installed in.
 
 Finally, for systems that support a notion of an application container or bundle, the following
-will provide the location to these directories. Currently these only provide values on macOS.
+will provide the location to these directories. On Linux and Windows an application's container
+is its installation directory.
 
 * [`app_container_dir_for`](fn.app_container_dir_for.html)
 * [`app_container_executable_dir_for`](fn.app_container_executable_dir_for.html)
+* [`app_container_resources_dir_for`](fn.app_container_resources_dir_for.html)
+* [`app_container_frameworks_dir_for`](fn.app_container_frameworks_dir_for.html)
+* [`app_container_plugins_dir_for`](fn.app_container_plugins_dir_for.html)
 * [`user_app_container_dir_for`](fn.user_app_container_dir_for.html)
 * [`user_app_container_executable_dir_for`](fn.user_app_container_executable_dir_for.html)
+* [`user_app_container_resources_dir_for`](fn.user_app_container_resources_dir_for.html)
+* [`user_app_container_frameworks_dir_for`](fn.user_app_container_frameworks_dir_for.html)
+* [`user_app_container_plugins_dir_for`](fn.user_app_container_plugins_dir_for.html)
+* [`sandbox_container_dir_for`](fn.sandbox_container_dir_for.html)
+* [`group_container_dir_for`](fn.group_container_dir_for.html)
+* [`application_scripts_dir_for`](fn.application_scripts_dir_for.html)
+* [`icloud_drive_dir`](fn.icloud_drive_dir.html)
+* [`icloud_container_dir_for`](fn.icloud_container_dir_for.html)
+
+The folders kept in sync by cloud storage clients, such as OneDrive, Dropbox, and iCloud Drive,
+are found on every platform by [`cloud_sync_dirs`](fn.cloud_sync_dirs.html).
+
+On Linux, applications packaged with Flatpak or Snap may detect the sandbox with
+[`flatpak_id`](fn.flatpak_id.html) and [`snap_name`](fn.snap_name.html), and find their own
+installation with [`sandboxed_app_dir`](fn.sandboxed_app_dir.html); the user's home directory
+outside of any sandbox is returned by [`host_home_dir`](fn.host_home_dir.html). On Windows,
+applications packaged with MSIX may detect this with
+[`package_family_name`](fn.package_family_name.html), and find the package's data with
+[`package_dir_for`](fn.package_dir_for.html).
+
+The following provide the locations in which background helpers are installed, as property lists;
+these only provide values on macOS.
+
+* [`launch_agents_dir`](fn.launch_agents_dir.html)
+* [`system_launch_agents_dir`](fn.system_launch_agents_dir.html)
+* [`launch_daemons_dir`](fn.launch_daemons_dir.html)
+
+Applications that should start when the user logs in are registered in
+[`autostart_dir`](fn.autostart_dir.html), or for all users in
+[`system_autostart_dir`](fn.system_autostart_dir.html), on all platforms. Installers add an
+application to the desktop's launcher, as a desktop entry on Linux or a Start Menu shortcut on
+Windows, in [`desktop_entry_dir`](fn.desktop_entry_dir.html) or
+[`system_desktop_entry_dir`](fn.system_desktop_entry_dir.html), and on Linux register their file
+types in [`mime_dir`](fn.mime_dir.html) or [`system_mime_dir`](fn.system_mime_dir.html), and
+their GSettings schemas in [`glib_schemas_dir`](fn.glib_schemas_dir.html) or
+[`system_glib_schemas_dir`](fn.system_glib_schemas_dir.html). Fonts are
+installed for the user in [`font_dir`](fn.font_dir.html), or for all users in
+[`system_font_dir`](fn.system_font_dir.html). Command-line tools install their manual pages in
+[`man_dir`](fn.man_dir.html) or [`system_man_dir`](fn.system_man_dir.html).
+
+Data exchanged between the users of a machine belongs in
+[`public_data_dir_for`](fn.public_data_dir_for.html), or for documents in
+[`shared_documents_dir_for`](fn.shared_documents_dir_for.html); unlike
+[`public_dir`](fn.public_dir.html) these do not belong to any one user.
+
+Personalization tools find desktop pictures in [`wallpaper_dir`](fn.wallpaper_dir.html) and
+[`system_wallpaper_dir`](fn.system_wallpaper_dir.html), and on Windows ringtones and camera
+photos in [`ringtones_dir`](fn.ringtones_dir.html) and
+[`camera_roll_dir`](fn.camera_roll_dir.html).
+
+Where a user's folders are redirected to a network share, as by Windows roaming profiles,
+[`is_redirected`](fn.is_redirected.html) reports it, and [`prefer_local`](fn.prefer_local.html)
+chooses the local counterpart of the config or data directory for large or frequently written
+files.
+
+Crash handlers may use [`minidump_path_for`](fn.minidump_path_for.html) to determine where to write
+crash dumps; as this cannot safely be called from within a handler, the
+[`signal_safe`](signal_safe/index.html) module allows the path to be resolved at startup and
+retrieved later without allocation or locking. On macOS the reports written by the system's own
+crash reporter are found in [`crash_reports_dir`](fn.crash_reports_dir.html) and
+[`system_crash_reports_dir`](fn.system_crash_reports_dir.html), and those for a single application
+are listed by [`crash_reports_for`](fn.crash_reports_for.html). Crash handlers that write full
+dumps, rather than minidumps, should use [`crash_dumps_dir_for`](fn.crash_dumps_dir_for.html).
 
 As is the case for dirs-next, this library provides the location of these directories by leveraging
 the mechanisms defined by
@@ -40,6 +121,18 @@ the mechanisms defined by
 * the [Known Folder](https://msdn.microsoft.com/en-us/library/windows/desktop/bb776911(v=vs.85).aspx) system on Windows, and
 * the [Standard Directories](https://developer.apple.com/library/content/documentation/FileManagement/Conceptual/FileSystemProgrammingGuide/FileSystemOverview/FileSystemOverview.html#//apple_ref/doc/uid/TP40010672-CH2-SW6) on macOS.
 
+On iOS the application's sandbox is its home directory; the cache, configuration, data, and
+document directories are found within it, and there are no installation directories. Unless noted
+otherwise, the functions below return the same values on iOS as on macOS, or `None`.
+
+On WASI the cache, configuration, and data directories are the XDG base directories beneath the
+`$HOME` given to the module by its host, which must also preopen them; in a browser, where there
+is no file system, every function returns `None`.
+
+On Haiku the user's directories are those returned by `find_directory`, beneath ~/config; the
+cache directory is ~/config/cache, the configuration directory ~/config/settings, the data
+directory ~/config/data, and the log directory ~/config/var/log.
+
 # Example
 
 ```rust
@@ -52,369 +145,2656 @@ let where_do_i_read_my_config = config_dir_for(APP_NAME);
 let where_do_i_write_log_files = log_dir_for(APP_NAME);
 ```
 
+Each of these categories is also identified by a [`DirKind`](enum.DirKind.html) value, which allows
+a directory to be selected by name, or all directories to be enumerated, as
+[`all_dirs`](fn.all_dirs.html) and [`all_dirs_for`](fn.all_dirs_for.html) do.
+
+```rust
+use xdirs::DirKind;
+use std::str::FromStr;
+
+let where_do_i_write_log_files = DirKind::from_str("log").unwrap().dir_for("MyApp");
+```
+
+# Application Names
+
+The application name passed to each `_for` function is used as a single path component, and so
+names that contain path separators, such as `../evil`, or that are not valid file names on every
+platform, such as `CON`, are first made safe by [`sanitize_app_name`](fn.sanitize_app_name.html);
+a name is only changed if [`validate_app_name`](fn.validate_app_name.html) rejects it. The
+fallible function [`try_dir_for`](fn.try_dir_for.html) rejects such names instead.
+
+By default the application name is used verbatim; an application may instead opt in to each
+platform's conventional form, such as `my-app` on Linux or `com.example.MyApp` on macOS, with
+[`set_naming`](fn.set_naming.html). The file names of platform-native artifacts, such as a Linux
+desktop entry or a Windows shortcut, follow the same policy and are returned by
+[`suggested_artifact_name`](fn.suggested_artifact_name.html).
+
+Command-line tools that prefer `~/.config` and `~/.cache` to `~/Library` on macOS may opt in to
+the XDG layout for their configuration, data, cache, and log directories with
+[`set_strict_xdg`](fn.set_strict_xdg.html). Applications that need both layouts, such as native
+locations for GUI data and XDG locations for configuration shared with command-line tools, may
+choose the [`Convention`](enum.Convention.html) for each directory with
+[`resolve`](fn.resolve.html).
+
+# Environment Overrides
+
+Each of the application-specific functions may be redirected by the user, or by a test harness,
+by setting an environment variable of the form `{APP}_{KIND}_DIR`; for example,
+`MYAPP_CONFIG_DIR` overrides `config_dir_for("MyApp")`. The prefix for an application may be
+changed, or overrides disabled, with [`set_env_prefix`](fn.set_env_prefix.html), and the name of
+the variable for a given directory is returned by
+[`env_override_var`](fn.env_override_var.html).
+
+On Linux and the BSDs the generic directories are themselves relocated by the XDG variables;
+`XDG_CONFIG_HOME`, `XDG_DATA_HOME`, `XDG_CACHE_HOME`, `XDG_STATE_HOME`, and `XDG_RUNTIME_DIR`,
+the search paths `XDG_CONFIG_DIRS` and `XDG_DATA_DIRS`, and the widely used draft `XDG_BIN_HOME`,
+which locates [`executable_dir`](fn.executable_dir.html) and whose parent replaces `$HOME`/.local
+for applications installed by the user. As the specification requires, a value that is not an
+absolute path is ignored in favor of the default; [`xdg_base`](fn.xdg_base.html) reports each
+such value.
+
+# Testing
+
+Code that depends on these directories may be tested without modifying `HOME` or `XDG_*`
+variables, which affects all threads in the process, by using
+[`override_root`](fn.override_root.html); this redirects every directory resolved on the current
+thread beneath a given root until the returned guard is dropped.
+
+Alternatively, the platform's rules for laying out an application's directories may be applied to
+any set of generic directories by implementing [`DirectoryProvider`](trait.DirectoryProvider.html);
+the crate root functions use the [`PlatformProvider`](struct.PlatformProvider.html). The rules of
+every supported [`Platform`](enum.Platform.html) are available on every host, so that, for
+example, the Windows layout may be tested on a Linux build machine.
+
+# Pre-Resolution
+
+Latency-sensitive applications may call [`warm`](fn.warm.html) at startup to resolve, and cache,
+the directories they will use on a background thread, so that the first user interaction does not
+pay the cost of Known Folder or file system access.
+
+```rust
+use xdirs::{warm, DirKind};
+
+let _ = warm("MyApp", &[DirKind::Config, DirKind::Cache, DirKind::Log]);
+```
+
+# Environment Changes
+
+Every directory is resolved from a snapshot of the environment, including `HOME` and the `XDG_*`
+variables, taken on first use, and the generic directories are resolved once and reused. A
+process that changes its environment after startup, for example a daemon that sets `HOME` after
+dropping privileges, must then call [`refresh`](fn.refresh.html), which takes a new snapshot and
+discards every directory already resolved; until it does, the previous values are returned. On
+Linux the user directories, such as [`document_dir`](fn.document_dir.html), are read from
+`user-dirs.dirs` on each call, beneath the snapshot's configuration directory.
+
+```rust
+std::env::set_var("XDG_CONFIG_HOME", "/srv/daemon/config");
+xdirs::refresh();
+```
+
+Where `HOME` is not set, as in minimal containers and some CI runners, the home directory is
+found by the [`HomeFallback`](enum.HomeFallback.html) strategy set with
+[`set_home_fallback`](fn.set_home_fallback.html): by default from the user database, but
+optionally a given directory, or none at all, in which case
+[`try_dir`](fn.try_dir.html) reports
+[`Error::HomeUnavailable`](enum.Error.html#variant.HomeUnavailable).
+
+Tests, and tools that compute the directories of another environment, may instead use
+[`resolve_in`](fn.resolve_in.html), which resolves a directory against an explicit
+[`Environment`](struct.Environment.html) and never reads the process environment.
+
+# Existing Directories
+
+Each of the functions above returns where a directory belongs, whether or not it exists. Code
+that only reads from a directory may instead use [`existing_dir_for`](fn.existing_dir_for.html),
+or a `_for_existing` variant such as
+[`config_dir_for_existing`](fn.config_dir_for_existing.html), which return `None` unless the
+directory is actually present.
+
+Where a directory has no value on the current platform, such as the template directory on
+macOS, the functions above return `None`; code that wants a path regardless may resolve through
+[`with_fallback`](fn.with_fallback.html), which places such directories beneath a base of its
+choosing.
+
+# System Services
+
+The functions above resolve the directories of the current user. A system service, or daemon,
+that runs without a user profile should instead use the conventional system-wide locations, such
+as `/var/lib/{app}` on Linux, returned by [`service_dirs_for`](fn.service_dirs_for.html).
+
+# Running Under sudo
+
+A tool run under `sudo` may see either the invoking user's home directory or the target user's,
+depending on how `sudo` is configured. [`check_sudo_home`](fn.check_sudo_home.html) detects the
+latter, and the `_for_sudo_user` variants, such as
+[`config_dir_for_sudo_user`](fn.config_dir_for_sudo_user.html), resolve the invoking user's
+directories in either case. The directories of any other user may be resolved, from their home
+directory, with the `_for_user` variants, such as
+[`config_dir_for_user`](fn.config_dir_for_user.html).
+
+# Installers
+
+Installer manifests and packaging scripts, such as WiX sources or `.deb` maintainer scripts,
+that are generated by the application's own build may use the same conventional locations as
+this crate: [`write_consts`](fn.write_consts.html), called from a build script, writes a constant
+for each directory relative to the home directory or file system root, such as
+`HOME_LOG = "Library/Logs"` on macOS, which [`consts!`](macro.consts.html) then includes.
+
+# Panics
+
+None of the functions in this crate are expected to panic for any input, including application
+names that are empty, contain path separators, or are otherwise malformed. Callers that must
+know why a directory is unavailable, such as launchers and crash handlers, may use the functions
+[`try_dir`](fn.try_dir.html) and [`try_dir_for`](fn.try_dir_for.html), which return the reason
+as an [`Error`](enum.Error.html).
+
+```rust
+use xdirs::{try_dir_for, DirKind};
+
+match try_dir_for(DirKind::Log, "MyApp") {
+    Ok(path) => println!("logging to {:?}", path),
+    Err(e) => eprintln!("no log directory: {}", e),
+}
+```
+
+# Features
+
+* **cli**; builds the `xdirs` command-line tool which prints any of the directories provided by
+  this crate, for use from shell scripts and installers.
+* **create**; adds a `_for_create` variant of each application-specific function, for example
+  `config_dir_for_create`, which creates the directory if it does not exist and returns an
+  `io::Result<PathBuf>`. Directories that may hold sensitive content are created such that only
+  the current user may access them, and `create_private_dir` provides the same for any path.
+  Every directory created may be recorded by an `Audit` sink, set with `set_audit_sink`, and
+  changes that must be backed out on failure may be made within a `Transaction`. Stale cache
+  entries may be removed, by age or to keep within a size budget, with `clean_cache_for`, and
+  uninstallers may remove all of an application's directories with `remove_all_dirs_for`. An
+  application's settings and data may be copied between machines with `export_app_dirs` and
+  `import_app_dirs`, and the directories and initial files it needs on its first run created with
+  `initialize_app_dirs`.
+* **testing**; adds the [`testing`](testing/index.html) module, with proptest strategies for
+  application names and environments, and `FakeHome`, a temporary home directory to resolve
+  directories against.
+* **metrics**; records the number of resolutions, and the time taken, for each directory kind;
+  these are returned by `metrics`.
+* **ndk-context**; on Android, asks the application context, through JNI, for the application's
+  private data directory. Without it, or before the context is initialized, the directory is
+  taken to be `/data/data/{package}`, for the package name of the current process. The cache
+  directory is `cache`, and the configuration and data directories are `files`, beneath it.
+* **registry**; on Windows, looks for an application that is in neither `App Paths` nor its
+  conventional installation directory in the registry's `Uninstall` entries, whose
+  `InstallLocation` records where installers placed it; see
+  [`app_container_dir_for`](fn.app_container_dir_for.html). The entries are searched once for
+  each application, until the next [`refresh`](fn.refresh.html).
+* **tokio**; implies **create**, and adds asynchronous variants of the creation functions, such
+  as `ensure_config_dir_for(app).await`, which create directories on the Tokio runtime's blocking
+  thread pool rather than blocking the calling task.
+
 */
 
-pub use dirs_next::{cache_dir, config_dir, data_dir, data_local_dir};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+mod kind;
+pub use kind::{all_dirs, all_dirs_for, DirKind, ParseDirKindError};
+
+pub mod signal_safe;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+mod env_override;
+pub use env_override::{env_override_var, set_env_prefix};
+
+mod warm;
+pub use warm::warm;
+#[cfg(feature = "create")]
+pub use warm::warm_and_create;
+
+mod override_root;
+pub use override_root::{override_root, RootOverride};
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+mod metrics;
+#[cfg(feature = "metrics")]
+pub use metrics::{metrics, reset_metrics, ResolutionMetrics};
+
+mod provider;
+pub use provider::{DirectoryProvider, PlatformProvider};
+
+mod layout;
+pub use layout::Platform;
+
+mod nesting;
+pub use nesting::{set_nesting_scheme, NestingScheme};
+
+mod naming;
+pub use naming::{set_naming, Naming};
+
+mod artifact;
+pub use artifact::{suggested_artifact_name, ArtifactKind};
+
+mod profile;
+pub use profile::{
+    cache_dir_for_profile, config_dir_for_profile, data_dir_for_profile, dir_for_profile,
+};
+
+mod explain;
+pub use explain::{explain_dir, explain_dir_for, Explanation, Source};
+
+mod resolve;
+pub use resolve::refresh;
+
+mod home_fallback;
+pub use home_fallback::{set_home_fallback, HomeFallback};
+
+#[cfg(unix)]
+mod passwd;
+
+mod environment;
+pub use environment::{reload_env, resolve_in, Environment};
+
+mod known_folder;
+pub use known_folder::{known_folder, FolderId};
+
+mod config_file;
+pub use config_file::config_file_for;
+
+mod joiner;
+pub use joiner::{dir_joiner_for, DirJoiner};
+
+mod config_resolver;
+pub use config_resolver::ConfigResolver;
+
+mod dot_dir;
+pub use dot_dir::dot_dir_for;
+
+mod redirect;
+pub use redirect::{is_redirected, prefer_local};
+
+mod media;
+pub use media::{camera_roll_dir, ringtones_dir, system_wallpaper_dir, wallpaper_dir};
+
+mod cloud_sync;
+pub use cloud_sync::{cloud_sync_dirs, CloudProvider, CloudSyncDir};
+
+mod cache_scope;
+pub use cache_scope::{cache_dir_for_scope, cache_scopes_for};
+
+mod log_file;
+#[cfg(feature = "create")]
+pub use log_file::log_file_for_create;
+pub use log_file::{log_file_for, log_file_for_with, LogFilePattern};
+
+mod homebrew;
+pub use homebrew::{brew_cellar_dir_for, brew_share_dir_for, homebrew_prefix};
+
+mod xdg;
+pub use xdg::{xdg_base, BaseKind, Resolution};
+
+mod strict_xdg;
+pub use strict_xdg::set_strict_xdg;
+
+mod convention;
+pub use convention::{resolve, Convention};
+
+mod user;
+pub use user::{
+    cache_dir_for_user, config_dir_for_user, data_dir_for_user, data_local_dir_for_user,
+    dir_for_user, home_dir_for_user, log_dir_for_user,
+};
+
+mod project_path;
+pub use project_path::{
+    cache_path_for, config_local_path_for, config_path_for, data_local_path_for, data_path_for,
+    log_path_for, path_for, ProjectPath,
+};
+
+mod user_dirs;
+pub use user_dirs::{read_user_dirs, set_user_dir, user_dir, user_dirs_file};
+
+mod service;
+pub use service::{service_dirs_for, ServiceDirs};
+
+mod sudo;
+pub use sudo::{
+    cache_dir_for_sudo_user, check_sudo_home, config_dir_for_sudo_user, data_dir_for_sudo_user,
+    data_local_dir_for_sudo_user, dir_for_sudo_user, log_dir_for_sudo_user, sudo_user,
+    sudo_user_home,
+};
+
+mod app_name;
+pub use app_name::{sanitize_app_name, validate_app_name};
+
+mod fallible;
+pub use fallible::{try_dir, try_dir_for, Error};
+
+mod fallback;
+pub use fallback::{with_fallback, WithFallback};
+
+mod usage;
+pub use usage::{dir_size, usage_report_for, DirUsage, UsageReport};
+
+mod writable;
+pub use writable::{writable_config_dir_for, writable_data_dir_for};
+
+mod existing;
+pub use existing::{
+    application_dir_for_existing, cache_dir_for_existing, config_dir_for_existing,
+    config_local_dir_for_existing, data_dir_for_existing, data_local_dir_for_existing,
+    existing_dir, existing_dir_for, favorites_dir_for_existing, icons_dir_for_existing,
+    installed_application_dir_for, log_dir_for_existing, preference_dir_for_existing,
+    saved_games_dir_for_existing, system_log_dir_for_existing, template_dir_for_existing,
+    themes_dir_for_existing, user_application_dir_for_existing,
+};
+
+mod consts;
+pub use consts::{path_consts, write_consts};
+
+#[cfg(feature = "create")]
+mod audit;
+#[cfg(feature = "create")]
+pub use audit::{set_audit_sink, Audit, AuditEvent, JsonLinesAudit, Operation};
+
+#[cfg(feature = "create")]
+mod transaction;
+#[cfg(feature = "create")]
+pub use transaction::{rollback, Transaction};
+
+#[cfg(feature = "watch")]
+mod watch;
+#[cfg(feature = "watch")]
+pub use watch::{watch_config_dir_for, DirWatcher};
+
+#[cfg(feature = "create")]
+mod clean;
+#[cfg(feature = "create")]
+pub use clean::{clean_cache_for, CleanPolicy, CleanReport};
+
+#[cfg(feature = "create")]
+mod create;
+#[cfg(feature = "create")]
+pub use create::{
+    application_dir_for_create, backup_dir_for_create, cache_dir_for_create, config_dir_for_create,
+    config_local_dir_for_create, create_dir, create_dir_for, create_private_dir,
+    create_private_dir_for, data_dir_for_create, data_local_dir_for_create, desktop_dir_for_create,
+    document_dir_for_create, download_dir_for_create, favorites_dir_for_create,
+    icons_dir_for_create, lock_dir_for_create, log_dir_for_create, preference_dir_for_create,
+    public_share_dir_for_create, saved_games_dir_for_create, secrets_dir_for_create,
+    socket_dir_for_create, system_log_dir_for_create, temp_dir_for_create, template_dir_for_create,
+    themes_dir_for_create, user_application_dir_for_create,
+};
+
+#[cfg(feature = "create")]
+mod remove;
+#[cfg(feature = "create")]
+pub use remove::{remove_all_dirs_for, RemovePolicy, RemoveReport};
+
+#[cfg(feature = "create")]
+mod initialize;
+#[cfg(feature = "create")]
+pub use initialize::{initialize_app_dirs, AppLayout, InitReport};
+
+#[cfg(feature = "create")]
+mod export;
+#[cfg(feature = "create")]
+pub use export::{export_app_dirs, import_app_dirs};
+
+#[cfg(feature = "tokio")]
+mod ensure;
+#[cfg(feature = "tokio")]
+pub use ensure::{
+    ensure_cache_dir_for, ensure_config_dir_for, ensure_config_local_dir_for, ensure_data_dir_for,
+    ensure_data_local_dir_for, ensure_dir, ensure_dir_for, ensure_log_dir_for,
+    ensure_private_dir_for,
+};
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
 ///
-/// Returns the path to the system's application directory.
+/// Returns the path to the user's home directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$HOME`                                  | /home/alice                             |
+/// | macOS   | `$HOME`                                  | /Users/Alice                            |
+/// | Windows | `{FOLDERID_Profile}`                     | C:\Users\Alice                          |
+///
+/// The individual XDG base directories, and how each was resolved, are returned by
+/// [`xdg_base`](fn.xdg_base.html).
+///
+pub fn home_dir() -> Option<PathBuf> {
+    override_root::apply(PlatformProvider.home_dir())
+}
+
+///
+/// Returns the path to the system's application directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | /opt                                     | /opt                     |
+/// | macOS   | /Applications                            | /Applications            |
+/// | Windows | `{FOLDERID_ProgramFiles}`                | C:\Program Files         |
+///
+/// See also [`application_dir_for`](fn.application_dir_for.html),
+///   [`application_shared_dir`](fn.application_shared_dir.html), and
+///   [`user_application_dir`](fn.user_application_dir.html).
+///
+pub fn application_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Application)
+}
+
+///
+/// Returns the path to the conventional installation directory of a specific application,
+/// installed for all users.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | /opt/{app}                               | /opt/MyApp               |
+/// | macOS   | /Applications/{app}.app                  | /Applications/MyApp.app  |
+/// | Windows | `{FOLDERID_ProgramFiles}`\{app}          | C:\Program Files\MyApp   |
+///
+/// On Linux, applications installed by the distribution's package manager are instead placed
+/// beneath /usr, as in /usr/lib/{app}; /opt is for add-on packages. On macOS this is the
+/// application bundle, as returned by [`app_container_dir_for`](fn.app_container_dir_for.html).
+///
+/// See also [`application_dir`](fn.application_dir.html).
+///
+pub fn application_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Application, app)
+}
+
+///
+/// Returns the path to the system's application shared components directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | -                                        | -                        |
+/// | macOS   | /Library/Frameworks                      | /Library/Frameworks      |
+/// | Windows | `{FOLDERID_ProgramFilesCommon}`          | C:\Program Files\Common Files |
+///
+/// See also [`application_dir`](fn.application_dir.html) and
+///   [`user_application_dir`](fn.user_application_dir.html).
+///
+pub fn application_shared_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::ApplicationShared)
+}
+
+///
+/// Returns the path to the user's application directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | `$HOME`/.local/opt                       | /home/alice/.local/opt   |
+/// | macOS   | `$HOME`/Applications                     | /Users/Alice/Applications     |
+/// | Windows | `{FOLDERID_UserProgramFiles}`            | C:\Users\Alice\AppData\Local\Programs |
+///
+/// On Windows the `UserProgramFiles` Known Folder is often not registered, in which case this
+/// falls back to `{FOLDERID_LocalAppData}`\Programs, its default location, which may not yet
+/// exist; [`explain_dir`](fn.explain_dir.html) reports whether the fallback was used.
+///
+/// On Linux the prefix `$HOME`/.local is replaced by the parent of `$XDG_BIN_HOME`, if that is
+/// set; executables intended to be on the user's `$PATH` belong in its `bin` sibling.
+///
+/// See also [`application_dir`](fn.application_dir.html),
+///   [`application_shared_dir`](fn.application_shared_dir.html), and
+///   [`user_application_dir_for`](fn.user_application_dir_for.html).
+///
+pub fn user_application_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::UserApplication)
+}
+
+///
+/// Returns the path to the conventional installation directory of a specific application,
+/// installed for the user only.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | `$HOME`/.local/opt/{app}                 | /home/alice/.local/opt/MyApp |
+/// | macOS   | `$HOME`/Applications/{app}.app           | /Users/Alice/Applications/MyApp.app |
+/// | Windows | `{FOLDERID_UserProgramFiles}`\{app}      | C:\Users\Alice\AppData\Local\Programs\MyApp |
+///
+/// See also [`user_application_dir`](fn.user_application_dir.html).
+///
+pub fn user_application_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::UserApplication, app)
+}
+
+///
+/// Returns the path to the user's application shared components directory, in which per-user
+/// installers may place shared components without administrative rights.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | -                                        | -                        |
+/// | macOS   | `$HOME`/Library/Frameworks               | /Users/Alice/Library/Frameworks |
+/// | Windows | `{FOLDERID_UserProgramFilesCommon}`      | C:\Users\Alice\AppData\Local\Programs\Common |
+///
+/// As for [`user_application_dir`](fn.user_application_dir.html), on Windows this falls back to
+/// `{FOLDERID_LocalAppData}`\Programs\Common when the Known Folder is not registered.
+///
+/// See also [`application_shared_dir`](fn.application_shared_dir.html).
+///
+pub fn user_application_shared_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::UserApplicationShared)
+}
+
+///
+/// Returns the path to the directory in which the user's own executables are installed, which is
+/// expected to be on the user's `$PATH`.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | `$XDG_BIN_HOME` or `$HOME`/.local/bin    | /home/alice/.local/bin   |
+/// | macOS   | -                                        | -                        |
+/// | Windows | -                                        | -                        |
+///
+/// See also [`user_application_dir`](fn.user_application_dir.html).
+///
+pub fn executable_dir() -> Option<PathBuf> {
+    override_root::apply(sys::executable_dir())
+}
+
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path to the application container directory, for a given system application name.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | /opt/{app}                               | /opt/MyApp               |
+/// | macOS   | /Applications/{app}                      | /Applications/MyApp.app  |
+/// | Windows | `{FOLDERID_ProgramFiles}`\{app}          | C:\Program Files\MyApp   |
+///
+/// On Linux the first of /opt/{app}, /usr/lib/{app}, and /usr/share/{app} that exists is
+/// returned, otherwise the conventional /opt/{app}. On Windows an application registered in the
+/// system's `App Paths` key is found in the directory recorded there. Otherwise, with the
+/// `registry` feature, if the conventional installation directory does not exist, an application
+/// registered in the system's `Uninstall` key, by name or display name, is found in its
+/// `InstallLocation`; failing that, the conventional installation directory is returned.
+///
+pub fn app_container_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::AppContainer, app)
+}
+
+///
+/// Returns the path to the application container's executable directory, for a given system
+/// application name.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | /opt/{app}/bin                           | /opt/MyApp/bin           |
+/// | macOS   | /Applications/{app}/Contents/MacOS       | /Applications/MyApp.app/Contents/MacOS  |
+/// | Windows | `{FOLDERID_ProgramFiles}`\{app}          | C:\Program Files\MyApp   |
+///
+/// On Linux an application found beneath /usr, without a `bin` directory of its own, has its
+/// executables in /usr/bin.
+///
+pub fn app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::AppContainerExecutable, app)
+}
+
+///
+/// Returns the path to the application container's resources directory, for a given system
+/// application name; this holds the images, nib files, and localized strings of the bundle.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | -                                        | -                        |
+/// | macOS   | /Applications/{app}/Contents/Resources | /Applications/MyApp.app/Contents/Resources |
+/// | Windows | -                                        | -                        |
+///
+pub fn app_container_resources_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::AppContainerResources, app)
+}
+
+///
+/// Returns the path to the application container's frameworks directory, for a given system
+/// application name; this holds the private frameworks and shared libraries embedded in the bundle.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | -                                        | -                        |
+/// | macOS   | /Applications/{app}/Contents/Frameworks | /Applications/MyApp.app/Contents/Frameworks |
+/// | Windows | -                                        | -                        |
+///
+pub fn app_container_frameworks_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::AppContainerFrameworks, app)
+}
+
+///
+/// Returns the path to the application container's plug-ins directory, for a given system
+/// application name; this holds the plug-ins and app extensions embedded in the bundle.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | -                                        | -                        |
+/// | macOS   | /Applications/{app}/Contents/PlugIns | /Applications/MyApp.app/Contents/PlugIns |
+/// | Windows | -                                        | -                        |
+///
+pub fn app_container_plugins_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::AppContainerPlugins, app)
+}
+
+///
+/// Returns the path to the application container directory, for a given user application name.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | `$HOME`/.local/opt/{app}                 | /home/alice/.local/opt/MyApp |
+/// | macOS   | `$HOME`/Applications/{app}               | /Applications/MyApp.app  |
+/// | Windows | `{FOLDERID_UserProgramFiles}`\{app}      | C:\Users\Alice\AppData\Local\Programs\MyApp |
+///
+/// On Windows an application registered in the user's `App Paths` key is found in the
+/// directory recorded there. Otherwise, with the `registry` feature, if the conventional
+/// installation directory does not exist, an application registered in the user's `Uninstall`
+/// key is found in its `InstallLocation`; failing that, the conventional installation directory
+/// is returned.
+///
+pub fn user_app_container_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::UserAppContainer, app)
+}
+
+///
+/// Returns the path to the application container's executable directory, for a given user
+/// application name.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | `$HOME`/.local/opt/{app}/bin             | /home/alice/.local/opt/MyApp/bin |
+/// | macOS   | `$HOME`/Applications/{app}/Contents/MacOS | /Users/alica/Applications/MyApp.app/Contents/MacOS  |
+/// | Windows | `{FOLDERID_UserProgramFiles}`\{app}      | C:\Users\Alice\AppData\Local\Programs\MyApp |
+///
+pub fn user_app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::UserAppContainerExecutable, app)
+}
+
+///
+/// Returns the path to the application container's resources directory, for a given user
+/// application name; this holds the images, nib files, and localized strings of the bundle.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | -                                        | -                        |
+/// | macOS   | `$HOME`/Applications/{app}/Contents/Resources | /Users/Alice/Applications/MyApp.app/Contents/Resources |
+/// | Windows | -                                        | -                        |
+///
+pub fn user_app_container_resources_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::UserAppContainerResources, app)
+}
+
+///
+/// Returns the path to the application container's frameworks directory, for a given user
+/// application name; this holds the private frameworks and shared libraries embedded in the bundle.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | -                                        | -                        |
+/// | macOS   | `$HOME`/Applications/{app}/Contents/Frameworks | /Users/Alice/Applications/MyApp.app/Contents/Frameworks |
+/// | Windows | -                                        | -                        |
+///
+pub fn user_app_container_frameworks_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::UserAppContainerFrameworks, app)
+}
+
+///
+/// Returns the path to the application container's plug-ins directory, for a given user
+/// application name; this holds the plug-ins and app extensions embedded in the bundle.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                  |
+/// | ------- | -----------------------------------------| ------------------------ |
+/// | Linux   | -                                        | -                        |
+/// | macOS   | `$HOME`/Applications/{app}/Contents/PlugIns | /Users/Alice/Applications/MyApp.app/Contents/PlugIns |
+/// | Windows | -                                        | -                        |
+///
+pub fn user_app_container_plugins_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::UserAppContainerPlugins, app)
+}
+
+///
+/// Returns the path to the data directory of the macOS app sandbox container for the application
+/// with the bundle identifier `bundle_id`; this allows an application, sandboxed or not, to
+/// locate the files of another sandboxed application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | -                                              | -                              |
+/// | macOS   | `$HOME`/Library/Containers/{bundle_id}/Data    | /Users/Alice/Library/Containers/com.example.MyApp/Data |
+/// | Windows | -                                              | -                              |
+///
+/// Within the sandbox `$HOME` is itself the container's data directory, and so the directories
+/// returned by the other functions are already within the container; the user's own home
+/// directory is recovered so that this function, and those that locate installed applications
+/// such as [`user_application_dir`](fn.user_application_dir.html), return the same values inside
+/// and outside the sandbox.
+///
+pub fn sandbox_container_dir_for(bundle_id: &str) -> Option<PathBuf> {
+    override_root::apply(sys::sandbox_container_dir_for(bundle_id))
+}
+
+///
+/// Returns the path to the macOS group container with the application group identifier
+/// `group_id`, which an application shares with its extensions and helpers.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | -                                              | -                              |
+/// | macOS   | `$HOME`/Library/Group Containers/{group_id}    | /Users/Alice/Library/Group Containers/group.com.example.MyApp |
+/// | Windows | -                                              | -                              |
+///
+/// As for [`sandbox_container_dir_for`](fn.sandbox_container_dir_for.html), the same value is
+/// returned inside and outside the app sandbox.
+///
+pub fn group_container_dir_for(group_id: &str) -> Option<PathBuf> {
+    override_root::apply(sys::group_container_dir_for(group_id))
+}
+
+///
+/// Returns the path to the macOS directory of user-provided automation scripts for the
+/// application with the bundle identifier `bundle_id`; a sandboxed application may run scripts
+/// from this directory, but may not write to it.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | -                                              | -                              |
+/// | macOS   | `$HOME`/Library/Application Scripts/{bundle_id} | /Users/Alice/Library/Application Scripts/com.example.MyApp |
+/// | Windows | -                                              | -                              |
+///
+pub fn application_scripts_dir_for(bundle_id: &str) -> Option<PathBuf> {
+    override_root::apply(sys::application_scripts_dir_for(bundle_id))
+}
+
+///
+/// Returns the path to the directory of the user's launch agents, the property lists of
+/// background helpers started by `launchd` when the user logs in.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | `$HOME`/Library/LaunchAgents             | /Users/Alice/Library/LaunchAgents       |
+/// | Windows | -                                        | -                                       |
+///
+/// The file name of a launch agent is returned by
+/// [`suggested_artifact_name`](fn.suggested_artifact_name.html) for
+/// `ArtifactKind::PropertyList`.
+///
+pub fn launch_agents_dir() -> Option<PathBuf> {
+    override_root::apply(sys::launch_agents_dir())
+}
+
+///
+/// Returns the path to the directory of launch agents installed for all users.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | /Library/LaunchAgents                    | /Library/LaunchAgents                   |
+/// | Windows | -                                        | -                                       |
+///
+pub fn system_launch_agents_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_launch_agents_dir())
+}
+
+///
+/// Returns the path to the directory of launch daemons, the property lists of system-wide
+/// background services started by `launchd` at boot.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | /Library/LaunchDaemons                   | /Library/LaunchDaemons                  |
+/// | Windows | -                                        | -                                       |
+///
+pub fn launch_daemons_dir() -> Option<PathBuf> {
+    override_root::apply(sys::launch_daemons_dir())
+}
+
+///
+/// Returns the path to the directory in which an application registers itself to be started
+/// when the user logs in.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config/autostart | /home/alice/.config/autostart    |
+/// | macOS   | `$HOME`/Library/LaunchAgents             | /Users/Alice/Library/LaunchAgents       |
+/// | Windows | `{FOLDERID_Startup}`                     | C:\Users\Alice\AppData\Roaming\Microsoft\Windows\Start Menu\Programs\Startup |
+///
+/// The entry placed in this directory is a desktop entry on Linux, a launch agent property list
+/// on macOS, and a shortcut on Windows; its file name is returned by
+/// [`suggested_artifact_name`](fn.suggested_artifact_name.html).
+///
+pub fn autostart_dir() -> Option<PathBuf> {
+    override_root::apply(sys::autostart_dir())
+}
+
+///
+/// Returns the path to the directory in which fonts are installed for the user.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/fonts | /home/alice/.local/share/fonts    |
+/// | macOS   | `$HOME`/Library/Fonts                    | /Users/Alice/Library/Fonts              |
+/// | Windows | `{FOLDERID_LocalAppData}`\Microsoft\Windows\Fonts | C:\Users\Alice\AppData\Local\Microsoft\Windows\Fonts |
+///
+/// On Linux the legacy directory `$HOME`/.fonts is returned instead if it exists, and the XDG
+/// directory does not. On Windows a font copied to this directory must also be registered before
+/// it is available to other applications.
+///
+pub fn font_dir() -> Option<PathBuf> {
+    override_root::apply(sys::font_dir())
+}
+
+///
+/// Returns the path to the directory in which fonts are installed for all users; writing to this
+/// directory will usually require elevated privileges.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/local/share/fonts                   | /usr/local/share/fonts                  |
+/// | macOS   | /Library/Fonts                           | /Library/Fonts                          |
+/// | Windows | `{FOLDERID_Fonts}`                       | C:\Windows\Fonts                        |
+///
+/// See also [`font_dir`](fn.font_dir.html).
+///
+pub fn system_font_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_font_dir())
+}
+
+///
+/// Returns the path to the directory in which manual pages are installed for the user; pages are
+/// placed in the section subdirectories, such as `man1`, of this directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/man | /home/alice/.local/share/man        |
+/// | macOS   | `$XDG_DATA_HOME` or `$HOME`/.local/share/man | /Users/Alice/.local/share/man       |
+/// | Windows | -                                        | -                                       |
+///
+/// Not every `man` implementation searches this directory by default; it may need to be added
+/// to `$MANPATH`.
+///
+pub fn man_dir() -> Option<PathBuf> {
+    override_root::apply(sys::man_dir())
+}
+
+///
+/// Returns the path to the directory in which manual pages are installed for all users; writing
+/// to this directory will usually require elevated privileges.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/local/share/man                     | /usr/local/share/man                    |
+/// | macOS   | /usr/local/share/man                     | /usr/local/share/man                    |
+/// | Windows | -                                        | -                                       |
+///
+/// See also [`man_dir`](fn.man_dir.html).
+///
+pub fn system_man_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_man_dir())
+}
+
+///
+/// Returns the path to the directory in which an application is added to the user's desktop
+/// launcher.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/applications | /home/alice/.local/share/applications |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_Programs}`                    | C:\Users\Alice\AppData\Roaming\Microsoft\Windows\Start Menu\Programs |
+///
+/// The entry placed in this directory is a desktop entry on Linux and a shortcut on Windows; its
+/// file name is returned by [`suggested_artifact_name`](fn.suggested_artifact_name.html).
+///
+pub fn desktop_entry_dir() -> Option<PathBuf> {
+    override_root::apply(sys::desktop_entry_dir())
+}
+
+///
+/// Returns the path to the directory in which an application is added to the desktop launcher
+/// of all users; writing to this directory will usually require elevated privileges.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/share/applications, or /usr/local/share/applications on the BSDs | /usr/share/applications |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_CommonPrograms}`              | C:\ProgramData\Microsoft\Windows\Start Menu\Programs |
+///
+/// See also [`desktop_entry_dir`](fn.desktop_entry_dir.html).
+///
+pub fn system_desktop_entry_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_desktop_entry_dir())
+}
+
+///
+/// Returns the path to the user's shared MIME-info directory, in whose `packages` subdirectory an
+/// application installs the XML describing its custom file types, before running
+/// `update-mime-database` on this directory.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/mime | /home/alice/.local/share/mime      |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | -                                        | -                                       |
+///
+/// File types are declared in an application's `Info.plist` on macOS, and registered in the
+/// registry, beneath `HKEY_CLASSES_ROOT`, on Windows.
+///
+/// See also [`system_mime_dir`](fn.system_mime_dir.html).
+///
+pub fn mime_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Mime)
+}
+
+///
+/// Returns the path to the shared MIME-info directory of all users; writing to this directory
+/// will usually require elevated privileges.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/share/mime, or /usr/local/share/mime on the BSDs | /usr/share/mime            |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | -                                        | -                                       |
+///
+/// See also [`mime_dir`](fn.mime_dir.html).
+///
+pub fn system_mime_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_mime_dir())
+}
+
+///
+/// Returns the path to the user's GSettings schema directory, in which a GTK or GLib application
+/// installs its `.gschema.xml` files, before running `glib-compile-schemas` on this directory.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/glib-2.0/schemas | /home/alice/.local/share/glib-2.0/schemas |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | -                                        | -                                       |
+///
+/// See also [`system_glib_schemas_dir`](fn.system_glib_schemas_dir.html).
+///
+pub fn glib_schemas_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::GlibSchemas)
+}
+
+///
+/// Returns the path to the GSettings schema directory of all users; writing to this directory
+/// will usually require elevated privileges.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/share/glib-2.0/schemas, or /usr/local/share/glib-2.0/schemas on the BSDs | /usr/share/glib-2.0/schemas |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | -                                        | -                                       |
+///
+/// See also [`glib_schemas_dir`](fn.glib_schemas_dir.html).
+///
+pub fn system_glib_schemas_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_glib_schemas_dir())
+}
+
+///
+/// Returns the path to the directory in which GSettings' keyfile backend keeps the user's
+/// settings, in the file `keyfile`, where dconf is not used.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config/glib-2.0/settings | /home/alice/.config/glib-2.0/settings |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | -                                        | -                                       |
+///
+pub fn keyfile_backend_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::KeyfileBackend)
+}
+
+///
+/// Returns the path to the directory shared by all users of this machine, for data exchanged
+/// between them; unlike [`public_dir`](fn.public_dir.html) this does not belong to any one user.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/share, or /usr/local/share on the BSDs | /usr/share                           |
+/// | macOS   | /Users/Shared                            | /Users/Shared                           |
+/// | Windows | `{FOLDERID_ProgramData}`                 | C:\ProgramData                          |
+///
+/// On Linux this directory is read-only to users, and writing to it will usually require
+/// elevated privileges. On Windows a user may create a directory here, but only its creator may
+/// write to it unless the installer grants access to all users.
+///
+pub fn public_data_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::PublicData)
+}
+
+///
+/// Returns the path to the public data directory for a specific application.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/share/{app}                         | /usr/share/MyApp                        |
+/// | macOS   | /Users/Shared/{app}                      | /Users/Shared/MyApp                     |
+/// | Windows | `{FOLDERID_ProgramData}`\{app}           | C:\ProgramData\MyApp                    |
+///
+pub fn public_data_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::PublicData, app)
+}
+
+///
+/// Returns the path to the documents directory shared by all users of this machine.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | /Users/Shared                            | /Users/Shared                           |
+/// | Windows | `{FOLDERID_PublicDocuments}`             | C:\Users\Public\Documents               |
+///
+pub fn shared_documents_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::SharedDocuments)
+}
+
+///
+/// Returns the path to the shared documents directory for a specific application.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | /Users/Shared/{app}                      | /Users/Shared/MyApp                     |
+/// | Windows | `{FOLDERID_PublicDocuments}`\{app}       | C:\Users\Public\Documents\MyApp         |
+///
+pub fn shared_documents_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::SharedDocuments, app)
+}
+
+///
+/// Returns the path to the directory in which an application registers itself to be started
+/// when any user logs in; writing to this directory will usually require elevated privileges.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | the first of `$XDG_CONFIG_DIRS`, or /etc/xdg (/usr/local/etc/xdg on the BSDs), /autostart | /etc/xdg/autostart |
+/// | macOS   | /Library/LaunchAgents                    | /Library/LaunchAgents                   |
+/// | Windows | `{FOLDERID_CommonStartup}`               | C:\ProgramData\Microsoft\Windows\Start Menu\Programs\StartUp |
+///
+/// See also [`autostart_dir`](fn.autostart_dir.html).
+///
+pub fn system_autostart_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_autostart_dir())
+}
+
+///
+/// Returns the path to the user's documents directory, in which files the user has created, or
+/// which should be visible to them, are kept.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `XDG_DOCUMENTS_DIR`                            | /home/alice/Documents          |
+/// | macOS   | `$HOME`/Documents                              | /Users/Alice/Documents         |
+/// | iOS     | `$HOME`/Documents                              | /var/mobile/Containers/Data/Application/{UUID}/Documents |
+/// | Windows | `{FOLDERID_Documents}`                         | C:\Users\Alice\Documents        |
+///
+pub fn document_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Document)
+}
+
+///
+/// Returns the path to the directory in which a specific application should save the documents it
+/// exports for the user.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `XDG_DOCUMENTS_DIR`/{app}                      | /home/alice/Documents/MyApp    |
+/// | macOS   | `$HOME`/Documents/{app}                        | /Users/Alice/Documents/MyApp   |
+/// | Windows | `{FOLDERID_Documents}`\{app}                   | C:\Users\Alice\Documents\MyApp  |
+///
+pub fn document_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Document, app)
+}
+
+///
+/// Returns the path to the user's downloads directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `XDG_DOWNLOAD_DIR`                             | /home/alice/Downloads          |
+/// | macOS   | `$HOME`/Downloads                              | /Users/Alice/Downloads         |
+/// | Windows | `{FOLDERID_Downloads}`                         | C:\Users\Alice\Downloads        |
+///
+pub fn download_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Download)
+}
+
+///
+/// Returns the path to the directory in which a specific application should save the files it
+/// downloads for the user.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `XDG_DOWNLOAD_DIR`/{app}                       | /home/alice/Downloads/MyApp    |
+/// | macOS   | `$HOME`/Downloads/{app}                        | /Users/Alice/Downloads/MyApp   |
+/// | Windows | `{FOLDERID_Downloads}`\{app}                   | C:\Users\Alice\Downloads\MyApp  |
+///
+pub fn download_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Download, app)
+}
+
+///
+/// Returns the path to the user's desktop directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `XDG_DESKTOP_DIR`                              | /home/alice/Desktop            |
+/// | macOS   | `$HOME`/Desktop                                | /Users/Alice/Desktop           |
+/// | Windows | `{FOLDERID_Desktop}`                           | C:\Users\Alice\Desktop          |
+///
+pub fn desktop_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Desktop)
+}
+
+///
+/// Returns the path to a directory on the user's desktop for a specific application, for example
+/// to hold the shortcuts to its documents.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `XDG_DESKTOP_DIR`/{app}                        | /home/alice/Desktop/MyApp      |
+/// | macOS   | `$HOME`/Desktop/{app}                          | /Users/Alice/Desktop/MyApp     |
+/// | Windows | `{FOLDERID_Desktop}`\{app}                     | C:\Users\Alice\Desktop\MyApp    |
+///
+pub fn desktop_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Desktop, app)
+}
+
+///
+/// Returns the path to the directory in which files are shared with the other users of this
+/// machine.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `XDG_PUBLICSHARE_DIR`                          | /home/alice/Public             |
+/// | macOS   | `$HOME`/Public                                 | /Users/Alice/Public            |
+/// | Windows | `{FOLDERID_Public}`                            | C:\Users\Public                |
+///
+/// Note that on Linux and macOS this directory belongs to the user, and may be read, but not
+/// written, by others; on Windows it is shared by, and writable by, all users.
+///
+pub fn public_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::PublicShare)
+}
+
+///
+/// Returns the path to the directory in which a specific application should place the files it
+/// shares with the other users of this machine.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `XDG_PUBLICSHARE_DIR`/{app}                    | /home/alice/Public/MyApp       |
+/// | macOS   | `$HOME`/Public/{app}                           | /Users/Alice/Public/MyApp      |
+/// | Windows | `{FOLDERID_Public}`\{app}                      | C:\Users\Public\MyApp          |
+///
+pub fn public_share_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::PublicShare, app)
+}
+
+///
+/// Returns the path to the user's trash directory, on the volume holding their home directory.
+/// Files on other volumes are moved to a trash directory on that volume, which is not returned
+/// here.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share, /Trash | /home/alice/.local/share/Trash |
+/// | macOS   | `$HOME`/.Trash                                 | /Users/Alice/.Trash            |
+/// | Windows | -                                              | -                              |
+///
+/// On Linux the directory follows the
+/// [FreeDesktop.org Trash specification](https://specifications.freedesktop.org/trash-spec/trashspec-latest.html),
+/// and holds the `files` and `info` directories. On Windows the Recycle Bin is not a directory that
+/// may be used directly, and so there is no value.
+///
+pub fn trash_dir() -> Option<PathBuf> {
+    override_root::apply(sys::trash_dir())
+}
+
+///
+/// Returns the path to the directory in which the platform records the documents the user has
+/// recently used.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share       | /home/alice/.local/share       |
+/// | macOS   | `$HOME`/Library/Application Support/com.apple.sharedfilelist | /Users/Alice/Library/Application Support/com.apple.sharedfilelist |
+/// | Windows | `{FOLDERID_Recent}`                            | C:\Users\Alice\AppData\Roaming\Microsoft\Windows\Recent |
+///
+/// On Linux the list is held in the file recently-used.xbel within this directory, as described
+/// by the [Desktop Bookmark specification](https://www.freedesktop.org/wiki/Specifications/desktop-bookmark-spec/);
+/// on Windows each document is a shortcut in the directory.
+///
+pub fn recent_dir() -> Option<PathBuf> {
+    override_root::apply(sys::recent_dir())
+}
+
+///
+/// Returns the path to the directory in which a specific application should keep its history
+/// files, such as those of a shell or the command line of an editor.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `$XDG_STATE_HOME` or `$HOME`/.local/state/{app}/history | /home/alice/.local/state/MyApp/history |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/History | /Users/Alice/Library/Application Support/MyApp/History |
+/// | Windows | `{FOLDERID_LocalAppData}`\{app}\History        | C:\Users\Alice\AppData\Local\MyApp\History |
+///
+pub fn history_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::History, app)
+}
+
+///
+/// Returns the path to the directory in which a specific application should keep its own backups,
+/// or snapshots, of the user's data.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `$XDG_STATE_HOME` or `$HOME`/.local/state/{app}/backups | /home/alice/.local/state/MyApp/backups |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/Backups | /Users/Alice/Library/Application Support/MyApp/Backups |
+/// | Windows | `{FOLDERID_RoamingAppData}`\{app}\Backups      | C:\Users\Alice\AppData\Roaming\MyApp\Backups |
+///
+pub fn backup_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Backup, app)
+}
+
+///
+/// Returns the path to the directory in which a specific application should keep secrets, such
+/// as access tokens, that must be stored on disk. This is always a local, non-roaming, per-user
+/// location.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}/secrets | /home/alice/.local/share/MyApp/secrets |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/Secrets | /Users/Alice/Library/Application Support/MyApp/Secrets |
+/// | Windows | `{FOLDERID_LocalAppData}`\{app}\Secrets        | C:\Users\Alice\AppData\Local\MyApp\Secrets |
+///
+/// The directory should be created with
+/// [`secrets_dir_for_create`](fn.secrets_dir_for_create.html), which ensures that it is only
+/// accessible by the current user. Where the platform provides a credential store, such as the
+/// macOS Keychain, it should be preferred.
+///
+pub fn secrets_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Secrets, app)
+}
+
+///
+/// Returns the path to the directory in which the user installs plugins, or extensions, for a
+/// specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}/plugins | /home/alice/.local/share/MyApp/plugins |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/PlugIns | /Users/Alice/Library/Application Support/MyApp/PlugIns |
+/// | Windows | `{FOLDERID_RoamingAppData}`\{app}\Plugins      | C:\Users\Alice\AppData\Roaming\MyApp\Plugins |
+///
+/// See also [`plugin_search_dirs_for`](fn.plugin_search_dirs_for.html).
+///
+pub fn plugins_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Plugins, app)
+}
+
+///
+/// Returns the path to the directory in which plugins, or extensions, for a specific application
+/// are installed for all users; writing to this directory will usually require elevated
+/// privileges.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | the first of `$XDG_DATA_DIRS`, or /usr/local/share, /{app}/plugins | /usr/local/share/MyApp/plugins |
+/// | macOS   | /Library/Application Support/{app}/PlugIns     | /Library/Application Support/MyApp/PlugIns |
+/// | Windows | `{FOLDERID_ProgramData}`\{app}\Plugins         | C:\ProgramData\MyApp\Plugins    |
+///
+pub fn system_plugins_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::SystemPlugins, app)
+}
+
+///
+/// Returns the directories in which a specific application should look for plugins, in order of
+/// precedence; the user's directory first, followed by those for all users. On Linux there is
+/// one directory for each of `$XDG_DATA_DIRS`.
+///
+/// ```rust
+/// for dir in xdirs::plugin_search_dirs_for("MyApp") {
+///     println!("loading plugins from {:?}", dir);
+/// }
+/// ```
+///
+pub fn plugin_search_dirs_for(app: &str) -> Vec<PathBuf> {
+    // the first of the shared directories is that returned by system_plugins_dir_for, any others
+    // are those of the remaining shared data directories.
+    plugins_dir_for(app)
+        .into_iter()
+        .chain(system_plugins_dir_for(app))
+        .chain(
+            sys::extra_plugin_dirs_for(app)
+                .into_iter()
+                .filter_map(|path| override_root::apply(Some(path))),
+        )
+        .collect()
+}
+
+///
+/// Returns the path to the directory in which a specific application installs its translation
+/// catalogs.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}/locale | /home/alice/.local/share/MyApp/locale |
+/// | macOS   | /Applications/{app}/Contents/Resources         | /Applications/MyApp.app/Contents/Resources |
+/// | Windows | `{FOLDERID_RoamingAppData}`\{app}\locale       | C:\Users\Alice\AppData\Roaming\MyApp\locale |
+///
+/// Within this directory catalogs are arranged by language; on Linux and Windows as
+/// `{lang}/LC_MESSAGES/{app}.mo`, following gettext, and on macOS as `{lang}.lproj`.
+///
+/// See also [`locale_search_dirs_for`](fn.locale_search_dirs_for.html).
+///
+pub fn locale_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Locale, app)
+}
+
+///
+/// Returns the directories in which a specific application should look up translation catalogs,
+/// in order of precedence; the first is that returned by
+/// [`locale_dir_for`](fn.locale_dir_for.html).
+///
+/// On Linux this is followed by the shared `locale` directory within each of `$XDG_DATA_DIRS`, as
+/// in /usr/share/locale, in which catalogs are named for the application. On macOS the resources
+/// of the user's copy of the application bundle, in `$HOME`/Applications, are searched first.
+///
+/// ```rust
+/// for dir in xdirs::locale_search_dirs_for("MyApp") {
+///     let catalog = dir.join("fr/LC_MESSAGES/MyApp.mo");
+///     println!("looking for {:?}", catalog);
+/// }
+/// ```
+///
+pub fn locale_search_dirs_for(app: &str) -> Vec<PathBuf> {
+    // only on macOS, where the locale directory is within the application bundle, is there a
+    // user's copy of the bundle's resources.
+    user_app_container_resources_dir_for(app)
+        .into_iter()
+        .chain(locale_dir_for(app))
+        .chain(
+            sys::system_locale_dirs()
+                .into_iter()
+                .filter_map(|path| override_root::apply(Some(path))),
+        )
+        .collect()
+}
+
+///
+/// Returns the path to the local copy of the user's iCloud Drive, or `None` if iCloud is not
+/// configured.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | -                                              | -                              |
+/// | macOS   | `$HOME`/Library/Mobile Documents/com~apple~CloudDocs | /Users/Alice/Library/Mobile Documents/com~apple~CloudDocs |
+/// | Windows | -                                              | -                              |
+///
+/// See also [`icloud_container_dir_for`](fn.icloud_container_dir_for.html).
+///
+pub fn icloud_drive_dir() -> Option<PathBuf> {
+    override_root::apply(sys::icloud_drive_dir())
+}
+
+///
+/// Returns the path to the local copy of the iCloud container with the identifier
+/// `container_id`, such as `iCloud.com.example.MyApp`, or `None` if iCloud is not configured;
+/// the periods in the identifier are replaced with `~`, as they are by macOS.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | -                                              | -                              |
+/// | macOS   | `$HOME`/Library/Mobile Documents/{container_id} | /Users/Alice/Library/Mobile Documents/iCloud~com~example~MyApp |
+/// | Windows | -                                              | -                              |
+///
+/// The container itself may not exist until the application has been granted access to it.
+///
+pub fn icloud_container_dir_for(container_id: &str) -> Option<PathBuf> {
+    override_root::apply(sys::icloud_container_dir_for(container_id))
+}
+
+///
+/// Returns the bundle identifier of the macOS app sandbox container the current process is
+/// running in, from the environment variable `APP_SANDBOX_CONTAINER_ID`, or `None` if it is
+/// not sandboxed.
+///
+pub fn sandbox_container_id() -> Option<String> {
+    sys::sandbox_container_id()
+}
+
+///
+/// Returns the application identifier of the Flatpak sandbox the current process is running in,
+/// from the environment variable `FLATPAK_ID` or the sandbox's `/.flatpak-info`, or `None` if it
+/// is not running in Flatpak.
+///
+pub fn flatpak_id() -> Option<String> {
+    sys::flatpak_id()
+}
+
+///
+/// Returns the name of the Snap the current process is running in, from the environment variable
+/// `SNAP_NAME`, or `None` if it is not running in a Snap.
+///
+pub fn snap_name() -> Option<String> {
+    sys::snap_name()
+}
+
+///
+/// Returns the path to the read-only directory the running application is installed in, if it
+/// is running within a Flatpak or Snap sandbox.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /app, or `$SNAP`                         | /snap/myapp/42                          |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | -                                        | -                                       |
+///
+/// Within these sandboxes the host's installation directories are not visible, and so
+/// [`application_dir`](fn.application_dir.html) and
+/// [`user_application_dir`](fn.user_application_dir.html) return `None`. The XDG base directories
+/// are remapped by the sandbox itself, and need no adjustment.
+///
+pub fn sandboxed_app_dir() -> Option<PathBuf> {
+    override_root::apply(sys::sandboxed_app_dir())
+}
+
+///
+/// Returns the path to the Snap's per-user data directory that is shared between its revisions,
+/// from the environment variable `SNAP_USER_COMMON`, or `None` if not running in a Snap; `$HOME`,
+/// and the directories beneath it, are specific to the Snap's current revision.
+///
+pub fn snap_user_common_dir() -> Option<PathBuf> {
+    override_root::apply(sys::snap_user_common_dir())
+}
+
+///
+/// Returns the package family name of the MSIX package the current process is running as part
+/// of, such as `Example.App_8wekyb3d8bbwe`, or `None` if it is not packaged.
+///
+pub fn package_family_name() -> Option<String> {
+    sys::package_family_name()
+}
+
+///
+/// Returns the path to the per-user data directory of the MSIX package with the given family
+/// name.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_LocalAppData}`\Packages\{family_name} | C:\Users\Alice\AppData\Local\Packages\Example.App_8wekyb3d8bbwe |
+///
+/// The package's application data is kept in the `LocalState`, `RoamingState`, and `LocalCache`
+/// subdirectories. A packaged application may continue to use paths such as
+/// [`config_dir_for`](fn.config_dir_for.html), as Windows redirects its writes beneath
+/// `{FOLDERID_LocalAppData}`\Packages\{family_name}\LocalCache; this function gives the
+/// location of that data to other processes, such as an uninstaller or a migration tool.
+///
+/// ```rust
+/// use xdirs::{package_dir_for, package_family_name};
+///
+/// if let Some(package) = package_family_name().and_then(|name| package_dir_for(&name)) {
+///     println!("packaged data is in {:?}", package);
+/// }
+/// ```
+///
+pub fn package_dir_for(family_name: &str) -> Option<PathBuf> {
+    override_root::apply(sys::package_dir_for(family_name))
+}
+
+///
+/// Returns the path to the user's real home directory, as seen outside of any sandbox the current
+/// process is running in; this differs from [`home_dir`](fn.home_dir.html) within a Snap, where
+/// it is `$SNAP_REAL_HOME`, and within the macOS app sandbox, where it is recovered from the
+/// container's path.
+///
+pub fn host_home_dir() -> Option<PathBuf> {
+    override_root::apply(sys::host_home_dir())
+}
+
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path to the user's cache directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_CACHE_HOME` or `$HOME`/.cache      | /home/alice/.cache                      |
+/// | macOS   | `$HOME`/Library/Caches                   | /Users/Alice/Library/Caches             |
+/// | Windows | `{FOLDERID_LocalAppData}`                | C:\Users\Alice\AppData\Local             |
+///
+/// See also [`cache_dir_for`](fn.cache_dir_for.html).
+///
+pub fn cache_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Cache)
+}
+
+///
+/// Returns the path to the user's cache directory for a specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                               | Example                      |
+/// | ------- | ----------------------------------- | ---------------------------- |
+/// | Linux   | `$XDG_CACHE_HOME` or `$HOME`/.cache/{app} | /home/alice/.cache/MyApp           |
+/// | macOS   | `$HOME`/Library/Caches/{app}        | /Users/Alice/Library/Caches/MyApp  |
+/// | Windows | `{FOLDERID_LocalAppData}`/{app}/Cache | C:\Users\Alice\AppData\Local\MyApp\Cache |
+///
+/// On Windows the cache is nested within the application's local data directory, which is also
+/// its local config directory, so that clearing the cache does not remove either.
+///
+/// See also [`cache_dir`](fn.cache_dir.html).
+///
+pub fn cache_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Cache, app)
+}
+
+///
+/// Returns the path to the user's config directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config    | /home/alice/.config                     |
+/// | macOS   | `$HOME`/Library/Application Support      | /Users/Alice/Library/Application Support |
+/// | Windows | `{FOLDERID_RoamingAppData}`              | C:\Users\Alice\AppData\Roaming           |
+///
+/// See also [`config_dir_for`](fn.config_dir_for.html).
+///
+pub fn config_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Config)
+}
+
+///
+/// Returns the path to the user's config directory for a specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                 | Example                          |
+/// | ------- | ------------------------------------- | -------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config/{app} | /home/alice/.config/MyApp              |
+/// | macOS   | `$HOME`/Library/Application Support/{app}   | /Users/Alice/Library/Application Support/MyApp |
+/// | Windows | `{FOLDERID_RoamingAppData}`/{app}           | C:\Users\Alice\AppData\Roaming\MyApp   |
+///
+/// See also [`config_dir`](fn.config_dir.html)`.
+///
+pub fn config_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Config, app)
+}
+
+///
+/// Returns the path to the user's local config directory; this is for configuration that is
+/// specific to the machine, such as window geometry, and should not roam with the user's profile.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config    | /home/alice/.config                     |
+/// | macOS   | `$HOME`/Library/Application Support      | /Users/Alice/Library/Application Support |
+/// | Windows | `{FOLDERID_LocalAppData}`                | C:\Users\Alice\AppData\Local              |
+///
+/// See also [`config_local_dir_for`](fn.config_local_dir_for.html).
+///
+pub fn config_local_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::ConfigLocal)
+}
+
+///
+/// Returns the path to the user's local config directory for a specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                 | Example                          |
+/// | ------- | ------------------------------------- | -------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config/{app} | /home/alice/.config/MyApp              |
+/// | macOS   | `$HOME`/Library/Application Support/{app}   | /Users/Alice/Library/Application Support/MyApp |
+/// | Windows | `{FOLDERID_LocalAppData}`/{app}             | C:\Users\Alice\AppData\Local\MyApp     |
+///
+/// See also [`config_local_dir`](fn.config_local_dir.html).
+///
+pub fn config_local_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::ConfigLocal, app)
+}
+
+///
+/// Returns the directories in which a specific application should look for its configuration files, in
+/// order of precedence; the user's own directories, as returned by
+/// [`config_dir_for`](fn.config_dir_for.html) and [`config_local_dir_for`](fn.config_local_dir_for.html),
+/// followed by those shared by all users.
+///
+/// The shared directories depend on the operating system, and are as follows.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | each of `$XDG_CONFIG_DIRS`, or /etc/xdg, /{app} | /etc/xdg/MyApp                 |
+/// | macOS   | /Library/Application Support/{app}             | /Library/Application Support/MyApp |
+/// | Windows | `{FOLDERID_ProgramData}`\{app}                 | C:\ProgramData\MyApp           |
+///
+/// See also [`ConfigResolver`](struct.ConfigResolver.html), which orders the copies of a
+/// configuration file in these directories for layered configuration.
+///
+pub fn config_search_dirs_for(app: &str) -> Vec<PathBuf> {
+    search_dirs_for(
+        config_dir_for(app),
+        config_local_dir_for(app),
+        sys::system_config_dirs(),
+        app,
+    )
+}
+
+///
+/// Returns the path to the user's data directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share | /home/alice/.local/share                |
+/// | macOS   | `$HOME`/Library/Application Support      | /Users/Alice/Library/Application Support |
+/// | Windows | `{FOLDERID_RoamingAppData}`              | C:\Users\Alice\AppData\Roaming           |
+///
+/// See also [`data_dir_for`](fn.data_dir_for.html).
+///
+pub fn data_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Data)
+}
+
+///
+/// Returns the path to the user's data directory for a specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app} | /home/alice/.local/share/MyApp                 |
+/// | macOS   | `$HOME`/Library/Application Support/{app}      | /Users/Alice/Library/Application Support/MyApp |
+/// | Windows | `{FOLDERID_RoamingAppData}`/{app}              | C:\Users\Alice\AppData\Roaming\MyApp           |
+///
+/// See also [`data_dir`](fn.data_dir.html).
+///
+pub fn data_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Data, app)
+}
+
+///
+/// Returns the path to the user's local data directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share | /home/alice/.local/share                |
+/// | macOS   | `$HOME`/Library/Application Support      | /Users/Alice/Library/Application Support |
+/// | Windows | `{FOLDERID_LocalAppData}`                | C:\Users\Alice\AppData\Local             |
+///
+/// See also [`data_local_dir_for`](fn.data_local_dir_for.html).
+///
+pub fn data_local_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::DataLocal)
+}
+
+///
+/// Returns the path to the user's local data directory for a specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app} | /home/alice/.local/share/MyApp    |
+/// | macOS   | `$HOME`/Library/Application Support/{app}      | /Users/Alice/Library/Application Support/MyApp |
+/// | Windows | `{FOLDERID_LocalAppData}`/{app}                | C:\Users\Alice\AppData\Local\MyApp |
+///
+/// See also [`data_local_dir`](fn.data_local_dir.html).
+///
+pub fn data_local_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::DataLocal, app)
+}
+
+///
+/// Returns the directories in which a specific application should look for its data files, in
+/// order of precedence; the user's own directories, as returned by
+/// [`data_dir_for`](fn.data_dir_for.html) and [`data_local_dir_for`](fn.data_local_dir_for.html),
+/// followed by those shared by all users.
+///
+/// The shared directories depend on the operating system, and are as follows.
+///
+/// |Platform | Value                                          | Example                        |
+/// | ------- | ---------------------------------------------- | ------------------------------ |
+/// | Linux   | each of `$XDG_DATA_DIRS`, or /usr/local/share and /usr/share, /{app} | /usr/share/MyApp |
+/// | macOS   | /Library/Application Support/{app}             | /Library/Application Support/MyApp |
+/// | Windows | `{FOLDERID_ProgramData}`\{app}                 | C:\ProgramData\MyApp           |
+///
+pub fn data_search_dirs_for(app: &str) -> Vec<PathBuf> {
+    search_dirs_for(
+        data_dir_for(app),
+        data_local_dir_for(app),
+        sys::system_data_dirs(),
+        app,
+    )
+}
+
+///
+/// Returns the directories, shared by all users, in which data files are found, in order of
+/// precedence; these are the generic directories, for resource lookup beyond any one
+/// application's directory.
+///
+/// |Platform | Value                                               | Example                        |
+/// | ------- | --------------------------------------------------- | ------------------------------ |
+/// | Linux   | each of `$XDG_DATA_DIRS`, or /usr/local/share and /usr/share | /usr/local/share, /usr/share |
+/// | macOS   | /Library/Application Support, /Library, /System/Library | /Library                   |
+/// | Windows | `{FOLDERID_ProgramData}`                            | C:\ProgramData                 |
+///
+/// ```rust
+/// use xdirs::system_data_dirs;
+///
+/// let icons = system_data_dirs()
+///     .into_iter()
+///     .map(|dir| dir.join("icons"))
+///     .find(|dir| dir.is_dir());
+/// ```
+///
+pub fn system_data_dirs() -> Vec<PathBuf> {
+    sys::system_data_dirs()
+        .into_iter()
+        .chain(sys::system_domain_dirs())
+        .filter_map(|path| override_root::apply(Some(path)))
+        .collect()
+}
+
+///
+/// Returns the path to the user's favorites directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/favorites | /home/alice/.local/share/favorites |
+/// | macOS   | `$HOME`/Library/Favorites                | /Users/Alice/Library/Favorites          |
+/// | Windows | `{FOLDERID_Favorites}`                   | C:\Users\Alice\Favorites                |
+///
+/// See also [`favorites_dir_for`](fn.favorites_dir_for.html).
+///
+pub fn favorites_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Favorites)
+}
+
+///
+/// Returns the path to the user's favorites directory for a specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/favorites/{app} | /home/alice/.local/share/favorites/MyApp |
+/// | macOS   | `$HOME`/Library/Favorites/{app}          | /Users/Alice/Library/Favorites/MyApp    |
+/// | Windows | `{FOLDERID_Favorites}`/{app}             | C:\Users\Alice\Favorites\MyApp          |
+///
+/// The order in which the application and favorites directories are nested may be changed with
+/// [`set_nesting_scheme`](fn.set_nesting_scheme.html).
+///
+/// Before version 0.2.0 this was `{data_local}/{app}/favorites` on Linux, and that directory is
+/// still returned while it exists and the new one does not. An application may keep it with
+/// `NestingScheme::KindUnderApp`, or move its favorites from it once.
+///
+/// ```rust,no_run
+/// use xdirs::{favorites_dir_for, set_nesting_scheme, NestingScheme};
+///
+/// set_nesting_scheme("MyApp", NestingScheme::KindUnderApp);
+/// let legacy = favorites_dir_for("MyApp");
+/// set_nesting_scheme("MyApp", NestingScheme::AppUnderKind);
+/// let current = favorites_dir_for("MyApp");
+/// set_nesting_scheme("MyApp", NestingScheme::PlatformDefault);
+/// if let (Some(legacy), Some(current)) = (legacy, current) {
+///     if legacy.is_dir() && !current.exists() {
+///         std::fs::create_dir_all(current.parent().unwrap()).unwrap();
+///         std::fs::rename(legacy, current).unwrap();
+///     }
+/// }
+/// ```
+///
+/// See also [`favorites_dir`](fn.favorites_dir.html).
+///
+pub fn favorites_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Favorites, app)
+}
+
+///
+/// Returns the path to the user's log file directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | `$HOME`/Library/Logs                     | /Users/Alice/Library/Logs               |
+/// | Windows | `{FOLDERID_LocalAppData}`\Logs           | C:\Users\Alice\AppData\AppData\Local\Logs  |
+///
+/// See also [`log_dir_for`](fn.log_dir_for.html).
+///
+pub fn log_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Log)
+}
+
+///
+/// Returns the path to the user's log file directory for a specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}/logs | /home/alice/.local/share/MyApp/logs |
+/// | macOS   | `$HOME`/Library/Logs/{app}               | /Users/Alice/Library/Logs/Chrome        |
+/// | Windows | `{FOLDERID_LocalAppData}`\Logs\{app}     | C:\Users\Alice\AppData\AppData\Local\Logs\Chrome  |
+///
+/// See also [`log_dir`](fn.log_dir.html).
+///
+pub fn log_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Log, app)
+}
+
+///
+/// Returns the path to the system-wide log file directory, used by services and daemons rather
+/// than by an application run by the user.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /var/log                                 | /var/log                                |
+/// | macOS   | /Library/Logs                            | /Library/Logs                           |
+/// | Windows | `{FOLDERID_ProgramData}`                 | C:\ProgramData                          |
+///
+/// Whether the platform's native log service should be preferred to log files is returned by
+/// [`uses_system_journal`](fn.uses_system_journal.html).
+///
+/// See also [`system_log_dir_for`](fn.system_log_dir_for.html).
+///
+pub fn system_log_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::SystemLog)
+}
+
+///
+/// Returns the path to the system-wide log file directory for a specific application.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /var/log/{app}                           | /var/log/MyApp                          |
+/// | macOS   | /Library/Logs/{app}                      | /Library/Logs/MyApp                     |
+/// | Windows | `{FOLDERID_ProgramData}`\{app}\Logs      | C:\ProgramData\MyApp\Logs               |
+///
+/// See also [`system_log_dir`](fn.system_log_dir.html).
+///
+pub fn system_log_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::SystemLog, app)
+}
+
+///
+/// Returns the path to the user's preference file directory.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config    | /home/alice/.config                     |
+/// | macOS   | `$HOME`/Library/Preferences              | /Users/Alice/Library/Preferences        |
+/// | Windows | `{FOLDERID_RoamingAppData}`              | C:\Users\Alice\AppData\Roaming          |
+///
+/// Preferences are the settings a user may edit, and which should follow them between machines;
+/// state that the application records for itself, such as history and window positions, belongs
+/// in [`state_dir`](fn.state_dir.html).
+///
+/// See also [`preference_dir_for`](fn.preference_dir_for.html).
+///
+pub fn preference_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Preference)
+}
+
+///
+/// Returns the path to the user's preference file directory for a specific application.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                                    | Example                  |
-/// | ------- | -----------------------------------------| ------------------------ |
-/// | Linux   | -                                        | -                        |
-/// | macOS   | /Applications                            | /Applications            |
-/// | Windows | `{FOLDERID_ProgramFiles}`                | C:\Program Files         |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config/{app} | /home/alice/.config/MyApp            |
+/// | macOS   | `$HOME`/Library/Preferences/{app}        | /Users/Alice/Library/Preferences/MyApp  |
+/// | Windows | `{FOLDERID_RoamingAppData}`\{app}        | C:\Users\Alice\AppData\Roaming\MyApp    |
 ///
-/// See also [`application_shared_dir`](fn.application_shared_dir.html), and
-///   [`user_application_dir`](fn.user_application_dir.html).
+/// See also [`preference_dir`](fn.preference_dir.html), and for the application's own state
+/// [`state_dir_for`](fn.state_dir_for.html).
 ///
-pub fn application_dir() -> Option<PathBuf> {
-    sys::application_dir()
+pub fn preference_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Preference, app)
 }
 
 ///
-/// Returns the path to the system's application shared components directory.
+/// Returns the path to the user's state directory, in which applications keep the state they
+/// record for themselves, such as history, recently used files, and window positions, which
+/// should persist between runs but is neither a preference nor important enough to roam with
+/// the user.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                                    | Example                  |
-/// | ------- | -----------------------------------------| ------------------------ |
-/// | Linux   | -                                        | -                        |
-/// | macOS   | /Library/Frameworks                      | /Library/Frameworks      |
-/// | Windows | `{FOLDERID_ProgramFilesCommon}`          | C:\Program Files\Common Files |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_STATE_HOME` or `$HOME`/.local/state | /home/alice/.local/state               |
+/// | macOS   | `$HOME`/Library/Application Support      | /Users/Alice/Library/Application Support |
+/// | Windows | `{FOLDERID_LocalAppData}`                | C:\Users\Alice\AppData\Local            |
 ///
-/// See also [`application_dir`](fn.application_dir.html) and
-///   [`user_application_dir`](fn.user_application_dir.html).
+/// See also [`state_dir_for`](fn.state_dir_for.html), and for the settings a user may edit
+/// [`preference_dir`](fn.preference_dir.html).
 ///
-pub fn application_shared_dir() -> Option<PathBuf> {
-    sys::application_shared_dir()
+pub fn state_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::State)
 }
 
 ///
-/// Returns the path to the user's application directory.
+/// Returns the path to the user's state directory for a specific application.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                                    | Example                  |
-/// | ------- | -----------------------------------------| ------------------------ |
-/// | Linux   | -                                        | -                        |
-/// | macOS   | `$HOME`/Applications                     | /Users/Alice/Applications     |
-/// | Windows | `{FOLDERID_ProgramFilesCommon}`          | C:\Program Files\Common Files |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_STATE_HOME` or `$HOME`/.local/state/{app} | /home/alice/.local/state/MyApp   |
+/// | macOS   | `$HOME`/Library/Application Support/{app} | /Users/Alice/Library/Application Support/MyApp |
+/// | Windows | `{FOLDERID_LocalAppData}`\{app}          | C:\Users\Alice\AppData\Local\MyApp      |
 ///
-/// See also [`application_dir`](fn.application_dir.html) and
-///   [`application_shared_dir`](fn.application_shared_dir.html).
+/// See also [`state_dir`](fn.state_dir.html).
 ///
-pub fn user_application_dir() -> Option<PathBuf> {
-    sys::user_application_dir()
+pub fn state_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::State, app)
 }
 
-// ------------------------------------------------------------------------------------------------
-
 ///
-/// Returns the path to the application container directory, for a given system application name.
+/// Returns the path to the user's template directory.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                                    | Example                  |
-/// | ------- | -----------------------------------------| ------------------------ |
-/// | Linux   | -                                        | -                        |
-/// | macOS   | /Applications/{app}                      | /Applications/MyApp.app  |
-/// | Windows | -                                        | -                        |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_TEMPLATES_DIR`                     | /home/alice/Templates                   |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_Templates}`                   | C:\Users\Alice\AppData\Roaming\Microsoft\Windows\Templates |
 ///
-pub fn app_container_dir_for(app: &str) -> Option<PathBuf> {
-    sys::app_container_dir_for(app)
+/// See also [`template_dir_for`](fn.template_dir_for.html).
+///
+pub fn template_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Template)
 }
 
 ///
-/// Returns the path to the application container's executable directory, for a given system
-/// application name.
+/// Returns the path to the user's template directory for a specific application.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                                    | Example                  |
-/// | ------- | -----------------------------------------| ------------------------ |
-/// | Linux   | -                                        | -                        |
-/// | macOS   | /Applications/{app}/Contents/MacOS       | /Applications/MyApp.app/Contents/MacOS  |
-/// | Windows | -                                        | -                        |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_TEMPLATES_DIR`/{app}               | /home/alice/Templates                   |
+/// | macOS   | `$HOME`Library/Application Support/{app}/Templates | /Users/Alice/Library/Application Support/Chrome/Templates |
+/// | Windows | `{FOLDERID_Templates}`/{app}             | C:\Users\Alice\AppData\Roaming\Microsoft\Windows\Templates |
 ///
-pub fn app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
-    sys::app_container_executable_dir_for(app)
+/// See also [`template_dir`](fn.template_dir.html), and for the stock templates installed for all
+/// users [`system_template_dir_for`](fn.system_template_dir_for.html).
+///
+pub fn template_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Template, app)
 }
 
 ///
-/// Returns the path to the application container directory, for a given user application name.
+/// Returns the path to the template directory shared by all users; writing to this directory
+/// will usually require elevated privileges.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                                    | Example                  |
-/// | ------- | -----------------------------------------| ------------------------ |
-/// | Linux   | -                                        | -                        |
-/// | macOS   | `$HOME`/Applications/{app}               | /Applications/MyApp.app  |
-/// | Windows | -                                        | -                        |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/share/templates, or /usr/local/share/templates on the BSDs | /usr/share/templates |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_CommonTemplates}`             | C:\ProgramData\Microsoft\Windows\Templates |
 ///
-pub fn user_app_container_dir_for(app: &str) -> Option<PathBuf> {
-    sys::user_app_container_dir_for(app)
+/// See also [`system_template_dir_for`](fn.system_template_dir_for.html), and for the user's
+/// own templates [`template_dir`](fn.template_dir.html).
+///
+pub fn system_template_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::SystemTemplate)
 }
 
 ///
-/// Returns the path to the application container's executable directory, for a given user
-/// application name.
+/// Returns the path to the template directory shared by all users for a specific application,
+/// in which an installer places the application's stock templates.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                                    | Example                  |
-/// | ------- | -----------------------------------------| ------------------------ |
-/// | Linux   | -                                        | -                        |
-/// | macOS   | `$HOME`/Applications/{app}/Contents/MacOS | /Users/alica/Applications/MyApp.app/Contents/MacOS  |
-/// | Windows | -                                        | -                        |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/share/templates/{app}               | /usr/share/templates/MyApp              |
+/// | macOS   | /Library/Application Support/{app}/Templates | /Library/Application Support/MyApp/Templates |
+/// | Windows | `{FOLDERID_CommonTemplates}`\{app}       | C:\ProgramData\Microsoft\Windows\Templates\MyApp |
 ///
-pub fn user_app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
-    sys::user_app_container_executable_dir_for(app)
+/// See also [`template_dir_for`](fn.template_dir_for.html).
+///
+pub fn system_template_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::SystemTemplate, app)
 }
 
-// ------------------------------------------------------------------------------------------------
-
 ///
-/// Returns the path to the user's cache directory for a specific application.
+/// Returns the path to the user's saved games directory.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                               | Example                      |
-/// | ------- | ----------------------------------- | ---------------------------- |
-/// | Linux   | `$XDG_CACHE_HOME` or `$HOME`/.cache/{app} | /home/alice/.cache/MyApp           |
-/// | macOS   | `$HOME`/Library/Caches/{app}        | /Users/Alice/Library/Caches/MyApp  |
-/// | Windows | `{FOLDERID_LocalAppData}`/{app}     | C:\Users\Alice\AppData\Local\MyApp |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_SavedGames}`                  | C:\Users\Alice\Saved Games               |
 ///
-/// See also [`cache_dir`](fn.cache_dir.html).
+/// See also [`saved_games_dir_for`](fn.saved_games_dir_for.html).
 ///
-pub fn cache_dir_for(app: &str) -> Option<PathBuf> {
-    sys::cache_dir_for(app)
+pub fn saved_games_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::SavedGames)
 }
 
 ///
-/// Returns the path to the user's config directory for a specific application.
+/// Returns the path to the user's saved games directory for a specific application. Only Windows
+/// has a shared location for saved games; elsewhere they are kept with the application's data.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
-/// |Platform | Value                                 | Example                          |
-/// | ------- | ------------------------------------- | -------------------------------- |
-/// | Linux   | `$XDG_CONFIG_HOME` or `$HOME`/.config/{app} | /home/alice/.config/MyApp              |
-/// | macOS   | `$HOME`/Library/Application Support/{app}   | /Users/Alice/Library/Application Support/MyApp |
-/// | Windows | `{FOLDERID_RoamingAppData}`/{app}           | C:\Users\Alice\AppData\Roaming\MyApp   |
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}/saves | /home/alice/.local/share/MyGame/saves |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/Saves | /Users/Alice/Library/Application Support/MyGame/Saves |
+/// | Windows | `{FOLDERID_SavedGames}`/{app}            | C:\Users\Alice\Saved Games\MyGame         |
 ///
-/// See also [`config_dir`](fn.config_dir.html)`.
+/// See also [`saved_games_dir`](fn.saved_games_dir.html).
 ///
-pub fn config_dir_for(app: &str) -> Option<PathBuf> {
-    sys::config_dir_for(app)
+pub fn saved_games_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::SavedGames, app)
 }
 
 ///
-/// Returns the path to the user's data directory for a specific application.
+/// Returns the path to the user's icons directory, in which icon themes are installed.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app} | /home/alice/.local/share/MyApp                 |
-/// | macOS   | `$HOME`/Library/Application Support/{app}      | /Users/Alice/Library/Application Support/MyApp |
-/// | Windows | `{FOLDERID_RoamingAppData}`/{app}              | C:\Users\Alice\AppData\Roaming\MyApp           |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/icons | /home/alice/.local/share/icons    |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | -                                        | -                                       |
 ///
-/// See also [`data_dir`](fn.data_dir.html).
+/// See also [`icons_dir_for`](fn.icons_dir_for.html).
 ///
-pub fn data_dir_for(app: &str) -> Option<PathBuf> {
-    sys::data_dir_for(app)
+pub fn icons_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Icons)
 }
 
 ///
-/// Returns the path to the user's local data directory for a specific application.
+/// Returns the path to the user's icons directory for a specific application. On Linux this is
+/// the user's icons directory itself, in which an application installs its icons beneath the
+/// theme they belong to, such as `hicolor/48x48/apps/{app}.png`, and so is not specific to the
+/// application; elsewhere icons are kept with the application's data.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app} | /home/alice/.local/share/MyApp    |
-/// | macOS   | `$HOME`/Library/Application Support/{app}      | /Users/Alice/Library/Application Support/MyApp |
-/// | Windows | `{FOLDERID_LocalAppData}`/{app}                | C:\Users\Alice\AppData\Local\MyApp |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/icons | /home/alice/.local/share/icons    |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/Icons | /Users/Alice/Library/Application Support/MyApp/Icons |
+/// | Windows | `{FOLDERID_RoamingAppData}`\{app}\Icons  | C:\Users\Alice\AppData\Roaming\MyApp\Icons |
 ///
-/// See also [`data_local_dir`](fn.data_local_dir.html).
+/// See also [`icons_dir`](fn.icons_dir.html).
 ///
-pub fn data_local_dir_for(app: &str) -> Option<PathBuf> {
-    sys::data_local_dir_for(app)
+pub fn icons_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Icons, app)
 }
 
 ///
-/// Returns the path to the user's favorites directory.
+/// Returns the path to the user's themes directory.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share | /home/alice/.local/share/favorites      |
-/// | macOS   | `$HOME`/Library/Favorites                | /Users/Alice/Library/Favorites          |
-/// | Windows | `{FOLDERID_Favorites}`                   | C:\Users\Alice\Favorites                |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/themes | /home/alice/.local/share/themes  |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_LocalAppData}`\Microsoft\Windows\Themes | C:\Users\Alice\AppData\Local\Microsoft\Windows\Themes |
 ///
-/// See also [`favorites_dir_for`](fn.favorites_dir_for.html).
+/// See also [`themes_dir_for`](fn.themes_dir_for.html).
 ///
-pub fn favorites_dir() -> Option<PathBuf> {
-    sys::favorites_dir()
+pub fn themes_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Themes)
 }
 
 ///
-/// Returns the path to the user's favorites directory for a specific application.
+/// Returns the path to the user's themes directory for a specific application. On Linux this is
+/// the directory of a theme named for the application; elsewhere themes are kept with the
+/// application's data.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app} | /home/alice/.local/share/favorites/MyApp    |
-/// | macOS   | `$HOME`/Library/Favorites/{app}          | /Users/Alice/Library/Favorites/MyApp    |
-/// | Windows | `{FOLDERID_Favorites}`/{app}             | C:\Users\Alice\Favorites\MyApp          |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/themes/{app} | /home/alice/.local/share/themes/MyApp |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/Themes | /Users/Alice/Library/Application Support/MyApp/Themes |
+/// | Windows | `{FOLDERID_RoamingAppData}`\{app}\Themes | C:\Users\Alice\AppData\Roaming\MyApp\Themes |
 ///
-/// See also [`favorites_dir`](fn.favorites_dir.html).
+/// See also [`themes_dir`](fn.themes_dir.html).
 ///
-pub fn favorites_dir_for(app: &str) -> Option<PathBuf> {
-    sys::favorites_dir_for(app)
+pub fn themes_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Themes, app)
 }
 
+// ------------------------------------------------------------------------------------------------
+
 ///
-/// Returns the path to the user's log file directory.
+/// Returns the path to the directory an application's crash handler should write minidumps to.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | -                                        | -                                       |
-/// | macOS   | `$HOME`/Library/Logs                     | /Users/Alice/Library/Logs               |
-/// | Windows | `{FOLDERID_LocalAppData}`\Logs           | C:\Users\Alice\AppData\AppData\Local\Logs  |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}/crashes | /home/alice/.local/share/MyApp/crashes |
+/// | macOS   | `$HOME`/Library/Application Support/{app}/Crashes | /Users/Alice/Library/Application Support/MyApp/Crashes |
+/// | Windows | `{FOLDERID_LocalAppData}`/{app}/CrashDumps | C:\Users\Alice\AppData\Local\MyApp\CrashDumps |
+///
+/// This function allocates, and so should not be called from within a crash handler; see the
+/// [`signal_safe`](signal_safe/index.html) module for a way to resolve this path at startup.
+///
+pub fn minidump_path_for(app: &str) -> Option<PathBuf> {
+    override_root::apply(Platform::current().minidump_path_for(
+        &PlatformProvider,
+        app,
+        &naming::naming(app),
+    ))
+}
+
 ///
-/// See also [`log_dir_for`](fn.log_dir_for.html).
+/// Returns the path to the directory in which the user's crash dumps are written by default.
 ///
-pub fn log_dir() -> Option<PathBuf> {
-    sys::log_dir()
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_STATE_HOME` or `$HOME`/.local/state | /home/alice/.local/state               |
+/// | macOS   | `$HOME`/Library/Logs/DiagnosticReports   | /Users/Alice/Library/Logs/DiagnosticReports |
+/// | Windows | `{FOLDERID_LocalAppData}`\CrashDumps     | C:\Users\Alice\AppData\Local\CrashDumps |
+///
+/// See also [`crash_dumps_dir_for`](fn.crash_dumps_dir_for.html).
+///
+pub fn crash_dumps_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::CrashDumps)
 }
 
 ///
-/// Returns the path to the user's log file directory for a specific application.
+/// Returns the path to the directory in which a crash handler should write the crash dumps of
+/// a specific application. On macOS and Windows this is the directory shared by all
+/// applications, in which the system's own crash reporter writes, with each dump named for the
+/// process; on Linux each application has its own directory.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}/logs | /home/alice/.local/share/MyApp/logs |
-/// | macOS   | `$HOME`/Library/Logs/{app}               | /Users/Alice/Library/Logs/Chrome        |
-/// | Windows | `{FOLDERID_LocalAppData}`\Logs\{app}     | C:\Users\Alice\AppData\AppData\Local\Logs\Chrome  |
+/// | Linux   | `$XDG_STATE_HOME` or `$HOME`/.local/state/{app}/crashes | /home/alice/.local/state/MyApp/crashes |
+/// | macOS   | `$HOME`/Library/Logs/DiagnosticReports   | /Users/Alice/Library/Logs/DiagnosticReports |
+/// | Windows | `{FOLDERID_LocalAppData}`\CrashDumps     | C:\Users\Alice\AppData\Local\CrashDumps |
 ///
-/// See also [`log_dir`](fn.log_dir.html).
+/// The Windows value is the default `DumpFolder` of Windows Error Reporting's local dumps.
 ///
-pub fn log_dir_for(app: &str) -> Option<PathBuf> {
-    sys::log_dir_for(app)
+/// See also [`crash_dumps_dir`](fn.crash_dumps_dir.html), and
+/// [`minidump_path_for`](fn.minidump_path_for.html) for minidumps.
+///
+pub fn crash_dumps_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::CrashDumps, app)
 }
 
 ///
-/// Returns the path to the user's preference file directory.
+/// Returns the path to the platform's directory for temporary files. This is the value of
+/// `std::env::temp_dir`, except on Android, where that directory is not writable by
+/// applications, and in a browser, where there is none.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.config      | /home/alice/.config                     |
-/// | macOS   | `$HOME`/Library/Preferences              | /Users/Alice/Library/Preferences        |
-/// | Windows | `{FOLDERID_RoamingAppData}`/{app}        | C:\Users\Alice\AppData\Roaming\MyApp    |
-///
-/// See also [`preference_dir_for`](fn.preference_dir_for.html).
+/// | Linux   | `$TMPDIR`, or /tmp                       | /tmp                                    |
+/// | macOS   | `$TMPDIR`, the `DARWIN_USER_TEMP_DIR`    | /var/folders/zz/zyxvpxvq6csfxvn_n0000000000000/T |
+/// | Windows | `%TEMP%`                                 | C:\Users\Alice\AppData\Local\Temp        |
 ///
-pub fn preference_dir() -> Option<PathBuf> {
-    sys::preference_dir()
+pub fn temp_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Temp)
 }
 
 ///
-/// Returns the path to the user's preference file directory for a specific application.
+/// Returns the path to a directory for the temporary files of a specific application. Where the
+/// temporary directory is shared between users, as /tmp is on Linux, the name of the directory
+/// includes the user's id so that the directories of different users do not collide.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.config      | /home/alice/.config                     |
-/// | macOS   | `$HOME`/Library/Preferences/{app}        | /Users/Alice/Library/Preferences/Chrome |
-/// | Windows | `{FOLDERID_RoamingAppData}`/{app}        | C:\Users\Alice\AppData\Roaming\MyApp    |
+/// | Linux   | `$TMPDIR`, or /tmp, /{app}-{uid}         | /tmp/MyApp-1000                         |
+/// | macOS   | `$TMPDIR`/{app}                          | /var/folders/zz/zyxvpxvq6csfxvn_n0000000000000/T/MyApp |
+/// | Windows | `%TEMP%`\{app}                           | C:\Users\Alice\AppData\Local\Temp\MyApp  |
 ///
-/// See also [`preference_dir`](fn.preference_dir.html).
+/// As the directory may be shared, it should be created with
+/// [`temp_dir_for_create`](fn.temp_dir_for_create.html), which ensures that it is accessible only
+/// by the current user.
 ///
-pub fn preference_dir_for(app: &str) -> Option<PathBuf> {
-    sys::preference_dir_for(app)
+pub fn temp_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Temp, app)
 }
 
 ///
-/// Returns the path to the user's template directory.
+/// Returns the path to the directory in which a specific application should create its Unix
+/// domain sockets, and any other files used to communicate with its running instances.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_TEMPLATES_DIR`                     | /home/alice/Templates                   |
+/// | Linux   | `$XDG_RUNTIME_DIR`/{app}, or as [`temp_dir_for`](fn.temp_dir_for.html) | /run/user/1000/MyApp |
+/// | macOS   | `$TMPDIR`/{app}                          | /var/folders/zz/zyxvpxvq6csfxvn_n0000000000000/T/MyApp |
+/// | Windows | `{FOLDERID_LocalAppData}`\{app}          | C:\Users\Alice\AppData\Local\MyApp        |
+///
+/// The path of a Unix domain socket is limited to a little over 100 bytes on most platforms, and
+/// so socket names within this directory should be kept short. On Windows, named pipes are more
+/// commonly used than sockets; see [`pipe_name_for`](fn.pipe_name_for.html).
+///
+pub fn socket_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Socket, app)
+}
+
+///
+/// Returns the name of the named pipe a specific application should use on Windows, or `None`
+/// on other platforms.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
 /// | macOS   | -                                        | -                                       |
-/// | Windows | `{FOLDERID_Templates}`                   | C:\Users\Alice\AppData\Roaming\Microsoft\Windows\Templates |
+/// | Windows | `\\.\pipe\{app}`                         | `\\.\pipe\MyApp`                        |
 ///
-/// See also [`template_dir_for`](fn.template_dir_for.html).
+/// The pipe namespace is shared by all users and sessions; an application that runs an instance
+/// per user should append the user's, or the session's, name.
 ///
-pub fn template_dir() -> Option<PathBuf> {
-    sys::template_dir()
+pub fn pipe_name_for(app: &str) -> Option<String> {
+    sys::pipe_name_for(app)
 }
 
 ///
-/// Returns the path to the user's template directory for a specific application.
+/// Returns the path to the directory in which a specific application should keep its lock
+/// files, for example those used to ensure only a single instance is running. On Linux this is
+/// the same directory as [`socket_dir_for`](fn.socket_dir_for.html), which is removed when the
+/// user logs out, and so never holds stale locks from a previous session.
 ///
 /// The returned value depends on the operating system and is either a `Some`, containing a value
 /// from the following table, or a `None`.
 ///
 /// |Platform | Value                                    | Example                                 |
 /// | ------- | ---------------------------------------- | --------------------------------------- |
-/// | Linux   | `$XDG_TEMPLATES_DIR`/{app}               | /home/alice/Templates                   |
-/// | macOS   | `$HOME`Library/Application Support/{app}/Templates | /Users/Alice/Library/Application Support/Chrome/Templates |
-/// | Windows | `{FOLDERID_Templates}`/{app}             | C:\Users\Alice\AppData\Roaming\Microsoft\Windows\Templates |
+/// | Linux   | `$XDG_RUNTIME_DIR`/{app}, or as [`temp_dir_for`](fn.temp_dir_for.html) | /run/user/1000/MyApp |
+/// | macOS   | `$HOME`/Library/Application Support/{app} | /Users/Alice/Library/Application Support/MyApp |
+/// | Windows | `{FOLDERID_LocalAppData}`\{app}          | C:\Users\Alice\AppData\Local\MyApp        |
+///
+pub fn lock_dir_for(app: &str) -> Option<PathBuf> {
+    resolve::dir_for(DirKind::Lock, app)
+}
+
 ///
-/// See also [`template_dir`](fn.template_dir.html).
+/// Returns the path to the file in which a specific application should record its process id;
+/// this is the file {app}.pid in [`lock_dir_for`](fn.lock_dir_for.html).
 ///
-pub fn template_dir_for(app: &str) -> Option<PathBuf> {
-    sys::template_dir_for(app)
+/// ```rust
+/// if let Some(pid_file) = xdirs::pid_file_for("MyApp") {
+///     assert!(pid_file.ends_with("MyApp.pid"));
+/// }
+/// ```
+///
+pub fn pid_file_for(app: &str) -> Option<PathBuf> {
+    lock_dir_for(app).map(|path| path.join(format!("{}.pid", sanitize_app_name(app))))
+}
+
+///
+/// Returns the path to the directory in which the system's crash reporter writes the reports of
+/// the user's processes.
+///
+/// The returned value depends on the operating system and is either a `Some`, containing a value
+/// from the following table, or a `None`.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | `$HOME`/Library/Logs/DiagnosticReports   | /Users/Alice/Library/Logs/DiagnosticReports |
+/// | Windows | -                                        | -                                       |
+///
+/// See also [`crash_reports_for`](fn.crash_reports_for.html).
+///
+pub fn crash_reports_dir() -> Option<PathBuf> {
+    override_root::apply(sys::crash_reports_dir())
+}
+
+///
+/// Returns the path to the directory in which the system's crash reporter writes the reports of
+/// system processes, and of processes run by other users.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | /Library/Logs/DiagnosticReports          | /Library/Logs/DiagnosticReports         |
+/// | Windows | -                                        | -                                       |
+///
+pub fn system_crash_reports_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_crash_reports_dir())
+}
+
+///
+/// Returns the paths, sorted, of the crash reports for the process named `app` found in
+/// [`crash_reports_dir`](fn.crash_reports_dir.html) and
+/// [`system_crash_reports_dir`](fn.system_crash_reports_dir.html). The crash reporter names each
+/// report after the process, as in `MyApp-2024-01-31-120000.ips` or
+/// `MyApp_2024-01-31-120000_host.crash`, and so `app` should be the executable's name rather than
+/// a display name. Directories that cannot be read are skipped, and on platforms without these
+/// directories the result is empty.
+///
+/// ```rust
+/// use xdirs::crash_reports_for;
+///
+/// for report in crash_reports_for("MyApp") {
+///     println!("found crash report {:?}", report);
+/// }
+/// ```
+///
+pub fn crash_reports_for(app: &str) -> Vec<PathBuf> {
+    let mut reports: Vec<PathBuf> = crash_reports_dir()
+        .into_iter()
+        .chain(system_crash_reports_dir())
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| is_crash_report_for(path, app))
+        .collect();
+    reports.sort();
+    reports
+}
+
+///
+/// Returns `true` if the platform provides a native log service that an application should
+/// prefer to writing its own log files, in which case a logging framework may choose to write to
+/// that service rather than to [`log_dir_for`](fn.log_dir_for.html) or
+/// [`system_log_dir_for`](fn.system_log_dir_for.html).
+///
+/// |Platform | Log Service                 | Detection                                        |
+/// | ------- | --------------------------- | ------------------------------------------------ |
+/// | Linux   | systemd-journald            | the journal socket, /run/systemd/journal/socket, exists |
+/// | macOS   | Unified Logging             | always                                           |
+/// | Windows | Event Log                   | always                                           |
+///
+pub fn uses_system_journal() -> bool {
+    sys::uses_system_journal()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the user's directories, `user` and `local`, followed by the application's directory
+/// within each of the shared directories `system`, without duplicates.
+///
+fn search_dirs_for(
+    user: Option<PathBuf>,
+    local: Option<PathBuf>,
+    system: Vec<PathBuf>,
+    app: &str,
+) -> Vec<PathBuf> {
+    let app = sanitize_app_name(app);
+    let system = system
+        .into_iter()
+        .filter_map(|path| override_root::apply(Some(path.join(app.as_ref()))));
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    for path in user.into_iter().chain(local).chain(system) {
+        if !dirs.contains(&path) {
+            dirs.push(path);
+        }
+    }
+    dirs
+}
+
+///
+/// Returns `true` if the file name of `path` is that of a crash report for the process `app`,
+/// the process name followed by `-` or `_` and a timestamp.
+///
+fn is_crash_report_for(path: &Path, app: &str) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_prefix(app))
+        .map(|rest| {
+            (rest.starts_with('-') || rest.starts_with('_'))
+                && rest[1..].starts_with(|c: char| c.is_ascii_digit())
+        })
+        .unwrap_or_default()
 }
 
 // ------------------------------------------------------------------------------------------------
 // System-Specific Modules
 // ------------------------------------------------------------------------------------------------
 
-#[cfg(any(target_os = "macos", target_os = "ios"))]
+// each platform uses only those stubs for the directories it does not support.
+#[allow(dead_code)]
+mod unsupported;
+
+#[cfg(target_os = "macos")]
 #[path = "macos.rs"]
 mod sys;
 
+#[cfg(target_os = "ios")]
+#[path = "ios.rs"]
+mod sys;
+
+#[cfg(target_arch = "wasm32")]
+#[path = "wasm.rs"]
+mod sys;
+
+#[cfg(target_os = "android")]
+#[path = "android.rs"]
+mod sys;
+
+#[cfg(target_os = "haiku")]
+#[path = "haiku.rs"]
+mod sys;
+
 #[cfg(not(any(
     target_arch = "wasm32",
     windows,
+    target_os = "android",
+    target_os = "haiku",
     target_os = "macos",
     target_os = "ios"
 )))]
@@ -424,3 +2804,45 @@ mod sys;
 #[cfg(windows)]
 #[path = "windows.rs"]
 mod sys;
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_crash_report_for() {
+        assert!(is_crash_report_for(
+            Path::new("/r/Chrome_2023-01-01-120000_host.crash"),
+            "Chrome"
+        ));
+        assert!(is_crash_report_for(
+            Path::new("/r/Chrome-2023-01-01-120000.ips"),
+            "Chrome"
+        ));
+        assert!(!is_crash_report_for(
+            Path::new("/r/Chrome Helper-2023-01-01-120000.ips"),
+            "Chrome"
+        ));
+        assert!(!is_crash_report_for(Path::new("/r/Chrome"), "Chrome"));
+    }
+
+    #[test]
+    fn test_generic_dirs_follow_override_root() {
+        let root = std::env::temp_dir().join(format!("xdirs-generic-{}", std::process::id()));
+        let _guard = override_root(&root);
+        for dir in [cache_dir(), config_dir(), data_dir(), data_local_dir()]
+            .iter()
+            .flatten()
+        {
+            assert!(dir.starts_with(&root));
+        }
+        assert_eq!(
+            cache_dir_for("Chrome").map(|p| p.starts_with(cache_dir().unwrap())),
+            cache_dir().map(|_| true)
+        );
+    }
+}
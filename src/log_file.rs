@@ -0,0 +1,219 @@
+/*!
+Provides the conventional paths of an application's log files, within its log directory, so that
+applications and logging frameworks share a single naming convention.
+*/
+
+use crate::naming::file_stem;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "create")]
+use std::io;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Identifies how a log file is named, where `{name}` is the application name in lower-case with
+/// words separated by dashes, and times are in UTC.
+///
+/// |Pattern     | Name                              | Example                             |
+/// | ---------- | --------------------------------- | ----------------------------------- |
+/// | `Single`   | {name}.log                        | my-app.log                          |
+/// | `Daily`    | {name}.{date}.log                 | my-app.2024-05-01.log               |
+/// | `Timestamp`| {name}.{date}T{time}.log          | my-app.2024-05-01T13-45-10.log      |
+/// | `Sequence` | {name}.{n}.log                    | my-app.3.log                        |
+///
+/// For `Sequence`, `n` is one more than the highest number of the existing log files, starting
+/// at 1.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LogFilePattern {
+    /// A single file, which is appended to or rotated externally.
+    Single,
+    /// A file for each day, as written by daily rolling appenders.
+    #[default]
+    Daily,
+    /// A file for each run of the application, named for the time it started.
+    Timestamp,
+    /// A file for each run of the application, numbered in sequence.
+    Sequence,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path to the current log file of a specific application, in the directory returned
+/// by [`log_dir_for`](fn.log_dir_for.html), named by the default pattern,
+/// [`LogFilePattern::Daily`](enum.LogFilePattern.html#variant.Daily).
+///
+/// ```rust
+/// use xdirs::log_file_for;
+///
+/// if let Some(file) = log_file_for("My App") {
+///     assert!(file.to_string_lossy().ends_with(".log"));
+/// }
+/// ```
+///
+pub fn log_file_for(app: &str) -> Option<PathBuf> {
+    log_file_for_with(app, LogFilePattern::default())
+}
+
+///
+/// Returns the path to the current log file of a specific application, in the directory returned
+/// by [`log_dir_for`](fn.log_dir_for.html), named by `pattern`.
+///
+pub fn log_file_for_with(app: &str, pattern: LogFilePattern) -> Option<PathBuf> {
+    crate::log_dir_for(app).map(|path| log_file_in(&path, app, pattern))
+}
+
+///
+/// Returns the path to the current log file of a specific application, named by `pattern`,
+/// creating the log directory if necessary, as by
+/// [`log_dir_for_create`](fn.log_dir_for_create.html). The file itself is not created.
+///
+#[cfg(feature = "create")]
+pub fn log_file_for_create(app: &str, pattern: LogFilePattern) -> io::Result<PathBuf> {
+    crate::log_dir_for_create(app).map(|path| log_file_in(&path, app, pattern))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const EXT_LOG: &str = "log";
+const SECONDS_PER_DAY: u64 = 86_400;
+
+fn log_file_in(dir: &Path, app: &str, pattern: LogFilePattern) -> PathBuf {
+    let stem = file_stem(app);
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    dir.join(file_name(&stem, pattern, seconds, || {
+        next_sequence(dir, &stem)
+    }))
+}
+
+fn file_name<F>(stem: &str, pattern: LogFilePattern, seconds: u64, sequence: F) -> String
+where
+    F: FnOnce() -> u64,
+{
+    match pattern {
+        LogFilePattern::Single => format!("{}.{}", stem, EXT_LOG),
+        LogFilePattern::Daily => format!("{}.{}.{}", stem, date(seconds), EXT_LOG),
+        LogFilePattern::Timestamp => {
+            let time = seconds % SECONDS_PER_DAY;
+            format!(
+                "{}.{}T{:02}-{:02}-{:02}.{}",
+                stem,
+                date(seconds),
+                time / 3600,
+                time % 3600 / 60,
+                time % 60,
+                EXT_LOG
+            )
+        }
+        LogFilePattern::Sequence => format!("{}.{}.{}", stem, sequence(), EXT_LOG),
+    }
+}
+
+///
+/// Returns the date, as `YYYY-MM-DD`, of the time `seconds` after the Unix epoch, by the
+/// proleptic Gregorian calendar.
+///
+fn date(seconds: u64) -> String {
+    let days = (seconds / SECONDS_PER_DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+///
+/// Returns one more than the highest sequence number of the log files of `stem` in `dir`.
+///
+fn next_sequence(dir: &Path, stem: &str) -> u64 {
+    let prefix = format!("{}.", stem);
+    let suffix = format!(".{}", EXT_LOG);
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()
+                        .and_then(|name| name.strip_prefix(&prefix))
+                        .and_then(|name| name.strip_suffix(&suffix))
+                        .and_then(|number| number.parse::<u64>().ok())
+                })
+                .max()
+                .unwrap_or_default()
+        })
+        .unwrap_or_default()
+        + 1
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(951_782_400), "2000-02-29");
+        assert_eq!(date(1_714_571_110), "2024-05-01");
+    }
+
+    #[test]
+    fn test_file_name() {
+        let name = |pattern| file_name("my-app", pattern, 1_714_571_110, || 3);
+        assert_eq!(name(LogFilePattern::Single), "my-app.log");
+        assert_eq!(name(LogFilePattern::Daily), "my-app.2024-05-01.log");
+        assert_eq!(
+            name(LogFilePattern::Timestamp),
+            "my-app.2024-05-01T13-45-10.log"
+        );
+        assert_eq!(name(LogFilePattern::Sequence), "my-app.3.log");
+    }
+
+    #[test]
+    fn test_next_sequence() {
+        let dir = std::env::temp_dir().join(format!("xdirs-log-file-{}", std::process::id()));
+        assert_eq!(next_sequence(&dir, "chrome"), 1);
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in &[
+            "chrome.1.log",
+            "chrome.7.log",
+            "chrome.x.log",
+            "other.9.log",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        assert_eq!(next_sequence(&dir, "chrome"), 8);
+        assert_eq!(
+            log_file_in(&dir, "Chrome", LogFilePattern::Sequence),
+            dir.join("chrome.8.log")
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
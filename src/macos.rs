@@ -1,5 +1,13 @@
-use dirs_next::{cache_dir, config_dir, data_dir, data_local_dir, home_dir};
-use std::path::PathBuf;
+pub use crate::environment::home_dir;
+pub use crate::unsupported::{
+    desktop_entry_dir, executable_dir, extra_plugin_dirs_for, fallback_source, flatpak_id,
+    glib_schemas_dir, icons_dir, installed_container_dir_for, keyfile_backend_dir,
+    known_folder_dir, mime_dir, package_dir_for, package_family_name, pipe_name_for,
+    registered_install_dir_for, runtime_dir, sandboxed_app_dir, saved_games_dir, snap_name,
+    snap_user_common_dir, system_desktop_entry_dir, system_glib_schemas_dir, system_locale_dirs,
+    system_mime_dir, system_template_dir, template_dir, themes_dir, user_id,
+};
+use std::path::{Path, PathBuf};
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -14,75 +22,377 @@ pub fn application_shared_dir() -> Option<PathBuf> {
 }
 
 pub fn user_application_dir() -> Option<PathBuf> {
-    home_dir().map(|path| path.join("Applications"))
+    user_home_dir().map(|path| path.join("Applications"))
+}
+
+pub fn user_application_shared_dir() -> Option<PathBuf> {
+    user_home_dir().map(|path| path.join("Library/Frameworks"))
 }
 
 // ------------------------------------------------------------------------------------------------
 
-pub fn app_container_dir_for(app: &str) -> Option<PathBuf> {
-    application_dir().map(|path| path.join(&format!("{}.app", app)))
+pub fn sandbox_container_id() -> Option<String> {
+    crate::environment::non_empty_var(E_SANDBOX_CONTAINER_ID)
+}
+
+pub fn sandbox_container_dir_for(bundle_id: &str) -> Option<PathBuf> {
+    user_home_dir().map(|path| {
+        path.join(D_CONTAINERS)
+            .join(crate::sanitize_app_name(bundle_id).as_ref())
+            .join(D_CONTAINER_DATA)
+    })
+}
+
+pub fn application_scripts_dir_for(bundle_id: &str) -> Option<PathBuf> {
+    user_home_dir().map(|path| {
+        path.join(D_APPLICATION_SCRIPTS)
+            .join(crate::sanitize_app_name(bundle_id).as_ref())
+    })
+}
+
+pub fn launch_agents_dir() -> Option<PathBuf> {
+    user_home_dir().map(|path| path.join(D_LAUNCH_AGENTS))
+}
+
+pub fn system_launch_agents_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/").join(D_LAUNCH_AGENTS))
+}
+
+pub fn launch_daemons_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/").join(D_LAUNCH_DAEMONS))
+}
+
+pub fn autostart_dir() -> Option<PathBuf> {
+    launch_agents_dir()
+}
+
+pub fn system_autostart_dir() -> Option<PathBuf> {
+    system_launch_agents_dir()
+}
+
+pub fn font_dir() -> Option<PathBuf> {
+    user_home_dir().map(|path| path.join(D_FONTS))
+}
+
+pub fn system_font_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/").join(D_FONTS))
+}
+
+pub fn man_dir() -> Option<PathBuf> {
+    crate::xdg::resolve(crate::BaseKind::DataHome)
+        .value
+        .map(|path| path.join(D_MAN))
+}
+
+pub fn system_man_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/usr/local/share").join(D_MAN))
+}
+
+pub fn wallpaper_dir() -> Option<PathBuf> {
+    user_home_dir().map(|path| path.join(D_DESKTOP_PICTURES))
+}
+
+pub fn system_wallpaper_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_LIBRARY).join(D_SYSTEM_DESKTOP_PICTURES))
+}
+
+pub fn public_data_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SHARED))
+}
+
+pub fn shared_documents_dir() -> Option<PathBuf> {
+    public_data_dir()
+}
+
+pub fn crash_reports_dir() -> Option<PathBuf> {
+    user_home_dir().map(|path| path.join(D_DIAGNOSTIC_REPORTS))
+}
+
+pub fn system_crash_reports_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/").join(D_DIAGNOSTIC_REPORTS))
+}
+
+pub fn crash_dumps_dir() -> Option<PathBuf> {
+    crash_reports_dir()
+}
+
+pub fn temp_dir() -> Option<PathBuf> {
+    Some(std::env::temp_dir())
+}
+
+pub fn trash_dir() -> Option<PathBuf> {
+    user_home_dir().map(|path| path.join(D_TRASH))
 }
 
-pub fn app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
-    app_container_dir_for(app).map(|a| a.join("Contents/MacOS"))
+pub fn recent_dir() -> Option<PathBuf> {
+    user_home_dir().map(|path| path.join(D_SHARED_FILE_LIST))
 }
 
-pub fn user_app_container_dir_for(app: &str) -> Option<PathBuf> {
-    user_application_dir().map(|path| path.join(&format!("{}.app", app)))
+pub fn system_config_dirs() -> Vec<PathBuf> {
+    system_data_dirs()
 }
 
-pub fn user_app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
-    user_app_container_dir_for(app).map(|a| a.join("Contents/MacOS"))
+pub fn system_data_dirs() -> Vec<PathBuf> {
+    vec![PathBuf::from(D_SYSTEM_APPLICATION_SUPPORT)]
+}
+
+pub fn system_domain_dirs() -> Vec<PathBuf> {
+    vec![
+        PathBuf::from(D_SYSTEM_LIBRARY),
+        PathBuf::from(D_SYSTEM_DOMAIN_LIBRARY),
+    ]
+}
+
+pub fn service_dirs_for(app: &str) -> crate::ServiceDirs {
+    let app = crate::sanitize_app_name(app);
+    let dir = |parent: &str| Some(Path::new(parent).join(app.as_ref()));
+    crate::ServiceDirs {
+        config: dir(D_SYSTEM_APPLICATION_SUPPORT),
+        data: dir(D_SYSTEM_APPLICATION_SUPPORT),
+        cache: dir(D_SYSTEM_CACHES),
+        runtime: dir(D_SERVICE_RUNTIME),
+        log: dir(D_SYSTEM_LOGS),
+    }
+}
+
+pub fn icloud_drive_dir() -> Option<PathBuf> {
+    mobile_documents_dir().map(|path| path.join(D_CLOUD_DOCS))
+}
+
+pub fn icloud_container_dir_for(container_id: &str) -> Option<PathBuf> {
+    mobile_documents_dir()
+        .map(|path| path.join(crate::sanitize_app_name(&container_id.replace('.', "~")).as_ref()))
+}
+
+pub fn host_home_dir() -> Option<PathBuf> {
+    user_home_dir()
+}
+
+pub fn group_container_dir_for(group_id: &str) -> Option<PathBuf> {
+    user_home_dir().map(|path| {
+        path.join(D_GROUP_CONTAINERS)
+            .join(crate::sanitize_app_name(group_id).as_ref())
+    })
 }
 
 // ------------------------------------------------------------------------------------------------
 
-pub fn cache_dir_for(app: &str) -> Option<PathBuf> {
-    cache_dir().map(|path| path.join(app))
+pub fn cache_dir() -> Option<PathBuf> {
+    search_dir(SearchDir::Caches)
 }
 
-pub fn config_dir_for(app: &str) -> Option<PathBuf> {
-    config_dir().map(|path| path.join(app))
+pub fn config_dir() -> Option<PathBuf> {
+    search_dir(SearchDir::ApplicationSupport)
 }
 
-pub fn data_dir_for(app: &str) -> Option<PathBuf> {
-    data_dir().map(|path| path.join(app))
+pub fn config_local_dir() -> Option<PathBuf> {
+    config_dir()
 }
 
-pub fn data_local_dir_for(app: &str) -> Option<PathBuf> {
-    data_local_dir().map(|path| path.join(app))
+pub fn data_dir() -> Option<PathBuf> {
+    search_dir(SearchDir::ApplicationSupport)
 }
 
-pub fn favorites_dir() -> Option<PathBuf> {
-    home_dir().map(|h| h.join("Library/Favorites"))
+pub fn data_local_dir() -> Option<PathBuf> {
+    data_dir()
+}
+
+pub fn desktop_dir() -> Option<PathBuf> {
+    search_dir(SearchDir::Desktop)
 }
 
-pub fn favorites_dir_for(app: &str) -> Option<PathBuf> {
-    favorites_dir().map(|path| path.join(app))
+pub fn document_dir() -> Option<PathBuf> {
+    search_dir(SearchDir::Documents)
+}
+
+pub fn download_dir() -> Option<PathBuf> {
+    search_dir(SearchDir::Downloads)
+}
+
+pub fn public_dir() -> Option<PathBuf> {
+    search_dir(SearchDir::Public)
+}
+
+pub fn favorites_dir() -> Option<PathBuf> {
+    search_dir(SearchDir::Library).map(|path| path.join(D_FAVORITES))
 }
 
 pub fn log_dir() -> Option<PathBuf> {
-    home_dir().map(|h| h.join("Library/Logs"))
+    search_dir(SearchDir::Library).map(|path| path.join(D_LOGS))
 }
 
-pub fn log_dir_for(app: &str) -> Option<PathBuf> {
-    log_dir().map(|path| path.join(&app))
+pub fn system_log_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/Library/Logs"))
 }
 
 pub fn preference_dir() -> Option<PathBuf> {
-    home_dir().map(|h| h.join("Library/Preferences"))
+    search_dir(SearchDir::Library).map(|path| path.join(D_PREFERENCES))
+}
+
+pub fn state_dir() -> Option<PathBuf> {
+    data_local_dir()
+}
+
+pub fn is_remote(_path: &Path) -> bool {
+    false
 }
 
-pub fn preference_dir_for(app: &str) -> Option<PathBuf> {
-    preference_dir().map(|path| path.join(app))
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The user's standard directories, as searched for by `sysdir`.
+///
+#[derive(Clone, Copy, Debug)]
+enum SearchDir {
+    Library,
+    Caches,
+    ApplicationSupport,
+    Desktop,
+    Documents,
+    Downloads,
+    Public,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl SearchDir {
+    ///
+    /// Returns the conventional location of this directory, relative to the home directory.
+    ///
+    fn relative_path(&self) -> &'static str {
+        match self {
+            SearchDir::Library => "Library",
+            SearchDir::Caches => "Library/Caches",
+            SearchDir::ApplicationSupport => "Library/Application Support",
+            SearchDir::Desktop => "Desktop",
+            SearchDir::Documents => "Documents",
+            SearchDir::Downloads => "Downloads",
+            SearchDir::Public => "Public",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_FAVORITES: &str = "Favorites";
+const D_LOGS: &str = "Logs";
+const D_PREFERENCES: &str = "Preferences";
+const D_SYSTEM_CACHES: &str = "/Library/Caches";
+const D_SYSTEM_LOGS: &str = "/Library/Logs";
+const D_SERVICE_RUNTIME: &str = "/var/run";
+const D_SYSTEM_APPLICATION_SUPPORT: &str = "/Library/Application Support";
+const D_SYSTEM_LIBRARY: &str = "/Library";
+const D_SYSTEM_DOMAIN_LIBRARY: &str = "/System/Library";
+const D_SHARED: &str = "/Users/Shared";
+const D_SHARED_FILE_LIST: &str = "Library/Application Support/com.apple.sharedfilelist";
+const E_SANDBOX_CONTAINER_ID: &str = "APP_SANDBOX_CONTAINER_ID";
+const D_CONTAINERS: &str = "Library/Containers";
+const D_CONTAINER_DATA: &str = "Data";
+const D_GROUP_CONTAINERS: &str = "Library/Group Containers";
+const D_APPLICATION_SCRIPTS: &str = "Library/Application Scripts";
+const D_LAUNCH_AGENTS: &str = "Library/LaunchAgents";
+const D_LAUNCH_DAEMONS: &str = "Library/LaunchDaemons";
+const D_DIAGNOSTIC_REPORTS: &str = "Library/Logs/DiagnosticReports";
+const D_FONTS: &str = "Library/Fonts";
+const D_MAN: &str = "man";
+const D_DESKTOP_PICTURES: &str = "Library/Desktop Pictures";
+const D_SYSTEM_DESKTOP_PICTURES: &str = "Desktop Pictures";
+const D_TRASH: &str = ".Trash";
+const D_MOBILE_DOCUMENTS: &str = "Library/Mobile Documents";
+const D_CLOUD_DOCS: &str = "com~apple~CloudDocs";
+
+///
+/// Returns the user's directory `dir`, as located by `sysdir` with the `sysdir` feature, or
+/// otherwise by its conventional location within the home directory.
+///
+fn search_dir(dir: SearchDir) -> Option<PathBuf> {
+    sysdir_user_dir(dir).or_else(|| home_dir().map(|home| home.join(dir.relative_path())))
 }
 
-pub fn template_dir() -> Option<PathBuf> {
+///
+/// Returns the first path in the user domain for `dir` from `sysdir`, which abbreviates the home
+/// directory as `~`; this is expanded from the same home directory as the rest of this module.
+///
+#[cfg(feature = "sysdir")]
+fn sysdir_user_dir(dir: SearchDir) -> Option<PathBuf> {
+    use libc::sysdir_search_path_directory_t::*;
+    use libc::sysdir_search_path_domain_mask_t::SYSDIR_DOMAIN_MASK_USER;
+    use libc::{sysdir_get_next_search_path_enumeration, sysdir_start_search_path_enumeration};
+    use std::ffi::CStr;
+    use std::os::raw::c_char;
+
+    let directory = match dir {
+        SearchDir::Library => SYSDIR_DIRECTORY_LIBRARY,
+        SearchDir::Caches => SYSDIR_DIRECTORY_CACHES,
+        SearchDir::ApplicationSupport => SYSDIR_DIRECTORY_APPLICATION_SUPPORT,
+        SearchDir::Desktop => SYSDIR_DIRECTORY_DESKTOP,
+        SearchDir::Documents => SYSDIR_DIRECTORY_DOCUMENT,
+        SearchDir::Downloads => SYSDIR_DIRECTORY_DOWNLOADS,
+        SearchDir::Public => SYSDIR_DIRECTORY_SHARED_PUBLIC,
+    };
+    let mut buffer = [0 as c_char; SYSDIR_PATH_MAX];
+    let path = unsafe {
+        let state = sysdir_start_search_path_enumeration(directory, SYSDIR_DOMAIN_MASK_USER);
+        if sysdir_get_next_search_path_enumeration(state, buffer.as_mut_ptr()) == 0 {
+            return None;
+        }
+        CStr::from_ptr(buffer.as_ptr()).to_str().ok()?.to_string()
+    };
+    match path.strip_prefix('~') {
+        Some(relative) => home_dir().map(|home| home.join(relative.trim_start_matches('/'))),
+        None => Some(PathBuf::from(path)),
+    }
+}
+
+#[cfg(feature = "sysdir")]
+const SYSDIR_PATH_MAX: usize = 1024;
+
+#[cfg(not(feature = "sysdir"))]
+fn sysdir_user_dir(_: SearchDir) -> Option<PathBuf> {
     None
 }
 
-pub fn template_dir_for(app: &str) -> Option<PathBuf> {
-    data_dir_for(app).map(|d| d.join("Templates"))
+///
+/// Returns the user's own home directory; within the app sandbox `$HOME` is the container's
+/// data directory, from which the user's home directory is recovered.
+///
+fn user_home_dir() -> Option<PathBuf> {
+    home_dir().map(|home| match sandbox_container_id() {
+        Some(id) => unsandboxed(&home, &id),
+        None => home,
+    })
+}
+
+///
+/// Returns the directory holding the local copies of iCloud documents, if iCloud is configured.
+///
+fn mobile_documents_dir() -> Option<PathBuf> {
+    user_home_dir()
+        .map(|path| path.join(D_MOBILE_DOCUMENTS))
+        .filter(|path| path.is_dir())
+}
+
+fn unsandboxed(home: &Path, container_id: &str) -> PathBuf {
+    let container = Path::new(D_CONTAINERS)
+        .join(container_id)
+        .join(D_CONTAINER_DATA);
+    match home
+        .to_string_lossy()
+        .strip_suffix(&*container.to_string_lossy())
+    {
+        Some(user_home) => PathBuf::from(user_home.trim_end_matches('/')),
+        None => home.to_path_buf(),
+    }
+}
+
+pub fn uses_system_journal() -> bool {
+    true
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -97,11 +407,7 @@ mod tests {
     fn test_user_dir(dir: PathBuf, suffix: &str) {
         assert_eq!(
             dir.to_string_lossy().to_string(),
-            format!(
-                "{}/{}",
-                home_dir().unwrap().to_string_lossy().to_string(),
-                suffix
-            )
+            format!("{}/{}", home_dir().unwrap().to_string_lossy(), suffix)
         )
     }
 
@@ -111,9 +417,207 @@ mod tests {
 
     // --------------------------------------------------------------------------------------------
 
+    #[test]
+    fn test_unsandboxed() {
+        assert_eq!(
+            super::unsandboxed(
+                std::path::Path::new("/Users/alice/Library/Containers/com.example.App/Data"),
+                "com.example.App"
+            ),
+            PathBuf::from("/Users/alice")
+        );
+        assert_eq!(
+            super::unsandboxed(std::path::Path::new("/Users/alice"), "com.example.App"),
+            PathBuf::from("/Users/alice")
+        );
+    }
+
+    #[test]
+    fn test_sandbox_container_dir_for() {
+        if crate::sandbox_container_id().is_none() {
+            test_user_dir(
+                crate::sandbox_container_dir_for("com.example.App").unwrap(),
+                "Library/Containers/com.example.App/Data",
+            );
+        }
+    }
+
+    #[test]
+    fn test_group_container_dir_for() {
+        if crate::sandbox_container_id().is_none() {
+            test_user_dir(
+                crate::group_container_dir_for("group.com.example.App").unwrap(),
+                "Library/Group Containers/group.com.example.App",
+            );
+        }
+    }
+
+    #[test]
+    fn test_application_scripts_dir_for() {
+        if crate::sandbox_container_id().is_none() {
+            test_user_dir(
+                crate::application_scripts_dir_for("com.example.App").unwrap(),
+                "Library/Application Scripts/com.example.App",
+            );
+        }
+    }
+
+    #[test]
+    fn test_launch_agents_dir() {
+        if crate::sandbox_container_id().is_none() {
+            test_user_dir(crate::launch_agents_dir().unwrap(), "Library/LaunchAgents");
+        }
+        test_dir(
+            crate::system_launch_agents_dir().unwrap(),
+            "/Library/LaunchAgents",
+        );
+        test_dir(
+            crate::launch_daemons_dir().unwrap(),
+            "/Library/LaunchDaemons",
+        );
+    }
+
+    #[test]
+    fn test_font_dir() {
+        if crate::sandbox_container_id().is_none() {
+            test_user_dir(crate::font_dir().unwrap(), "Library/Fonts");
+        }
+        test_dir(crate::system_font_dir().unwrap(), "/Library/Fonts");
+    }
+
+    #[test]
+    fn test_locale_dir_for() {
+        test_dir(
+            crate::locale_dir_for("Chrome").unwrap(),
+            "/Applications/Chrome.app/Contents/Resources",
+        );
+        let dirs = crate::locale_search_dirs_for("Chrome");
+        assert_eq!(dirs.len(), 2);
+        test_user_dir(
+            dirs[0].clone(),
+            "Applications/Chrome.app/Contents/Resources",
+        );
+    }
+
+    #[test]
+    fn test_system_data_dirs() {
+        assert_eq!(
+            crate::system_data_dirs(),
+            vec![
+                PathBuf::from("/Library/Application Support"),
+                PathBuf::from("/Library"),
+                PathBuf::from("/System/Library"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trash_dir() {
+        if crate::sandbox_container_id().is_none() {
+            test_user_dir(crate::trash_dir().unwrap(), ".Trash");
+        }
+    }
+
+    #[test]
+    fn test_man_dir() {
+        assert_eq!(
+            crate::man_dir(),
+            crate::xdg_base(crate::BaseKind::DataHome)
+                .value
+                .map(|path| path.join("man"))
+        );
+        test_dir(crate::system_man_dir().unwrap(), "/usr/local/share/man");
+    }
+
+    #[test]
+    fn test_desktop_entry_dir() {
+        assert!(crate::desktop_entry_dir().is_none());
+        assert!(crate::system_desktop_entry_dir().is_none());
+    }
+
+    #[test]
+    fn test_mime_dir() {
+        assert!(crate::mime_dir().is_none());
+        assert!(crate::system_mime_dir().is_none());
+        assert!(crate::glib_schemas_dir().is_none());
+        assert!(crate::keyfile_backend_dir().is_none());
+    }
+
+    #[test]
+    fn test_public_data_dir() {
+        assert_eq!(
+            crate::public_data_dir_for("Chrome"),
+            Some(PathBuf::from("/Users/Shared/Chrome"))
+        );
+        assert_eq!(crate::shared_documents_dir(), crate::public_data_dir());
+    }
+
+    #[test]
+    fn test_autostart_dir() {
+        assert_eq!(crate::autostart_dir(), crate::launch_agents_dir());
+        assert_eq!(
+            crate::system_autostart_dir(),
+            crate::system_launch_agents_dir()
+        );
+    }
+
+    #[test]
+    fn test_crash_reports_dir() {
+        test_user_dir(
+            crate::crash_reports_dir().unwrap(),
+            "Library/Logs/DiagnosticReports",
+        );
+        assert_eq!(
+            crate::system_crash_reports_dir(),
+            Some(PathBuf::from("/Library/Logs/DiagnosticReports"))
+        );
+    }
+
+    #[test]
+    fn test_crash_dumps_dir_for() {
+        assert_eq!(
+            crate::crash_dumps_dir_for("Chrome"),
+            crate::crash_reports_dir()
+        );
+    }
+
+    #[test]
+    fn test_package_dir_for() {
+        assert!(crate::package_family_name().is_none());
+        assert!(crate::package_dir_for("Example.App_8wekyb3d8bbwe").is_none());
+    }
+
+    #[test]
+    fn test_linux_sandbox() {
+        assert!(crate::flatpak_id().is_none());
+        assert!(crate::snap_name().is_none());
+        assert!(crate::sandboxed_app_dir().is_none());
+        if crate::sandbox_container_id().is_none() {
+            assert_eq!(crate::host_home_dir(), home_dir());
+        }
+    }
+
+    #[test]
+    fn test_icloud_container_dir_for() {
+        if let Some(drive) = crate::icloud_drive_dir() {
+            assert_eq!(
+                crate::icloud_container_dir_for("iCloud.com.example.App"),
+                drive
+                    .parent()
+                    .map(|path| path.join("iCloud~com~example~App"))
+            );
+        } else {
+            assert!(crate::icloud_container_dir_for("iCloud.com.example.App").is_none());
+        }
+    }
+
     #[test]
     fn test_application_dir() {
         test_dir(crate::application_dir().unwrap(), "/Applications");
+        test_dir(
+            crate::application_dir_for("Chrome").unwrap(),
+            "/Applications/Chrome.app",
+        );
     }
 
     #[test]
@@ -127,6 +631,20 @@ mod tests {
     #[test]
     fn test_user_application_dir() {
         test_user_dir(crate::user_application_dir().unwrap(), "Applications");
+        assert_eq!(
+            crate::user_application_dir_for("Chrome"),
+            crate::user_app_container_dir_for("Chrome")
+        );
+    }
+
+    #[test]
+    fn test_user_application_shared_dir() {
+        if crate::sandbox_container_id().is_none() {
+            test_user_dir(
+                crate::user_application_shared_dir().unwrap(),
+                "Library/Frameworks",
+            );
+        }
     }
 
     // --------------------------------------------------------------------------------------------
@@ -147,6 +665,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_app_container_bundle_dirs_for() {
+        test_dir(
+            crate::app_container_resources_dir_for("Chrome").unwrap(),
+            "/Applications/Chrome.app/Contents/Resources",
+        );
+        test_dir(
+            crate::app_container_frameworks_dir_for("Chrome").unwrap(),
+            "/Applications/Chrome.app/Contents/Frameworks",
+        );
+        test_dir(
+            crate::app_container_plugins_dir_for("Chrome").unwrap(),
+            "/Applications/Chrome.app/Contents/PlugIns",
+        );
+        test_user_dir(
+            crate::user_app_container_resources_dir_for("Chrome").unwrap(),
+            "Applications/Chrome.app/Contents/Resources",
+        );
+        test_user_dir(
+            crate::user_app_container_frameworks_dir_for("Chrome").unwrap(),
+            "Applications/Chrome.app/Contents/Frameworks",
+        );
+        test_user_dir(
+            crate::user_app_container_plugins_dir_for("Chrome").unwrap(),
+            "Applications/Chrome.app/Contents/PlugIns",
+        );
+    }
+
     #[test]
     fn test_user_app_container_dir_for() {
         test_user_dir(
@@ -191,6 +737,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_local_dir() {
+        test_user_dir(
+            crate::config_local_dir().unwrap(),
+            "Library/Application Support",
+        );
+    }
+
+    #[test]
+    fn test_config_local_dir_for() {
+        test_user_dir(
+            crate::config_local_dir_for("Chrome").unwrap(),
+            "Library/Application Support/Chrome",
+        );
+    }
+
     #[test]
     fn test_data_dir() {
         test_user_dir(crate::data_dir().unwrap(), "Library/Application Support");
@@ -243,6 +805,34 @@ mod tests {
         test_user_dir(crate::log_dir_for("Chrome").unwrap(), "Library/Logs/Chrome");
     }
 
+    #[test]
+    fn test_system_log_dir() {
+        assert_eq!(
+            crate::system_log_dir(),
+            Some(PathBuf::from("/Library/Logs"))
+        );
+    }
+
+    #[test]
+    fn test_system_log_dir_for() {
+        assert_eq!(
+            crate::system_log_dir_for("Chrome"),
+            Some(PathBuf::from("/Library/Logs/Chrome"))
+        );
+    }
+
+    #[test]
+    fn test_service_dirs_for() {
+        let dirs = crate::service_dirs_for("Chrome");
+        assert_eq!(
+            dirs.data,
+            Some(PathBuf::from("/Library/Application Support/Chrome"))
+        );
+        assert_eq!(dirs.cache, Some(PathBuf::from("/Library/Caches/Chrome")));
+        assert_eq!(dirs.runtime, Some(PathBuf::from("/var/run/Chrome")));
+        assert_eq!(dirs.log, Some(PathBuf::from("/Library/Logs/Chrome")));
+    }
+
     #[test]
     fn test_preference_dir() {
         test_user_dir(crate::preference_dir().unwrap(), "Library/Preferences");
@@ -256,6 +846,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_state_dir_for() {
+        test_user_dir(
+            crate::state_dir_for("Chrome").unwrap(),
+            "Library/Application Support/Chrome",
+        );
+    }
+
     #[test]
     fn test_template_for() {
         assert_eq!(crate::template_dir(), None,);
@@ -268,4 +866,56 @@ mod tests {
             "Library/Application Support/Chrome/Templates",
         );
     }
+
+    #[test]
+    fn test_system_template_dir_for() {
+        assert_eq!(crate::system_template_dir(), None);
+        assert_eq!(
+            crate::system_template_dir_for("Chrome"),
+            Some(PathBuf::from(
+                "/Library/Application Support/Chrome/Templates"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_saved_games_dir() {
+        assert_eq!(crate::saved_games_dir(), None);
+    }
+
+    #[test]
+    fn test_saved_games_dir_for() {
+        test_user_dir(
+            crate::saved_games_dir_for("Chrome").unwrap(),
+            "Library/Application Support/Chrome/Saves",
+        );
+    }
+
+    #[test]
+    fn test_icons_dir_for() {
+        assert_eq!(crate::icons_dir(), None);
+        test_user_dir(
+            crate::icons_dir_for("Chrome").unwrap(),
+            "Library/Application Support/Chrome/Icons",
+        );
+    }
+
+    #[test]
+    fn test_themes_dir_for() {
+        assert_eq!(crate::themes_dir(), None);
+        test_user_dir(
+            crate::themes_dir_for("Chrome").unwrap(),
+            "Library/Application Support/Chrome/Themes",
+        );
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn test_minidump_path_for() {
+        test_user_dir(
+            crate::minidump_path_for("Chrome").unwrap(),
+            "Library/Application Support/Chrome/Crashes",
+        );
+    }
 }
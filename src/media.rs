@@ -0,0 +1,107 @@
+/*!
+Provides the less common media locations that personalization tools use, such as the folders of
+desktop pictures and ringtones, on the platforms that define them.
+*/
+
+use crate::{known_folder, override_root, resolve, sys, DirKind, FolderId};
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path to the directory in which the user's own desktop pictures, or wallpapers,
+/// are kept.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | `$XDG_DATA_HOME` or `$HOME`/.local/share/backgrounds | /home/alice/.local/share/backgrounds |
+/// | macOS   | `$HOME`/Library/Desktop Pictures         | /Users/Alice/Library/Desktop Pictures   |
+/// | Windows | -                                        | -                                       |
+///
+/// On macOS this is in the user's own home directory, even within the app sandbox.
+///
+/// See also [`system_wallpaper_dir`](fn.system_wallpaper_dir.html).
+///
+pub fn wallpaper_dir() -> Option<PathBuf> {
+    resolve::dir(DirKind::Wallpaper)
+}
+
+///
+/// Returns the path to the directory of the desktop pictures, or wallpapers, installed for all
+/// users.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | /usr/share/backgrounds, or /usr/local/share/backgrounds on the BSDs | /usr/share/backgrounds |
+/// | macOS   | /Library/Desktop Pictures                | /Library/Desktop Pictures               |
+/// | Windows | `{FOLDERID_Windows}`\Web\Wallpaper       | C:\Windows\Web\Wallpaper                |
+///
+pub fn system_wallpaper_dir() -> Option<PathBuf> {
+    override_root::apply(sys::system_wallpaper_dir())
+}
+
+///
+/// Returns the path to the user's ringtones directory.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_Ringtones}`                   | C:\Users\Alice\AppData\Local\Microsoft\Windows\Ringtones |
+///
+pub fn ringtones_dir() -> Option<PathBuf> {
+    known_folder(FolderId::Ringtones)
+}
+
+///
+/// Returns the path to the directory into which photos and videos taken with the device's camera
+/// are saved.
+///
+/// |Platform | Value                                    | Example                                 |
+/// | ------- | ---------------------------------------- | --------------------------------------- |
+/// | Linux   | -                                        | -                                       |
+/// | macOS   | -                                        | -                                       |
+/// | Windows | `{FOLDERID_CameraRoll}`                  | C:\Users\Alice\Pictures\Camera Roll     |
+///
+pub fn camera_roll_dir() -> Option<PathBuf> {
+    known_folder(FolderId::CameraRoll)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_dirs() {
+        if cfg!(windows) {
+            assert!(system_wallpaper_dir().unwrap().ends_with("Web\\Wallpaper"));
+            assert!(wallpaper_dir().is_none());
+        } else {
+            assert!(ringtones_dir().is_none());
+            assert!(camera_roll_dir().is_none());
+        }
+        if cfg!(target_os = "macos") {
+            assert_eq!(
+                system_wallpaper_dir(),
+                Some(PathBuf::from("/Library/Desktop Pictures"))
+            );
+        }
+        if cfg!(target_os = "linux") {
+            assert_eq!(
+                wallpaper_dir(),
+                crate::data_dir().map(|data| data.join("backgrounds"))
+            );
+            assert_eq!(DirKind::Wallpaper.dir(), wallpaper_dir());
+            assert_eq!(
+                system_wallpaper_dir(),
+                Some(PathBuf::from("/usr/share/backgrounds"))
+            );
+        }
+    }
+}
@@ -0,0 +1,141 @@
+/*!
+Provides counts and cumulative durations of directory resolution, per [`DirKind`](enum.DirKind.html),
+so that applications can include the cost of this crate in their startup profiling and detect
+pathological environments such as slow network home directories.
+
+The public functions in this module are only available with the `metrics` feature; without it no
+measurements are taken.
+*/
+
+use crate::DirKind;
+#[cfg(feature = "metrics")]
+use std::collections::HashMap;
+#[cfg(feature = "metrics")]
+use std::sync::{Mutex, OnceLock};
+#[cfg(feature = "metrics")]
+use std::time::Duration;
+#[cfg(all(
+    feature = "metrics",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+use std::time::Instant;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The measurements taken for the resolution of a single directory kind; this includes both its
+/// generic and application-specific forms.
+///
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResolutionMetrics {
+    /// The number of times the directory kind was resolved.
+    pub count: u64,
+    /// The total time spent resolving the directory kind; this is always zero in a browser,
+    /// where there is no clock.
+    pub total: Duration,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns a snapshot of the measurements taken since the process started, or since the last call
+/// to [`reset_metrics`](fn.reset_metrics.html). Kinds that have not been resolved are not present.
+///
+#[cfg(feature = "metrics")]
+pub fn metrics() -> HashMap<DirKind, ResolutionMetrics> {
+    match measurements().lock() {
+        Ok(guard) => guard.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+///
+/// Discard all measurements taken so far.
+///
+#[cfg(feature = "metrics")]
+pub fn reset_metrics() {
+    match measurements().lock() {
+        Ok(mut guard) => guard.clear(),
+        Err(poisoned) => poisoned.into_inner().clear(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Call `resolver`, recording its duration against `kind`.
+///
+#[cfg(feature = "metrics")]
+pub(crate) fn timed<T, F: FnOnce() -> T>(kind: DirKind, resolver: F) -> T {
+    let (result, elapsed) = measure(resolver);
+    let mut measurements = match measurements().lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let entry = measurements.entry(kind).or_default();
+    entry.count += 1;
+    entry.total += elapsed;
+    result
+}
+
+#[cfg(not(feature = "metrics"))]
+#[inline(always)]
+pub(crate) fn timed<T, F: FnOnce() -> T>(_: DirKind, resolver: F) -> T {
+    resolver()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(
+    feature = "metrics",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+fn measure<T, F: FnOnce() -> T>(resolver: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = resolver();
+    (result, start.elapsed())
+}
+
+// in a browser there is no clock, and `Instant::now` panics; resolutions are only counted.
+#[cfg(all(feature = "metrics", target_arch = "wasm32", target_os = "unknown"))]
+fn measure<T, F: FnOnce() -> T>(resolver: F) -> (T, Duration) {
+    (resolver(), Duration::ZERO)
+}
+
+#[cfg(feature = "metrics")]
+fn measurements() -> &'static Mutex<HashMap<DirKind, ResolutionMetrics>> {
+    static MEASUREMENTS: OnceLock<Mutex<HashMap<DirKind, ResolutionMetrics>>> = OnceLock::new();
+    MEASUREMENTS.get_or_init(Default::default)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_counted() {
+        let before = metrics()
+            .get(&DirKind::Preference)
+            .copied()
+            .unwrap_or_default();
+        let _ = crate::preference_dir();
+        let _ = crate::preference_dir_for("Chrome");
+        let after = metrics().get(&DirKind::Preference).copied().unwrap();
+        // other tests may resolve the same kind concurrently.
+        assert!(after.count >= before.count + 2);
+        assert!(after.total >= before.total);
+    }
+}
@@ -0,0 +1,218 @@
+/*!
+Provides the choice of how an application's name is turned into the name of its directories.
+*/
+
+use crate::{sanitize_app_name, Platform};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The policy used to name an application's directories. By default the application name is
+/// used verbatim, other than being made safe by
+/// [`sanitize_app_name`](fn.sanitize_app_name.html); the conventional policy follows each
+/// platform's idiom, as does the `directories` crate.
+///
+/// |Platform | Verbatim | Conventional                       | Example, Conventional          |
+/// | ------- | -------- | ---------------------------------- | ------------------------------ |
+/// | Linux   | {app}    | {app}, lower-case, dash-separated  | my-app                         |
+/// | macOS   | {app}    | {qualifier}.{organization}.{app}   | com.Example-Corp.My-App        |
+/// | Windows | {app}    | {organization}\{app}               | Example Corp\My App            |
+///
+/// Application containers, such as a macOS `.app` bundle, are always named for the application
+/// itself.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Naming {
+    /// The application name, as given.
+    #[default]
+    Verbatim,
+    /// The platform's conventional form, which on macOS and Windows also includes the reverse
+    /// domain name qualifier, such as `com`, and the organization name; either may be empty.
+    Conventional {
+        qualifier: String,
+        organization: String,
+    },
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Set the naming policy used to name the directories of the application `app`; this applies to
+/// all of the `_for` functions, and to all other ways of resolving the application's
+/// directories. Values already cached by [`warm`](fn.warm.html) are not affected, so this should
+/// be called first.
+///
+/// ```rust
+/// use xdirs::{config_dir_for, set_naming, Naming};
+///
+/// set_naming("My App", Naming::conventional("com", "Example Corp"));
+/// let config = config_dir_for("My App");
+/// ```
+///
+pub fn set_naming(app: &str, naming: Naming) {
+    let mut policies = match policies().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let _ = policies.insert(app.to_string(), naming);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the naming policy set for `app`, or the default.
+///
+pub(crate) fn naming(app: &str) -> Naming {
+    let policies = match policies().read() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    policies.get(app).cloned().unwrap_or_default()
+}
+
+///
+/// Returns the stem of the file names this crate suggests for the files of `app`, such as its
+/// main configuration file; this is `app` in lower-case with words separated by dashes, whatever
+/// the naming policy.
+///
+pub(crate) fn file_stem(app: &str) -> String {
+    sanitize_app_name(&separated(app, '-', true)).into_owned()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Naming {
+    ///
+    /// Construct the conventional policy for an application published by `organization`, with
+    /// the reverse domain name `qualifier`.
+    ///
+    pub fn conventional(qualifier: &str, organization: &str) -> Self {
+        Self::Conventional {
+            qualifier: qualifier.to_string(),
+            organization: organization.to_string(),
+        }
+    }
+
+    ///
+    /// Returns the relative path, of one or more safe components, used to name the directories
+    /// of `app` on `platform`.
+    ///
+    pub fn dir_name(&self, platform: Platform, app: &str) -> PathBuf {
+        match self {
+            Self::Verbatim => PathBuf::from(sanitize_app_name(app).as_ref()),
+            Self::Conventional {
+                qualifier,
+                organization,
+            } => match platform {
+                Platform::Linux => {
+                    PathBuf::from(sanitize_app_name(&separated(app, '-', true)).as_ref())
+                }
+                Platform::MacOS => {
+                    let parts: Vec<String> = [qualifier.as_str(), organization, app]
+                        .iter()
+                        .filter(|part| !part.trim().is_empty())
+                        .map(|part| separated(part, '-', false))
+                        .collect();
+                    PathBuf::from(sanitize_app_name(&parts.join(".")).as_ref())
+                }
+                Platform::Windows => [organization.as_str(), app]
+                    .iter()
+                    .filter(|part| !part.trim().is_empty())
+                    .map(|part| sanitize_app_name(part).into_owned())
+                    .collect(),
+            },
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn policies() -> &'static RwLock<HashMap<String, Naming>> {
+    static POLICIES: OnceLock<RwLock<HashMap<String, Naming>>> = OnceLock::new();
+    POLICIES.get_or_init(Default::default)
+}
+
+fn separated(name: &str, separator: char, lower_case: bool) -> String {
+    let words: Vec<String> = name
+        .split(|c: char| c.is_whitespace() || c == '_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            if lower_case {
+                word.to_lowercase()
+            } else {
+                word.to_string()
+            }
+        })
+        .collect();
+    words.join(&separator.to_string())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbatim() {
+        for platform in [Platform::Linux, Platform::MacOS, Platform::Windows] {
+            assert_eq!(
+                Naming::Verbatim.dir_name(platform, "My App"),
+                PathBuf::from("My App")
+            );
+        }
+    }
+
+    #[test]
+    fn test_conventional() {
+        let naming = Naming::conventional("com", "Example Corp");
+        assert_eq!(
+            naming.dir_name(Platform::Linux, "My App_Name"),
+            PathBuf::from("my-app-name")
+        );
+        assert_eq!(
+            naming.dir_name(Platform::MacOS, "My App"),
+            PathBuf::from("com.Example-Corp.My-App")
+        );
+        assert_eq!(
+            naming.dir_name(Platform::Windows, "My App"),
+            PathBuf::from("Example Corp").join("My App")
+        );
+        assert_eq!(
+            Naming::conventional("", "").dir_name(Platform::MacOS, "My App"),
+            PathBuf::from("My-App")
+        );
+        assert_eq!(
+            naming.dir_name(Platform::Windows, "../evil"),
+            PathBuf::from("Example Corp").join(".._evil")
+        );
+    }
+
+    #[test]
+    fn test_set_naming() {
+        let app = "xdirs naming test";
+        set_naming(app, Naming::conventional("org", "xdirs"));
+        let config = crate::config_dir_for(app).unwrap();
+        let expected = Naming::conventional("org", "xdirs").dir_name(Platform::current(), app);
+        assert!(config.ends_with(expected));
+        assert_eq!(
+            crate::config_dir().map(|base| config.starts_with(base)),
+            Some(true)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+/*!
+Provides the choice of how an application's favorites directory is nested relative to the
+generic directories.
+*/
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The order in which the application name and the directory kind are nested to form an
+/// application's favorites directory. By default the application is nested under the favorites
+/// directory on every platform; the other variants apply the same order on every platform.
+///
+/// |Scheme          | Linux                                   | macOS, Windows                      |
+/// | -------------- | --------------------------------------- | ----------------------------------- |
+/// | PlatformDefault| {favorites}/{app}, or KindUnderApp where only that exists | {favorites}/{app} |
+/// | AppUnderKind   | {favorites} or {data_local}/favorites, then /{app} | {favorites}/{app}        |
+/// | KindUnderApp   | {data_local}/{app}/favorites            | {data_local}/{app}/Favorites        |
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NestingScheme {
+    /// The platform's existing order; this is `AppUnderKind`, except that on Linux the
+    /// favorites of an earlier version are found where they are.
+    #[default]
+    PlatformDefault,
+    /// The application directory is nested under the favorites directory.
+    AppUnderKind,
+    /// The favorites directory is nested under the application's local data directory; on Linux
+    /// this was the default before version 0.2.0.
+    KindUnderApp,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Set the nesting scheme used to resolve the favorites directory for the application `app`;
+/// this applies to
+/// [`favorites_dir_for`](fn.favorites_dir_for.html) and to all other ways of resolving
+/// `DirKind::Favorites` for the application. Values already cached by
+/// [`warm`](fn.warm.html) are not affected, so this should be called first.
+///
+/// ```rust
+/// use xdirs::{favorites_dir_for, set_nesting_scheme, NestingScheme};
+///
+/// set_nesting_scheme("MyApp", NestingScheme::AppUnderKind);
+/// if let Some(path) = favorites_dir_for("MyApp") {
+///     assert!(path.ends_with("MyApp"));
+/// }
+/// ```
+///
+pub fn set_nesting_scheme(app: &str, scheme: NestingScheme) {
+    let mut schemes = match schemes().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let _ = schemes.insert(app.to_string(), scheme);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the nesting scheme set for `app`, or the default.
+///
+pub(crate) fn nesting_scheme(app: &str) -> NestingScheme {
+    let schemes = match schemes().read() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    schemes.get(app).copied().unwrap_or_default()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn schemes() -> &'static RwLock<HashMap<String, NestingScheme>> {
+    static SCHEMES: OnceLock<RwLock<HashMap<String, NestingScheme>>> = OnceLock::new();
+    SCHEMES.get_or_init(Default::default)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DirectoryProvider, PlatformProvider};
+
+    #[test]
+    fn test_default_scheme() {
+        assert_eq!(
+            nesting_scheme("xdirs-nesting-test-1"),
+            NestingScheme::PlatformDefault
+        );
+        assert_eq!(
+            crate::favorites_dir_for("xdirs-nesting-test-1"),
+            PlatformProvider.dir_for(crate::DirKind::Favorites, "xdirs-nesting-test-1")
+        );
+    }
+
+    #[test]
+    fn test_set_scheme() {
+        set_nesting_scheme("xdirs-nesting-test-2", NestingScheme::KindUnderApp);
+        let favorites = crate::favorites_dir_for("xdirs-nesting-test-2").unwrap();
+        assert!(favorites.starts_with(crate::data_local_dir_for("xdirs-nesting-test-2").unwrap()));
+        set_nesting_scheme("xdirs-nesting-test-2", NestingScheme::AppUnderKind);
+        let favorites = crate::favorites_dir_for("xdirs-nesting-test-2").unwrap();
+        assert!(favorites.ends_with("xdirs-nesting-test-2"));
+    }
+}
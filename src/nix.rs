@@ -1,90 +1,478 @@
-use dirs_next::{cache_dir, config_dir, data_dir, data_local_dir};
-use std::path::PathBuf;
+pub use crate::environment::home_dir;
+use crate::environment::non_empty_var;
+pub use crate::unsupported::{
+    application_scripts_dir_for, application_shared_dir, crash_reports_dir, fallback_source,
+    group_container_dir_for, icloud_container_dir_for, icloud_drive_dir, known_folder_dir,
+    launch_agents_dir, launch_daemons_dir, log_dir, package_dir_for, package_family_name,
+    pipe_name_for, registered_install_dir_for, sandbox_container_dir_for, sandbox_container_id,
+    saved_games_dir, shared_documents_dir, system_crash_reports_dir, system_launch_agents_dir,
+};
+use crate::user_dirs::resolve_user_dir;
+use crate::xdg::resolve;
+use crate::{override_root, BaseKind, DirKind};
+use std::path::{Path, PathBuf};
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-const D_FAVORITES: &str = "favorites";
-const D_LOGS: &str = "logs";
-const D_TEMPLATES: &str = "templates";
-
 pub fn application_dir() -> Option<PathBuf> {
-    None
+    if is_sandboxed() {
+        None
+    } else {
+        Some(PathBuf::from("/opt"))
+    }
+}
+
+pub fn user_application_dir() -> Option<PathBuf> {
+    if is_sandboxed() {
+        None
+    } else {
+        local_prefix().map(|path| path.join(D_OPT))
+    }
 }
 
-pub fn application_shared_dir() -> Option<PathBuf> {
-    None
+pub fn user_application_shared_dir() -> Option<PathBuf> {
+    application_shared_dir()
 }
 
-pub fn user_application_dir() -> Option<PathBuf> {
-    application_dir()
+pub fn executable_dir() -> Option<PathBuf> {
+    resolve(BaseKind::BinHome).value
 }
 
 // ------------------------------------------------------------------------------------------------
 
-pub fn app_container_dir_for(_: &str) -> Option<PathBuf> {
-    None
+pub fn flatpak_id() -> Option<String> {
+    non_empty_var(E_FLATPAK_ID).or_else(flatpak_info_name)
+}
+
+pub fn snap_name() -> Option<String> {
+    non_empty_var(E_SNAP_NAME)
+}
+
+pub fn sandboxed_app_dir() -> Option<PathBuf> {
+    if flatpak_id().is_some() {
+        Some(PathBuf::from(D_FLATPAK_APP))
+    } else if snap_name().is_some() {
+        non_empty_var(E_SNAP).map(PathBuf::from)
+    } else {
+        None
+    }
+}
+
+pub fn snap_user_common_dir() -> Option<PathBuf> {
+    snap_name().and(non_empty_var(E_SNAP_USER_COMMON).map(PathBuf::from))
+}
+
+pub fn host_home_dir() -> Option<PathBuf> {
+    match snap_name().and(non_empty_var(E_SNAP_REAL_HOME)) {
+        Some(home) => Some(PathBuf::from(home)),
+        None => home_dir(),
+    }
+}
+
+pub fn installed_container_dir_for(kind: DirKind, app: &str) -> Option<PathBuf> {
+    let executable = match kind {
+        DirKind::AppContainer => false,
+        DirKind::AppContainerExecutable => true,
+        _ => return None,
+    };
+    let app = crate::sanitize_app_name(app);
+    let container = application_dir()
+        .into_iter()
+        .chain([D_SYSTEM_LIB, D_SYSTEM_SHARE].iter().map(PathBuf::from))
+        .map(|path| path.join(app.as_ref()))
+        .find(|path| override_root::is_dir(path))?;
+    if executable {
+        Some(executable_dir_of(&container))
+    } else {
+        Some(container)
+    }
+}
+
+pub fn crash_dumps_dir() -> Option<PathBuf> {
+    crate::xdg::resolve(crate::BaseKind::StateHome).value
+}
+
+pub fn temp_dir() -> Option<PathBuf> {
+    Some(std::env::temp_dir())
+}
+
+///
+/// Returns an identifier for the current user, used to keep apart the directories of different
+/// users in a shared location such as /tmp; this is the real uid where `/proc` is available,
+/// otherwise the login name.
+///
+pub fn user_id() -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+    std::fs::metadata(F_PROC_SELF)
+        .ok()
+        .map(|metadata| metadata.uid().to_string())
+        .or_else(|| non_empty_var(E_USER))
+}
+
+pub fn runtime_dir() -> Option<PathBuf> {
+    crate::xdg::resolve(crate::BaseKind::RuntimeDir).value
+}
+
+pub fn trash_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join(D_TRASH))
+}
+
+pub fn recent_dir() -> Option<PathBuf> {
+    data_dir()
+}
+
+pub fn extra_plugin_dirs_for(app: &str) -> Vec<PathBuf> {
+    let app = crate::sanitize_app_name(app);
+    system_data_dirs()
+        .into_iter()
+        .skip(1)
+        .map(|path| path.join(app.as_ref()).join(D_PLUGINS))
+        .collect()
+}
+
+pub fn system_locale_dirs() -> Vec<PathBuf> {
+    system_data_dirs()
+        .into_iter()
+        .map(|path| path.join(D_LOCALE))
+        .collect()
+}
+
+pub fn system_config_dirs() -> Vec<PathBuf> {
+    xdg_dirs(E_CONFIG_DIRS, &[D_SYSTEM_CONFIG])
+}
+
+pub fn system_data_dirs() -> Vec<PathBuf> {
+    xdg_dirs(E_DATA_DIRS, D_SYSTEM_DATA_DIRS)
+}
+
+pub fn system_domain_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn service_dirs_for(app: &str) -> crate::ServiceDirs {
+    let app = crate::sanitize_app_name(app);
+    let dir = |parent: &str| Some(Path::new(parent).join(app.as_ref()));
+    crate::ServiceDirs {
+        config: dir(D_SERVICE_CONFIG),
+        data: dir(D_SERVICE_DATA),
+        cache: dir(D_SERVICE_CACHE),
+        runtime: dir(if cfg!(target_os = "linux") {
+            D_SERVICE_RUNTIME
+        } else {
+            D_SERVICE_LEGACY_RUNTIME
+        }),
+        log: dir(D_SERVICE_LOG),
+    }
+}
+
+pub fn autostart_dir() -> Option<PathBuf> {
+    config_dir().map(|path| path.join(D_AUTOSTART))
+}
+
+pub fn system_autostart_dir() -> Option<PathBuf> {
+    Some(system_config_dir().join(D_AUTOSTART))
+}
+
+pub fn desktop_entry_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join(D_APPLICATIONS))
+}
+
+pub fn system_desktop_entry_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_DATA).join(D_APPLICATIONS))
+}
+
+pub fn mime_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join(D_MIME))
+}
+
+pub fn system_mime_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_DATA).join(D_MIME))
+}
+
+pub fn glib_schemas_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join(D_GLIB).join(D_SCHEMAS))
+}
+
+pub fn system_glib_schemas_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_DATA).join(D_GLIB).join(D_SCHEMAS))
+}
+
+pub fn keyfile_backend_dir() -> Option<PathBuf> {
+    config_dir().map(|path| path.join(D_GLIB).join(D_SETTINGS))
+}
+
+pub fn public_data_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_DATA))
+}
+
+pub fn font_dir() -> Option<PathBuf> {
+    let fonts = data_dir().map(|path| path.join(D_FONTS));
+    let legacy = home_dir().map(|path| path.join(D_LEGACY_FONTS));
+    match (fonts, legacy) {
+        (Some(fonts), Some(legacy)) if !fonts.is_dir() && legacy.is_dir() => Some(legacy),
+        (fonts, _) => fonts,
+    }
+}
+
+pub fn system_font_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_LOCAL_DATA).join(D_FONTS))
 }
 
-pub fn app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
-    app_container_dir_for(app)
+pub fn man_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join(D_MAN))
 }
 
-pub fn user_app_container_dir_for(_: &str) -> Option<PathBuf> {
-    None
+pub fn system_man_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_LOCAL_DATA).join(D_MAN))
 }
 
-pub fn user_app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
-    user_app_container_dir_for(app)
+pub fn wallpaper_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join(D_BACKGROUNDS))
+}
+
+pub fn system_wallpaper_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_DATA).join(D_BACKGROUNDS))
 }
 
 // ------------------------------------------------------------------------------------------------
 
-pub fn cache_dir_for(app: &str) -> Option<PathBuf> {
-    cache_dir().map(|path| path.join(app))
+pub fn cache_dir() -> Option<PathBuf> {
+    resolve(BaseKind::CacheHome).value
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    resolve(BaseKind::ConfigHome).value
 }
 
-pub fn config_dir_for(app: &str) -> Option<PathBuf> {
-    config_dir().map(|path| path.join(app))
+pub fn config_local_dir() -> Option<PathBuf> {
+    config_dir()
 }
 
-pub fn data_dir_for(app: &str) -> Option<PathBuf> {
-    data_dir().map(|path| path.join(app))
+pub fn data_dir() -> Option<PathBuf> {
+    resolve(BaseKind::DataHome).value
 }
 
-pub fn data_local_dir_for(app: &str) -> Option<PathBuf> {
-    data_local_dir().map(|path| path.join(app))
+pub fn data_local_dir() -> Option<PathBuf> {
+    data_dir()
 }
 
-pub fn favorites_dir() -> Option<PathBuf> {
-    None
+pub fn desktop_dir() -> Option<PathBuf> {
+    resolve_user_dir(U_DESKTOP)
 }
 
-pub fn favorites_dir_for(app: &str) -> Option<PathBuf> {
-    data_local_dir_for(app).map(|path| path.join(D_FAVORITES))
+pub fn document_dir() -> Option<PathBuf> {
+    resolve_user_dir(U_DOCUMENTS)
 }
 
-pub fn log_dir() -> Option<PathBuf> {
-    None
+pub fn download_dir() -> Option<PathBuf> {
+    resolve_user_dir(U_DOWNLOAD)
 }
 
-pub fn log_dir_for(app: &str) -> Option<PathBuf> {
-    data_local_dir_for(app).map(|path| path.join(D_LOGS))
+pub fn public_dir() -> Option<PathBuf> {
+    resolve_user_dir(U_PUBLICSHARE)
+}
+
+pub fn favorites_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join(D_FAVORITES))
+}
+
+pub fn system_log_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/var/log"))
 }
 
 pub fn preference_dir() -> Option<PathBuf> {
     config_dir()
 }
 
-pub fn preference_dir_for(app: &str) -> Option<PathBuf> {
-    config_dir_for(app)
+pub fn state_dir() -> Option<PathBuf> {
+    crate::xdg::resolve(crate::BaseKind::StateHome).value
+}
+
+pub fn template_dir() -> Option<PathBuf> {
+    resolve_user_dir(U_TEMPLATES)
 }
 
-pub use dirs_next::template_dir;
+pub fn system_template_dir() -> Option<PathBuf> {
+    Some(PathBuf::from(D_SYSTEM_DATA).join(D_TEMPLATES))
+}
 
-pub fn template_dir_for(app: &str) -> Option<PathBuf> {
-    config_dir_for(app).map(|d| d.join(D_TEMPLATES))
+pub fn is_remote(_path: &Path) -> bool {
+    false
+}
+
+pub fn icons_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join("icons"))
+}
+
+pub fn themes_dir() -> Option<PathBuf> {
+    data_dir().map(|path| path.join("themes"))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub fn uses_system_journal() -> bool {
+    Path::new("/run/systemd/journal/socket").exists()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const U_DESKTOP: &str = "DESKTOP";
+const U_DOCUMENTS: &str = "DOCUMENTS";
+const U_DOWNLOAD: &str = "DOWNLOAD";
+const U_PUBLICSHARE: &str = "PUBLICSHARE";
+const U_TEMPLATES: &str = "TEMPLATES";
+const D_SERVICE_CONFIG: &str = "/etc";
+const D_SERVICE_DATA: &str = "/var/lib";
+const D_SERVICE_CACHE: &str = "/var/cache";
+const D_SERVICE_RUNTIME: &str = "/run";
+const D_SERVICE_LEGACY_RUNTIME: &str = "/var/run";
+const D_SERVICE_LOG: &str = "/var/log";
+const D_LOCALE: &str = "locale";
+const D_PLUGINS: &str = "plugins";
+const D_APPLICATIONS: &str = "applications";
+const D_MIME: &str = "mime";
+const D_FAVORITES: &str = "favorites";
+const D_GLIB: &str = "glib-2.0";
+const D_SCHEMAS: &str = "schemas";
+const D_SETTINGS: &str = "settings";
+const D_TEMPLATES: &str = "templates";
+const D_AUTOSTART: &str = "autostart";
+const D_LOCAL: &str = ".local";
+const D_OPT: &str = "opt";
+const D_BIN: &str = "bin";
+const D_SYSTEM_PREFIX: &str = "/usr";
+const D_SYSTEM_LIB: &str = "/usr/lib";
+const D_SYSTEM_SHARE: &str = "/usr/share";
+const D_FONTS: &str = "fonts";
+const D_LEGACY_FONTS: &str = ".fonts";
+const D_MAN: &str = "man";
+const D_BACKGROUNDS: &str = "backgrounds";
+const D_TRASH: &str = "Trash";
+const D_SYSTEM_LOCAL_DATA: &str = "/usr/local/share";
+
+// the BSDs keep the machine-wide configuration and data of ports and packages beneath /usr/local,
+// leaving /etc and /usr/share to the base system.
+#[cfg(not(bsd))]
+const D_SYSTEM_DATA: &str = "/usr/share";
+#[cfg(not(bsd))]
+const D_SYSTEM_CONFIG: &str = "/etc/xdg";
+#[cfg(bsd)]
+const D_SYSTEM_DATA: &str = D_SYSTEM_LOCAL_DATA;
+#[cfg(bsd)]
+const D_SYSTEM_CONFIG: &str = "/usr/local/etc/xdg";
+// the default value of `$XDG_DATA_DIRS`, in which the system's data directory follows
+// /usr/local/share wherever the two differ.
+#[cfg(not(bsd))]
+const D_SYSTEM_DATA_DIRS: &[&str] = &[D_SYSTEM_LOCAL_DATA, D_SYSTEM_DATA];
+#[cfg(bsd)]
+const D_SYSTEM_DATA_DIRS: &[&str] = &[D_SYSTEM_DATA];
+const E_CONFIG_DIRS: &str = "XDG_CONFIG_DIRS";
+const E_DATA_DIRS: &str = "XDG_DATA_DIRS";
+const E_FLATPAK_ID: &str = "FLATPAK_ID";
+const E_USER: &str = "USER";
+const F_PROC_SELF: &str = "/proc/self";
+const E_SNAP: &str = "SNAP";
+const E_SNAP_NAME: &str = "SNAP_NAME";
+const E_SNAP_REAL_HOME: &str = "SNAP_REAL_HOME";
+const E_SNAP_USER_COMMON: &str = "SNAP_USER_COMMON";
+const D_FLATPAK_APP: &str = "/app";
+const F_FLATPAK_INFO: &str = "/.flatpak-info";
+
+///
+/// Returns `true` if the process is running within a Flatpak or Snap sandbox, in which the host's
+/// installation directories are not visible.
+///
+fn is_sandboxed() -> bool {
+    flatpak_id().is_some() || snap_name().is_some()
+}
+
+///
+/// Returns the directory holding the executables of the application installed in `container`;
+/// its own `bin` directory, if it has one, or /usr/bin for an application installed beneath /usr
+/// by the distribution. Otherwise the executables are assumed to be in the container itself.
+///
+fn executable_dir_of(container: &Path) -> PathBuf {
+    let bin = container.join(D_BIN);
+    if override_root::is_dir(&bin) {
+        bin
+    } else if container.starts_with(D_SYSTEM_PREFIX) {
+        PathBuf::from(D_SYSTEM_PREFIX).join(D_BIN)
+    } else {
+        container.to_path_buf()
+    }
+}
+
+///
+/// Returns the application identifier from the Flatpak metadata file, which is present in every
+/// Flatpak sandbox; this is only needed if `$FLATPAK_ID` has been removed from the environment.
+///
+fn flatpak_info_name() -> Option<String> {
+    std::fs::read_to_string(F_FLATPAK_INFO)
+        .ok()
+        .and_then(|info| info_name(&info))
+}
+
+fn info_name(info: &str) -> Option<String> {
+    info.lines()
+        .skip_while(|line| line.trim() != "[Application]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .find_map(|line| line.trim().strip_prefix("name="))
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+///
+/// Returns the prefix beneath which the user's own software is installed; this is the parent of
+/// `$XDG_BIN_HOME`, if that is set to an absolute path, and otherwise `$HOME`/.local.
+///
+fn local_prefix() -> Option<PathBuf> {
+    prefix_of(
+        crate::environment::var_os(BaseKind::BinHome.variable()),
+        home_dir(),
+    )
+}
+
+fn prefix_of(bin_home: Option<std::ffi::OsString>, home: Option<PathBuf>) -> Option<PathBuf> {
+    bin_home
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .and_then(|path| path.parent().map(Path::to_path_buf))
+        .or_else(|| home.map(|path| path.join(D_LOCAL)))
+}
+
+///
+/// Returns the most important of the system's XDG configuration directories, the first absolute
+/// path in `$XDG_CONFIG_DIRS`, or its default.
+///
+fn system_config_dir() -> PathBuf {
+    system_config_dirs()
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| PathBuf::from(D_SYSTEM_CONFIG))
+}
+
+///
+/// Returns the absolute paths in the XDG search path variable `var`, in order of importance, or
+/// `defaults` if there are none.
+///
+fn xdg_dirs(var: &str, defaults: &[&str]) -> Vec<PathBuf> {
+    let dirs: Vec<PathBuf> = crate::environment::var_os(var)
+        .map(|dirs| {
+            std::env::split_paths(&dirs)
+                .filter(|path| path.is_absolute())
+                .collect()
+        })
+        .unwrap_or_default();
+    if dirs.is_empty() {
+        defaults.iter().map(PathBuf::from).collect()
+    } else {
+        dirs
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -99,18 +487,10 @@ mod tests {
     fn test_user_dir(dir: PathBuf, suffix: &str) {
         assert_eq!(
             dir.to_string_lossy().to_string(),
-            format!(
-                "{}/{}",
-                home_dir().unwrap().to_string_lossy().to_string(),
-                suffix
-            )
+            format!("{}/{}", home_dir().unwrap().to_string_lossy(), suffix)
         )
     }
 
-    fn test_dir(dir: PathBuf, path: &str) {
-        assert_eq!(dir.to_string_lossy().to_string(), path.to_string())
-    }
-
     fn test_dir_is_none(dir: Option<PathBuf>) {
         assert!(dir.is_none())
     }
@@ -119,7 +499,11 @@ mod tests {
 
     #[test]
     fn test_application_dir() {
-        test_dir_is_none(crate::application_dir());
+        assert_eq!(crate::application_dir(), Some(PathBuf::from("/opt")));
+        assert_eq!(
+            crate::application_dir_for("Chrome"),
+            Some(PathBuf::from("/opt/Chrome"))
+        );
     }
 
     #[test]
@@ -129,29 +513,431 @@ mod tests {
 
     #[test]
     fn test_user_application_dir() {
-        test_dir_is_none(crate::user_application_dir());
+        let mut env = crate::environment::EnvGuard::lock();
+        env.remove("XDG_BIN_HOME");
+        crate::refresh();
+        test_user_dir(crate::user_application_dir().unwrap(), ".local/opt");
+        test_user_dir(
+            crate::user_application_dir_for("Chrome").unwrap(),
+            ".local/opt/Chrome",
+        );
+    }
+
+    #[test]
+    fn test_executable_dir() {
+        let mut env = crate::environment::EnvGuard::lock();
+        env.remove("XDG_BIN_HOME");
+        crate::refresh();
+        test_user_dir(crate::executable_dir().unwrap(), ".local/bin");
+        test_user_dir(crate::user_application_dir().unwrap(), ".local/opt");
+
+        env.set("XDG_BIN_HOME", "/srv/alice/bin");
+        crate::refresh();
+        assert_eq!(
+            crate::executable_dir(),
+            Some(PathBuf::from("/srv/alice/bin"))
+        );
+        assert_eq!(
+            crate::user_application_dir(),
+            Some(PathBuf::from("/srv/alice/opt"))
+        );
+    }
+
+    #[test]
+    fn test_prefix_of() {
+        let home = Some(PathBuf::from("/home/alice"));
+        assert_eq!(
+            super::prefix_of(Some("/usr/local/bin".into()), home.clone()),
+            Some(PathBuf::from("/usr/local"))
+        );
+        assert_eq!(
+            super::prefix_of(Some("relative/bin".into()), home.clone()),
+            Some(PathBuf::from("/home/alice/.local"))
+        );
+        assert_eq!(
+            super::prefix_of(None, home),
+            Some(PathBuf::from("/home/alice/.local"))
+        );
+    }
+
+    #[test]
+    fn test_user_application_shared_dir() {
+        test_dir_is_none(crate::user_application_shared_dir());
+    }
+
+    #[test]
+    fn test_sandbox_container_dir_for() {
+        assert!(crate::sandbox_container_id().is_none());
+        assert!(crate::sandbox_container_dir_for("com.example.App").is_none());
+    }
+
+    #[test]
+    fn test_linux_sandbox() {
+        if crate::flatpak_id().is_none() && crate::snap_name().is_none() {
+            assert!(crate::sandboxed_app_dir().is_none());
+            assert!(crate::snap_user_common_dir().is_none());
+            assert_eq!(crate::host_home_dir(), home_dir());
+        }
+    }
+
+    #[test]
+    fn test_info_name() {
+        assert_eq!(
+            super::info_name("[Application]\nname=org.example.App\nruntime=x\n[Instance]\n"),
+            Some(String::from("org.example.App"))
+        );
+        assert_eq!(super::info_name("[Instance]\nname=other\n"), None);
+    }
+
+    #[test]
+    fn test_package_dir_for() {
+        assert!(crate::package_family_name().is_none());
+        assert!(crate::package_dir_for("Example.App_8wekyb3d8bbwe").is_none());
+    }
+
+    #[test]
+    fn test_group_container_dir_for() {
+        assert!(crate::group_container_dir_for("group.com.example.App").is_none());
+    }
+
+    #[test]
+    fn test_crash_reports_dir() {
+        assert!(crate::crash_reports_dir().is_none());
+        assert!(crate::system_crash_reports_dir().is_none());
+        assert!(crate::crash_reports_for("Chrome").is_empty());
+    }
+
+    #[test]
+    fn test_crash_dumps_dir_for() {
+        assert_eq!(
+            crate::crash_dumps_dir_for("Chrome"),
+            crate::xdg_base(crate::BaseKind::StateHome)
+                .value
+                .map(|path| path.join("Chrome/crashes"))
+        );
+    }
+
+    #[test]
+    fn test_icloud_dirs() {
+        assert!(crate::icloud_drive_dir().is_none());
+        assert!(crate::icloud_container_dir_for("iCloud.com.example.App").is_none());
+    }
+
+    #[test]
+    fn test_application_scripts_dir_for() {
+        assert!(crate::application_scripts_dir_for("com.example.App").is_none());
+    }
+
+    #[test]
+    fn test_launch_agents_dir() {
+        assert!(crate::launch_agents_dir().is_none());
+        assert!(crate::system_launch_agents_dir().is_none());
+        assert!(crate::launch_daemons_dir().is_none());
+    }
+
+    #[test]
+    fn test_autostart_dir() {
+        assert_eq!(
+            crate::autostart_dir(),
+            crate::config_dir().map(|path| path.join("autostart"))
+        );
+    }
+
+    #[test]
+    fn test_font_dir() {
+        let font_dir = crate::font_dir().unwrap();
+        assert!(
+            font_dir.ends_with(".local/share/fonts") || font_dir.ends_with(".fonts"),
+            "{:?}",
+            font_dir
+        );
+        assert_eq!(
+            crate::system_font_dir(),
+            Some(PathBuf::from("/usr/local/share/fonts"))
+        );
+    }
+
+    #[test]
+    fn test_man_dir() {
+        test_user_dir(crate::man_dir().unwrap(), ".local/share/man");
+        assert_eq!(
+            crate::system_man_dir(),
+            Some(PathBuf::from("/usr/local/share/man"))
+        );
+    }
+
+    #[test]
+    fn test_desktop_entry_dir() {
+        test_user_dir(
+            crate::desktop_entry_dir().unwrap(),
+            ".local/share/applications",
+        );
+        assert_eq!(
+            crate::system_desktop_entry_dir(),
+            Some(PathBuf::from(super::D_SYSTEM_DATA).join("applications"))
+        );
+    }
+
+    #[test]
+    fn test_mime_dir() {
+        test_user_dir(crate::mime_dir().unwrap(), ".local/share/mime");
+        assert_eq!(
+            crate::system_mime_dir(),
+            Some(PathBuf::from(super::D_SYSTEM_DATA).join("mime"))
+        );
+    }
+
+    #[test]
+    fn test_glib_schemas_dir() {
+        test_user_dir(
+            crate::glib_schemas_dir().unwrap(),
+            ".local/share/glib-2.0/schemas",
+        );
+        assert_eq!(
+            crate::system_glib_schemas_dir(),
+            Some(PathBuf::from(super::D_SYSTEM_DATA).join("glib-2.0/schemas"))
+        );
+        test_user_dir(
+            crate::keyfile_backend_dir().unwrap(),
+            ".config/glib-2.0/settings",
+        );
+        assert_eq!(crate::DirKind::GlibSchemas.dir(), crate::glib_schemas_dir());
+    }
+
+    #[test]
+    fn test_public_data_dir() {
+        assert_eq!(
+            crate::public_data_dir_for("Chrome"),
+            Some(PathBuf::from(super::D_SYSTEM_DATA).join("Chrome"))
+        );
+        assert!(crate::shared_documents_dir_for("Chrome").is_none());
+    }
+
+    #[test]
+    fn test_temp_dir_for() {
+        assert_eq!(crate::temp_dir(), Some(std::env::temp_dir()));
+        let path = crate::temp_dir_for("Chrome").unwrap();
+        assert_eq!(path.parent(), Some(std::env::temp_dir().as_path()));
+        assert_eq!(
+            path.file_name().unwrap().to_string_lossy(),
+            format!("Chrome-{}", super::user_id().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_socket_dir_for() {
+        let expected = match std::env::var_os("XDG_RUNTIME_DIR") {
+            Some(runtime) if !runtime.is_empty() => Some(PathBuf::from(runtime).join("Chrome")),
+            _ => crate::temp_dir_for("Chrome"),
+        };
+        assert_eq!(crate::socket_dir_for("Chrome"), expected);
+        assert_eq!(crate::pipe_name_for("Chrome"), None);
+    }
+
+    #[test]
+    fn test_lock_dir_for() {
+        let lock_dir = crate::socket_dir_for("Chrome");
+        assert_eq!(crate::lock_dir_for("Chrome"), lock_dir);
+        assert_eq!(
+            crate::pid_file_for("Chrome"),
+            lock_dir.map(|path| path.join("Chrome.pid"))
+        );
+    }
+
+    #[test]
+    fn test_document_and_download_dir_for() {
+        assert_eq!(
+            crate::document_dir_for("Chrome"),
+            crate::document_dir().map(|path| path.join("Chrome"))
+        );
+        assert_eq!(
+            crate::download_dir_for("Chrome"),
+            crate::download_dir().map(|path| path.join("Chrome"))
+        );
+    }
+
+    #[test]
+    fn test_desktop_and_public_dir_for() {
+        assert_eq!(
+            crate::desktop_dir_for("Chrome"),
+            crate::desktop_dir().map(|path| path.join("Chrome"))
+        );
+        assert_eq!(
+            crate::public_share_dir_for("Chrome"),
+            crate::public_dir().map(|path| path.join("Chrome"))
+        );
+    }
+
+    #[test]
+    fn test_recent_and_history_dir() {
+        assert_eq!(crate::recent_dir(), crate::data_dir());
+        assert_eq!(
+            crate::history_dir_for("Chrome"),
+            crate::xdg_base(crate::BaseKind::StateHome)
+                .value
+                .map(|path| path.join("Chrome").join("history"))
+        );
+    }
+
+    #[test]
+    fn test_backup_dir_for() {
+        assert_eq!(
+            crate::backup_dir_for("Chrome"),
+            crate::state_dir_for("Chrome").map(|path| path.join("backups"))
+        );
+    }
+
+    #[test]
+    fn test_data_dirs_for() {
+        test_user_dir(
+            crate::locale_dir_for("Chrome").unwrap(),
+            ".local/share/Chrome/locale",
+        );
+        test_user_dir(
+            crate::plugins_dir_for("Chrome").unwrap(),
+            ".local/share/Chrome/plugins",
+        );
+        let mut env = crate::environment::EnvGuard::lock();
+        env.set("XDG_DATA_DIRS", "relative:/opt/share:/usr/share");
+        crate::reload_env();
+        assert_eq!(
+            crate::system_plugins_dir_for("Chrome"),
+            Some(PathBuf::from("/opt/share/Chrome/plugins"))
+        );
+        assert_eq!(
+            crate::plugin_search_dirs_for("Chrome"),
+            vec![
+                crate::plugins_dir_for("Chrome").unwrap(),
+                PathBuf::from("/opt/share/Chrome/plugins"),
+                PathBuf::from("/usr/share/Chrome/plugins"),
+            ]
+        );
+        assert_eq!(
+            crate::locale_search_dirs_for("Chrome"),
+            vec![
+                crate::locale_dir_for("Chrome").unwrap(),
+                PathBuf::from("/opt/share/locale"),
+                PathBuf::from("/usr/share/locale"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trash_dir() {
+        test_user_dir(crate::trash_dir().unwrap(), ".local/share/Trash");
+    }
+
+    #[test]
+    fn test_system_config_dir() {
+        let mut env = crate::environment::EnvGuard::lock();
+        env.set("XDG_CONFIG_DIRS", "relative:/opt/xdg:/etc/xdg");
+        crate::reload_env();
+        assert_eq!(super::system_config_dir(), PathBuf::from("/opt/xdg"));
+        assert_eq!(
+            crate::config_search_dirs_for("Chrome"),
+            vec![
+                crate::config_dir_for("Chrome").unwrap(),
+                PathBuf::from("/opt/xdg/Chrome"),
+                PathBuf::from("/etc/xdg/Chrome"),
+            ]
+        );
+        env.remove("XDG_CONFIG_DIRS");
+        crate::reload_env();
+        assert_eq!(
+            super::system_config_dir(),
+            PathBuf::from(super::D_SYSTEM_CONFIG)
+        );
+    }
+
+    #[test]
+    fn test_system_data_dirs() {
+        let mut env = crate::environment::EnvGuard::lock();
+        env.remove("XDG_DATA_DIRS");
+        crate::reload_env();
+        assert_eq!(
+            crate::system_data_dirs(),
+            super::D_SYSTEM_DATA_DIRS
+                .iter()
+                .map(PathBuf::from)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            crate::system_data_dirs()[0],
+            PathBuf::from("/usr/local/share")
+        );
     }
 
     // --------------------------------------------------------------------------------------------
 
     #[test]
     fn test_app_container_dir_for() {
-        test_dir_is_none(crate::app_container_dir_for("Chrome"));
+        assert_eq!(
+            crate::app_container_dir_for("NoSuchApp"),
+            Some(PathBuf::from("/opt/NoSuchApp"))
+        );
+        test_dir_is_none(crate::app_container_resources_dir_for("NoSuchApp"));
     }
 
     #[test]
     fn test_app_container_executable_dir_for() {
-        test_dir_is_none(crate::app_container_executable_dir_for("Chrome"));
+        assert_eq!(
+            crate::app_container_executable_dir_for("NoSuchApp"),
+            Some(PathBuf::from("/opt/NoSuchApp/bin"))
+        );
+    }
+
+    #[test]
+    fn test_installed_app_container_dir_for() {
+        let root = std::env::temp_dir().join(format!("xdirs-installed-{}", std::process::id()));
+        let container = PathBuf::from("/usr/lib/xdirs-installed");
+        std::fs::create_dir_all(root.join("usr/lib/xdirs-installed/bin")).unwrap();
+        {
+            let _guard = crate::override_root(&root);
+            assert_eq!(
+                crate::app_container_dir_for("xdirs-installed"),
+                Some(root.join("usr/lib/xdirs-installed"))
+            );
+            assert_eq!(
+                super::installed_container_dir_for(
+                    crate::DirKind::AppContainerExecutable,
+                    "xdirs-installed"
+                ),
+                Some(container.join("bin"))
+            );
+        }
+        assert_eq!(
+            crate::app_container_dir_for("xdirs-installed"),
+            Some(PathBuf::from("/opt/xdirs-installed"))
+        );
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_executable_dir_of() {
+        assert_eq!(
+            super::executable_dir_of(std::path::Path::new("/usr/lib/NoSuchApp")),
+            PathBuf::from("/usr/bin")
+        );
+        assert_eq!(
+            super::executable_dir_of(std::path::Path::new("/opt/NoSuchApp")),
+            PathBuf::from("/opt/NoSuchApp")
+        );
     }
 
     #[test]
     fn test_user_app_container_dir_for() {
-        test_dir_is_none(crate::user_app_container_dir_for("Chrome"));
+        test_user_dir(
+            crate::user_app_container_dir_for("NoSuchApp").unwrap(),
+            ".local/opt/NoSuchApp",
+        );
     }
 
     #[test]
     fn test_user_app_container_executable_dir_for() {
-        test_dir_is_none(crate::user_app_container_executable_dir_for("Chrome"));
+        test_user_dir(
+            crate::user_app_container_executable_dir_for("NoSuchApp").unwrap(),
+            ".local/opt/NoSuchApp/bin",
+        );
     }
 
     // --------------------------------------------------------------------------------------------
@@ -176,6 +962,28 @@ mod tests {
         test_user_dir(crate::config_dir_for("Chrome").unwrap(), ".config/Chrome");
     }
 
+    #[test]
+    fn test_config_local_dir() {
+        test_user_dir(crate::config_local_dir().unwrap(), ".config");
+    }
+
+    #[test]
+    fn test_config_local_dir_for() {
+        test_user_dir(
+            crate::config_local_dir_for("Chrome").unwrap(),
+            ".config/Chrome",
+        );
+    }
+
+    #[test]
+    fn test_state_dir_for() {
+        test_user_dir(crate::state_dir().unwrap(), ".local/state");
+        test_user_dir(
+            crate::state_dir_for("Chrome").unwrap(),
+            ".local/state/Chrome",
+        );
+    }
+
     #[test]
     fn test_data_dir() {
         test_user_dir(crate::data_dir().unwrap(), ".local/share");
@@ -204,17 +1012,32 @@ mod tests {
 
     #[test]
     fn test_favorites_dir() {
-        test_dir_is_none(crate::favorites_dir());
+        test_user_dir(crate::favorites_dir().unwrap(), ".local/share/favorites");
     }
 
     #[test]
     fn test_favorites_dir_for() {
         test_user_dir(
             crate::favorites_dir_for("Chrome").unwrap(),
-            ".local/share/Chrome/favorites",
+            ".local/share/favorites/Chrome",
         );
     }
 
+    #[test]
+    fn test_legacy_favorites_dir_for() {
+        let root = std::env::temp_dir().join(format!("xdirs-favorites-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let legacy = crate::data_local_dir_for("Chrome")
+            .unwrap()
+            .join("favorites");
+        std::fs::create_dir_all(&legacy).unwrap();
+        assert_eq!(crate::favorites_dir_for("Chrome"), Some(legacy));
+        let current = crate::favorites_dir().unwrap().join("Chrome");
+        std::fs::create_dir_all(&current).unwrap();
+        assert_eq!(crate::favorites_dir_for("Chrome"), Some(current));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
     #[test]
     fn test_log_dir() {
         test_dir_is_none(crate::log_dir());
@@ -228,6 +1051,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_system_log_dir() {
+        assert_eq!(crate::system_log_dir(), Some(PathBuf::from("/var/log")));
+    }
+
+    #[test]
+    fn test_system_log_dir_for() {
+        assert_eq!(
+            crate::system_log_dir_for("Chrome"),
+            Some(PathBuf::from("/var/log/Chrome"))
+        );
+    }
+
     #[test]
     fn test_preference_dir() {
         test_user_dir(crate::preference_dir().unwrap(), ".config");
@@ -253,4 +1089,64 @@ mod tests {
             ".config/Chrome/templates",
         );
     }
+
+    #[test]
+    fn test_system_template_dir_for() {
+        assert_eq!(
+            crate::system_template_dir_for("Chrome"),
+            Some(PathBuf::from(super::D_SYSTEM_DATA).join("templates/Chrome"))
+        );
+    }
+
+    #[test]
+    fn test_saved_games_dir() {
+        test_dir_is_none(crate::saved_games_dir());
+    }
+
+    #[test]
+    fn test_saved_games_dir_for() {
+        test_user_dir(
+            crate::saved_games_dir_for("Chrome").unwrap(),
+            ".local/share/Chrome/saves",
+        );
+    }
+
+    #[test]
+    fn test_icons_dir_for() {
+        test_user_dir(crate::icons_dir().unwrap(), ".local/share/icons");
+        test_user_dir(
+            crate::icons_dir_for("Chrome").unwrap(),
+            ".local/share/icons",
+        );
+    }
+
+    #[test]
+    fn test_themes_dir_for() {
+        test_user_dir(crate::themes_dir().unwrap(), ".local/share/themes");
+        test_user_dir(
+            crate::themes_dir_for("Chrome").unwrap(),
+            ".local/share/themes/Chrome",
+        );
+    }
+
+    #[test]
+    fn test_service_dirs_for() {
+        let dirs = crate::service_dirs_for("Chrome");
+        assert_eq!(dirs.config, Some(PathBuf::from("/etc/Chrome")));
+        assert_eq!(dirs.data, Some(PathBuf::from("/var/lib/Chrome")));
+        assert_eq!(dirs.cache, Some(PathBuf::from("/var/cache/Chrome")));
+        #[cfg(target_os = "linux")]
+        assert_eq!(dirs.runtime, Some(PathBuf::from("/run/Chrome")));
+        assert_eq!(dirs.log, Some(PathBuf::from("/var/log/Chrome")));
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn test_minidump_path_for() {
+        test_user_dir(
+            crate::minidump_path_for("Chrome").unwrap(),
+            ".local/share/Chrome/crashes",
+        );
+    }
 }
@@ -0,0 +1,178 @@
+/*!
+Provides scoped, programmatic, redirection of all resolved directories beneath a caller-supplied
+root, so that tests can simulate directory layouts without modifying `HOME` or `XDG_*` variables
+for the whole process.
+*/
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::path::{Component, Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A guard, returned by [`override_root`](fn.override_root.html), which restores the previous
+/// root when dropped. The guard applies only to the thread that created it, and so cannot be
+/// sent to another thread.
+///
+#[derive(Debug)]
+#[must_use = "the override is removed when the guard is dropped"]
+pub struct RootOverride {
+    previous: Option<PathBuf>,
+    not_send: PhantomData<*const ()>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Redirect every directory resolved on the current thread beneath `root`, until the returned
+/// guard is dropped. The resolved path is re-rooted by removing any prefix, such as a drive
+/// letter, and root directory, and appending the remainder to `root`; so with a root of
+/// `/tmp/test` the value `/home/alice/.config/MyApp` becomes `/tmp/test/home/alice/.config/MyApp`.
+///
+/// Overrides may be nested, the innermost applies. As the override is per-thread, it does not
+/// apply to directories resolved by [`warm`](fn.warm.html) on its background thread; however
+/// values cached by `warm` are re-rooted when they are returned.
+///
+/// ```rust
+/// use xdirs::{config_dir_for, override_root};
+///
+/// let root = std::env::temp_dir().join("my-test");
+/// {
+///     let _guard = override_root(&root);
+///     assert!(config_dir_for("MyApp").unwrap().starts_with(&root));
+/// }
+/// assert!(!config_dir_for("MyApp").unwrap().starts_with(&root));
+/// ```
+///
+pub fn override_root<P: AsRef<Path>>(root: P) -> RootOverride {
+    let previous = ROOT.with(|current| current.replace(Some(root.as_ref().to_path_buf())));
+    RootOverride {
+        previous,
+        not_send: PhantomData,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Re-root `path` beneath the current thread's override, if any.
+///
+pub(crate) fn apply(path: Option<PathBuf>) -> Option<PathBuf> {
+    match (current(), path) {
+        (Some(root), Some(path)) => Some(reroot(&root, &path)),
+        (_, path) => path,
+    }
+}
+
+///
+/// Returns the current thread's override, if any.
+///
+pub(crate) fn current() -> Option<PathBuf> {
+    ROOT.with(|current| current.borrow().clone())
+}
+
+///
+/// Returns `true` if `path`, re-rooted beneath the current thread's override, if any, is a
+/// directory; platforms that search the file system use this so that the search sees the same
+/// layout as the directories it returns.
+///
+pub(crate) fn is_dir(path: &Path) -> bool {
+    match current() {
+        Some(root) => reroot(&root, path).is_dir(),
+        None => path.is_dir(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Drop for RootOverride {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ROOT.with(|current| {
+            let _ = current.replace(previous);
+        });
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+thread_local! {
+    static ROOT: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+fn reroot(root: &Path, path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::Prefix(_) | Component::RootDir))
+        .fold(root.to_path_buf(), |result, component| {
+            result.join(component)
+        })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_override_root() {
+        let root = std::env::temp_dir().join("xdirs-root");
+        let inner = std::env::temp_dir().join("xdirs-inner");
+        let original = crate::cache_dir_for("Chrome").unwrap();
+        {
+            let _guard = override_root(&root);
+            let redirected = crate::cache_dir_for("Chrome").unwrap();
+            assert_eq!(redirected, reroot(&root, &original));
+            assert!(redirected.starts_with(&root));
+            {
+                let _guard = override_root(&inner);
+                assert!(crate::cache_dir_for("Chrome").unwrap().starts_with(&inner));
+            }
+            assert!(crate::DirKind::Cache.dir().unwrap().starts_with(&root));
+        }
+        assert_eq!(crate::cache_dir_for("Chrome").unwrap(), original);
+    }
+
+    #[test]
+    fn test_other_threads_unaffected() {
+        let root = std::env::temp_dir().join("xdirs-root-threads");
+        let _guard = override_root(&root);
+        let other = std::thread::spawn(|| crate::config_dir_for("Chrome"))
+            .join()
+            .unwrap();
+        assert!(!other.unwrap().starts_with(&root));
+    }
+
+    #[test]
+    fn test_is_dir() {
+        let root = std::env::temp_dir().join(format!("xdirs-root-is-dir-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("fixture")).unwrap();
+        assert!(!is_dir(Path::new("/fixture")));
+        {
+            let _guard = override_root(&root);
+            assert!(is_dir(Path::new("/fixture")));
+        }
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_reroot() {
+        assert_eq!(
+            reroot(Path::new("/tmp/test"), Path::new("/home/alice/.config")),
+            PathBuf::from("/tmp/test/home/alice/.config")
+        );
+    }
+}
@@ -0,0 +1,96 @@
+/*!
+Provides lookups in the system's user database, by way of the reentrant `getpw*_r` functions, so
+that directories in `/etc/passwd`, or in a directory service configured with NSS, are found
+without reading the environment.
+*/
+
+use std::ffi::{CStr, CString, OsString};
+use std::os::unix::ffi::OsStringExt;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the home directory recorded in the user database for the real user of the process,
+/// as `dirs-next` does where `$HOME` is not set.
+///
+pub(crate) fn current_user_home() -> Option<PathBuf> {
+    let uid = unsafe { libc::getuid() };
+    lookup_home(|entry, buffer, size, result| unsafe {
+        libc::getpwuid_r(uid, entry, buffer, size, result)
+    })
+}
+
+///
+/// Returns the home directory recorded in the user database for the user named `user`.
+///
+pub(crate) fn home_dir_for_name(user: &str) -> Option<PathBuf> {
+    let name = CString::new(user).ok()?;
+    lookup_home(|entry, buffer, size, result| unsafe {
+        libc::getpwnam_r(name.as_ptr(), entry, buffer, size, result)
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const INITIAL_BUFFER: usize = 512;
+const MAX_BUFFER: usize = 1 << 20;
+
+///
+/// Returns the absolute home directory of the entry found by `getpw`, which is called as a
+/// `getpw*_r` function with a buffer that is grown while it reports `ERANGE`.
+///
+fn lookup_home<F>(mut getpw: F) -> Option<PathBuf>
+where
+    F: FnMut(*mut libc::passwd, *mut libc::c_char, libc::size_t, *mut *mut libc::passwd) -> i32,
+{
+    let mut size = match unsafe { libc::sysconf(libc::_SC_GETPW_R_SIZE_MAX) } {
+        n if n > 0 => n as usize,
+        _ => INITIAL_BUFFER,
+    };
+    loop {
+        let mut buffer: Vec<libc::c_char> = vec![0; size];
+        let mut entry: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result: *mut libc::passwd = std::ptr::null_mut();
+        match getpw(&mut entry, buffer.as_mut_ptr(), buffer.len(), &mut result) {
+            0 if !result.is_null() && !entry.pw_dir.is_null() => {
+                let bytes = unsafe { CStr::from_ptr(entry.pw_dir) }.to_bytes();
+                return Some(PathBuf::from(OsString::from_vec(bytes.to_vec())))
+                    .filter(|home| home.is_absolute());
+            }
+            libc::ERANGE if size < MAX_BUFFER => size *= 2,
+            _ => return None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::EnvGuard;
+
+    #[test]
+    fn test_current_user_home() {
+        // without $HOME, dirs-next makes the same lookup.
+        let mut env = EnvGuard::lock();
+        env.remove("HOME");
+        assert_eq!(current_user_home(), dirs_next::home_dir());
+    }
+
+    #[test]
+    fn test_home_dir_for_name() {
+        if cfg!(target_os = "linux") {
+            assert_eq!(home_dir_for_name("root"), Some(PathBuf::from("/root")));
+        }
+        assert_eq!(home_dir_for_name("xdirs-no-such-user"), None);
+        assert_eq!(home_dir_for_name("nul\0byte"), None);
+    }
+}
@@ -0,0 +1,116 @@
+/*!
+Provides directories scoped to one of an application's named profiles, such as those of a web
+browser, laid out the same way beneath the application's directories on every platform.
+*/
+
+use crate::{sanitize_app_name, DirKind, Platform};
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the application-specific form of the directory `kind` for the named `profile` of
+/// `app`. The profile's directory is nested beneath the application's directory, as returned by
+/// [`DirKind::dir_for`](enum.DirKind.html#method.dir_for), and the profile name is made safe by
+/// [`sanitize_app_name`](fn.sanitize_app_name.html).
+///
+/// |Platform | Value                              | Example                                     |
+/// | ------- | ---------------------------------- | ------------------------------------------- |
+/// | Linux   | {kind_dir_for}/profiles/{profile}  | /home/alice/.config/MyApp/profiles/work     |
+/// | macOS   | {kind_dir_for}/Profiles/{profile}  | /Users/Alice/Library/Application Support/MyApp/Profiles/work |
+/// | Windows | {kind_dir_for}\Profiles\{profile}  | C:\Users\Alice\AppData\Roaming\MyApp\Profiles\work |
+///
+pub fn dir_for_profile(kind: DirKind, app: &str, profile: &str) -> Option<PathBuf> {
+    kind.dir_for(app)
+        .map(|app_dir| profile_dir(Platform::current(), &app_dir, profile))
+}
+
+///
+/// Returns the path to the config directory for the named `profile` of `app`; see
+/// [`dir_for_profile`](fn.dir_for_profile.html).
+///
+/// ```rust
+/// use xdirs::config_dir_for_profile;
+///
+/// if let Some(path) = config_dir_for_profile("MyApp", "work") {
+///     assert!(path.ends_with("work"));
+/// }
+/// ```
+///
+pub fn config_dir_for_profile(app: &str, profile: &str) -> Option<PathBuf> {
+    dir_for_profile(DirKind::Config, app, profile)
+}
+
+///
+/// Returns the path to the data directory for the named `profile` of `app`; see
+/// [`dir_for_profile`](fn.dir_for_profile.html).
+///
+pub fn data_dir_for_profile(app: &str, profile: &str) -> Option<PathBuf> {
+    dir_for_profile(DirKind::Data, app, profile)
+}
+
+///
+/// Returns the path to the cache directory for the named `profile` of `app`; see
+/// [`dir_for_profile`](fn.dir_for_profile.html).
+///
+pub fn cache_dir_for_profile(app: &str, profile: &str) -> Option<PathBuf> {
+    dir_for_profile(DirKind::Cache, app, profile)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const D_PROFILES_LINUX: &str = "profiles";
+
+const D_PROFILES: &str = "Profiles";
+
+fn profile_dir(platform: Platform, app_dir: &Path, profile: &str) -> PathBuf {
+    let parent = match platform {
+        Platform::Linux => D_PROFILES_LINUX,
+        Platform::MacOS | Platform::Windows => D_PROFILES,
+    };
+    app_dir
+        .join(parent)
+        .join(sanitize_app_name(profile).as_ref())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_dir() {
+        let app_dir = Path::new("/base/Chrome");
+        assert_eq!(
+            profile_dir(Platform::Linux, app_dir, "work"),
+            PathBuf::from("/base/Chrome/profiles/work")
+        );
+        assert_eq!(
+            profile_dir(Platform::Windows, app_dir, "../work"),
+            PathBuf::from("/base/Chrome/Profiles/.._work")
+        );
+    }
+
+    #[test]
+    fn test_config_dir_for_profile() {
+        assert_eq!(
+            config_dir_for_profile("Chrome", "work"),
+            crate::config_dir_for("Chrome").map(|path| profile_dir(
+                Platform::current(),
+                &path,
+                "work"
+            ))
+        );
+        assert_eq!(
+            data_dir_for_profile("Chrome", "work").map(|path| path.ends_with("work")),
+            crate::data_dir_for("Chrome").map(|_| true)
+        );
+    }
+}
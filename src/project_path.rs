@@ -0,0 +1,266 @@
+/*!
+Provides `ProjectPath`, a thin wrapper around an application's directory with helpers for the
+common operations on it, so that the policy for joining names and creating directories is kept
+in this crate rather than repeated at each call site.
+*/
+
+use crate::DirKind;
+use std::io;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An application's directory, or a subdirectory of it, as returned by
+/// [`path_for`](fn.path_for.html) and the `_path_for` functions. This dereferences to `Path`, and
+/// so may be used wherever a path is expected.
+///
+/// ```rust
+/// use xdirs::config_path_for;
+///
+/// if let Some(config) = config_path_for("MyApp") {
+///     let settings = config.file("settings.toml").unwrap();
+///     let plugins = config.subdir("plugins").unwrap();
+///     assert!(settings.starts_with(&config));
+///     assert!(plugins.ends_with("plugins"));
+///     assert!(config.file("../settings.toml").is_err());
+/// }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProjectPath {
+    path: PathBuf,
+    kind: Option<DirKind>,
+    private_root: Option<PathBuf>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the application-specific form of the directory `kind`, as returned by
+/// [`DirKind::dir_for`](enum.DirKind.html#method.dir_for), as a `ProjectPath`.
+///
+pub fn path_for(kind: DirKind, app: &str) -> Option<ProjectPath> {
+    kind.dir_for(app).map(|path| ProjectPath {
+        private_root: Some(path.clone()).filter(|_| kind.is_private()),
+        path,
+        kind: Some(kind),
+    })
+}
+
+///
+/// Returns the path to the user's cache directory for a specific application as a `ProjectPath`.
+/// See [`cache_dir_for`](fn.cache_dir_for.html).
+///
+pub fn cache_path_for(app: &str) -> Option<ProjectPath> {
+    path_for(DirKind::Cache, app)
+}
+
+///
+/// Returns the path to the user's config directory for a specific application as a
+/// `ProjectPath`. See [`config_dir_for`](fn.config_dir_for.html).
+///
+pub fn config_path_for(app: &str) -> Option<ProjectPath> {
+    path_for(DirKind::Config, app)
+}
+
+///
+/// Returns the path to the user's local config directory for a specific application as a
+/// `ProjectPath`. See [`config_local_dir_for`](fn.config_local_dir_for.html).
+///
+pub fn config_local_path_for(app: &str) -> Option<ProjectPath> {
+    path_for(DirKind::ConfigLocal, app)
+}
+
+///
+/// Returns the path to the user's data directory for a specific application as a `ProjectPath`.
+/// See [`data_dir_for`](fn.data_dir_for.html).
+///
+pub fn data_path_for(app: &str) -> Option<ProjectPath> {
+    path_for(DirKind::Data, app)
+}
+
+///
+/// Returns the path to the user's local data directory for a specific application as a
+/// `ProjectPath`. See [`data_local_dir_for`](fn.data_local_dir_for.html).
+///
+pub fn data_local_path_for(app: &str) -> Option<ProjectPath> {
+    path_for(DirKind::DataLocal, app)
+}
+
+///
+/// Returns the path to the user's log directory for a specific application as a `ProjectPath`.
+/// See [`log_dir_for`](fn.log_dir_for.html).
+///
+pub fn log_path_for(app: &str) -> Option<ProjectPath> {
+    path_for(DirKind::Log, app)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ProjectPath {
+    ///
+    /// Returns the kind of the application directory this is, or is beneath; this is `None` for a
+    /// path constructed from a `PathBuf`.
+    ///
+    pub fn kind(&self) -> Option<DirKind> {
+        self.kind
+    }
+
+    ///
+    /// Returns the path of the file `name` in this directory. The name is used unchanged, with
+    /// any extension, but must be a single component of this directory; a name that is empty,
+    /// `.` or `..`, or contains a path separator or NUL, is rejected with an error of the kind
+    /// `InvalidInput`.
+    ///
+    pub fn file(&self, name: &str) -> io::Result<PathBuf> {
+        check_name(name)?;
+        Ok(self.path.join(name))
+    }
+
+    ///
+    /// Returns the subdirectory `name` of this directory, which is checked as for
+    /// [`file`](#method.file).
+    ///
+    pub fn subdir(&self, name: &str) -> io::Result<ProjectPath> {
+        Ok(ProjectPath {
+            path: self.file(name)?,
+            kind: self.kind,
+            private_root: self.private_root.clone(),
+        })
+    }
+
+    ///
+    /// Returns `true` if this directory exists.
+    ///
+    pub fn exists(&self) -> bool {
+        self.path.is_dir()
+    }
+
+    ///
+    /// Create this directory, and any missing parents, if it does not already exist. As for
+    /// [`create_dir_for`](fn.create_dir_for.html), a private kind, such as the cache directory, is
+    /// created such that it is only accessible by the current user, including when it is created
+    /// as the parent of one of its subdirectories.
+    ///
+    #[cfg(feature = "create")]
+    pub fn ensure(&self) -> io::Result<&Self> {
+        crate::create::ensure_project_path(&self.path, self.kind, self.private_root.as_deref())?;
+        Ok(self)
+    }
+
+    ///
+    /// Returns the path, consuming this value.
+    ///
+    pub fn into_path_buf(self) -> PathBuf {
+        self.path
+    }
+}
+
+impl Deref for ProjectPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for ProjectPath {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl From<PathBuf> for ProjectPath {
+    fn from(path: PathBuf) -> Self {
+        Self {
+            path,
+            kind: None,
+            private_root: None,
+        }
+    }
+}
+
+impl From<ProjectPath> for PathBuf {
+    fn from(path: ProjectPath) -> Self {
+        path.path
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns an error unless `name` is a single component of a directory.
+///
+fn check_name(name: &str) -> io::Result<()> {
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\', '\0']) {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{:?} is not a file name", name),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_helpers() {
+        let config = config_path_for("Chrome").unwrap();
+        assert_eq!(Some(config.to_path_buf()), crate::config_dir_for("Chrome"));
+        assert_eq!(config.kind(), Some(DirKind::Config));
+        assert_eq!(
+            config.file("settings.toml").unwrap(),
+            config.join("settings.toml")
+        );
+        assert_eq!(config.file("nul.txt").unwrap(), config.join("nul.txt"));
+        for name in ["", ".", "..", "../escape", "a/b", "a\\b", "nul\0byte"] {
+            assert_eq!(
+                config.file(name).unwrap_err().kind(),
+                io::ErrorKind::InvalidInput
+            );
+        }
+        let plugins = config.subdir("plugins").unwrap();
+        assert_eq!(plugins.kind(), Some(DirKind::Config));
+        assert_eq!(PathBuf::from(plugins), config.join("plugins"));
+    }
+
+    #[cfg(feature = "create")]
+    #[test]
+    fn test_ensure() {
+        let root = std::env::temp_dir().join(format!("xdirs-project-{}", std::process::id()));
+        let path = ProjectPath {
+            path: root.join("cache").join("sub"),
+            kind: Some(DirKind::Cache),
+            private_root: Some(root.join("cache")),
+        };
+        assert!(!path.exists());
+        assert!(path.ensure().unwrap().exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(root.join("cache"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o700);
+        }
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,584 @@
+/*!
+Provides the `DirectoryProvider` trait, which separates the source of the generic, base,
+directories from the platform's rules for laying out an application's directories beneath them.
+*/
+
+use crate::{sys, DirKind, Platform};
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A source of the generic directories from which all application-specific directories are
+/// derived. Implementations need only supply the generic directories, the provided methods apply
+/// the current platform's layout rules to them.
+///
+/// The functions at the crate root use the [`PlatformProvider`](struct.PlatformProvider.html),
+/// which asks the operating system. Any other implementation may be used to compute the same
+/// application-specific directories against a different set of base directories; for example, to
+/// mock the platform in unit tests, or to resolve directories within a chroot-style sandbox.
+///
+/// ```rust
+/// use std::path::PathBuf;
+/// use xdirs::{DirKind, DirectoryProvider};
+///
+/// struct Sandbox(PathBuf);
+///
+/// impl DirectoryProvider for Sandbox {
+///     fn home_dir(&self) -> Option<PathBuf> { Some(self.0.join("home")) }
+///     fn application_dir(&self) -> Option<PathBuf> { None }
+///     fn application_shared_dir(&self) -> Option<PathBuf> { None }
+///     fn user_application_dir(&self) -> Option<PathBuf> { None }
+///     fn cache_dir(&self) -> Option<PathBuf> { Some(self.0.join("cache")) }
+///     fn config_dir(&self) -> Option<PathBuf> { Some(self.0.join("config")) }
+///     fn data_dir(&self) -> Option<PathBuf> { Some(self.0.join("data")) }
+///     fn data_local_dir(&self) -> Option<PathBuf> { Some(self.0.join("data")) }
+///     fn favorites_dir(&self) -> Option<PathBuf> { None }
+///     fn log_dir(&self) -> Option<PathBuf> { Some(self.0.join("log")) }
+///     fn preference_dir(&self) -> Option<PathBuf> { Some(self.0.join("config")) }
+///     fn template_dir(&self) -> Option<PathBuf> { None }
+/// }
+///
+/// let sandbox = Sandbox(PathBuf::from("/sandbox"));
+/// assert!(sandbox
+///     .dir_for(DirKind::Config, "MyApp")
+///     .unwrap()
+///     .starts_with("/sandbox/config"));
+/// ```
+///
+/// Note that environment overrides, values cached by [`warm`](fn.warm.html), and any
+/// [`override_root`](fn.override_root.html) in effect apply only to the crate root functions, and
+/// not to a provider used directly.
+///
+pub trait DirectoryProvider {
+    ///
+    /// Returns the path to the user's home directory.
+    ///
+    fn home_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the directory applications are installed in.
+    ///
+    fn application_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the directory shared application components are installed in.
+    ///
+    fn application_shared_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the directory applications installed for the user only are installed
+    /// in.
+    ///
+    fn user_application_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the directory shared application components installed for the user
+    /// only are installed in; by default there is none.
+    ///
+    fn user_application_shared_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's cache directory.
+    ///
+    fn cache_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the user's configuration directory.
+    ///
+    fn config_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the user's local, non-roaming, configuration directory; by default
+    /// this is the configuration directory.
+    ///
+    fn config_local_dir(&self) -> Option<PathBuf> {
+        self.config_dir()
+    }
+
+    ///
+    /// Returns the path to the user's data directory.
+    ///
+    fn data_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the user's local data directory.
+    ///
+    fn data_local_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the user's favorites directory.
+    ///
+    fn favorites_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the user's log directory.
+    ///
+    fn log_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the system-wide log directory; by default there is none.
+    ///
+    fn system_log_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's preference directory.
+    ///
+    fn preference_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the user's state directory; by default this is the local data
+    /// directory.
+    ///
+    fn state_dir(&self) -> Option<PathBuf> {
+        self.data_local_dir()
+    }
+
+    ///
+    /// Returns the path to the user's template directory.
+    ///
+    fn template_dir(&self) -> Option<PathBuf>;
+
+    ///
+    /// Returns the path to the user's saved games directory; by default there is none.
+    ///
+    fn saved_games_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's icons directory; by default there is none.
+    ///
+    fn icons_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's themes directory; by default there is none.
+    ///
+    fn themes_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's shared MIME-info directory; by default there is none.
+    ///
+    fn mime_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the data directory shared by all users; by default there is none.
+    ///
+    fn public_data_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the documents directory shared by all users; by default there is none.
+    ///
+    fn shared_documents_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's wallpaper directory; by default there is none.
+    ///
+    fn wallpaper_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's GSettings schema directory; by default there is none.
+    ///
+    fn glib_schemas_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the directory of GSettings' keyfile backend; by default there is none.
+    ///
+    fn keyfile_backend_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's crash dumps directory; by default there is none.
+    ///
+    fn crash_dumps_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the platform's directory for temporary files; by default there is none.
+    ///
+    fn temp_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's runtime directory, for sockets and other files that last
+    /// only as long as the user's session; by default there is none.
+    ///
+    fn runtime_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the first of the directories, shared by all users, in which data files
+    /// are found; by default there is none.
+    ///
+    fn system_data_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's documents directory; by default there is none.
+    ///
+    fn document_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's downloads directory; by default there is none.
+    ///
+    fn download_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the user's desktop directory; by default there is none.
+    ///
+    fn desktop_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the directory in which files are shared with other users; by default there is none.
+    ///
+    fn public_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the path to the template directory shared by all users; by default there is none.
+    ///
+    fn system_template_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    ///
+    /// Returns the generic form of `kind` from this provider, or `None` for those kinds that
+    /// have no generic form.
+    ///
+    fn dir(&self, kind: DirKind) -> Option<PathBuf> {
+        match kind {
+            DirKind::Application => self.application_dir(),
+            DirKind::ApplicationShared => self.application_shared_dir(),
+            DirKind::UserApplication => self.user_application_dir(),
+            DirKind::UserApplicationShared => self.user_application_shared_dir(),
+            DirKind::Cache => self.cache_dir(),
+            DirKind::Config => self.config_dir(),
+            DirKind::ConfigLocal => self.config_local_dir(),
+            DirKind::Data => self.data_dir(),
+            DirKind::DataLocal => self.data_local_dir(),
+            DirKind::Favorites => self.favorites_dir(),
+            DirKind::Log => self.log_dir(),
+            DirKind::SystemLog => self.system_log_dir(),
+            DirKind::Preference => self.preference_dir(),
+            DirKind::State => self.state_dir(),
+            DirKind::Template => self.template_dir(),
+            DirKind::SavedGames => self.saved_games_dir(),
+            DirKind::Icons => self.icons_dir(),
+            DirKind::Themes => self.themes_dir(),
+            DirKind::Mime => self.mime_dir(),
+            DirKind::PublicData => self.public_data_dir(),
+            DirKind::SharedDocuments => self.shared_documents_dir(),
+            DirKind::Wallpaper => self.wallpaper_dir(),
+            DirKind::GlibSchemas => self.glib_schemas_dir(),
+            DirKind::KeyfileBackend => self.keyfile_backend_dir(),
+            DirKind::CrashDumps => self.crash_dumps_dir(),
+            DirKind::Temp => self.temp_dir(),
+            DirKind::Document => self.document_dir(),
+            DirKind::Download => self.download_dir(),
+            DirKind::Desktop => self.desktop_dir(),
+            DirKind::PublicShare => self.public_dir(),
+            DirKind::SystemTemplate => self.system_template_dir(),
+            DirKind::AppContainer
+            | DirKind::AppContainerExecutable
+            | DirKind::AppContainerResources
+            | DirKind::AppContainerFrameworks
+            | DirKind::AppContainerPlugins
+            | DirKind::UserAppContainer
+            | DirKind::UserAppContainerExecutable
+            | DirKind::UserAppContainerResources
+            | DirKind::UserAppContainerFrameworks
+            | DirKind::UserAppContainerPlugins
+            | DirKind::Socket
+            | DirKind::Lock
+            | DirKind::History
+            | DirKind::Backup
+            | DirKind::Secrets
+            | DirKind::Plugins
+            | DirKind::SystemPlugins
+            | DirKind::Locale => None,
+        }
+    }
+
+    ///
+    /// Returns the application-specific form of `kind` for `app`, computed by applying the
+    /// current platform's layout rules to this provider's generic directories; see
+    /// [`Platform::dir_for`](enum.Platform.html#method.dir_for) to apply another platform's rules.
+    ///
+    fn dir_for(&self, kind: DirKind, app: &str) -> Option<PathBuf> {
+        Platform::current().dir_for(self, kind, app)
+    }
+}
+
+///
+/// The default provider, which asks the operating system for each generic directory.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlatformProvider;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl DirectoryProvider for PlatformProvider {
+    fn home_dir(&self) -> Option<PathBuf> {
+        sys::home_dir()
+    }
+
+    fn application_dir(&self) -> Option<PathBuf> {
+        sys::application_dir()
+    }
+
+    fn application_shared_dir(&self) -> Option<PathBuf> {
+        sys::application_shared_dir()
+    }
+
+    fn user_application_dir(&self) -> Option<PathBuf> {
+        sys::user_application_dir()
+    }
+
+    fn user_application_shared_dir(&self) -> Option<PathBuf> {
+        sys::user_application_shared_dir()
+    }
+
+    fn cache_dir(&self) -> Option<PathBuf> {
+        sys::cache_dir()
+    }
+
+    fn config_dir(&self) -> Option<PathBuf> {
+        sys::config_dir()
+    }
+
+    fn config_local_dir(&self) -> Option<PathBuf> {
+        sys::config_local_dir()
+    }
+
+    fn data_dir(&self) -> Option<PathBuf> {
+        sys::data_dir()
+    }
+
+    fn data_local_dir(&self) -> Option<PathBuf> {
+        sys::data_local_dir()
+    }
+
+    fn favorites_dir(&self) -> Option<PathBuf> {
+        sys::favorites_dir()
+    }
+
+    fn log_dir(&self) -> Option<PathBuf> {
+        sys::log_dir()
+    }
+
+    fn system_log_dir(&self) -> Option<PathBuf> {
+        sys::system_log_dir()
+    }
+
+    fn preference_dir(&self) -> Option<PathBuf> {
+        sys::preference_dir()
+    }
+
+    fn state_dir(&self) -> Option<PathBuf> {
+        sys::state_dir()
+    }
+
+    fn template_dir(&self) -> Option<PathBuf> {
+        sys::template_dir()
+    }
+
+    fn saved_games_dir(&self) -> Option<PathBuf> {
+        sys::saved_games_dir()
+    }
+
+    fn icons_dir(&self) -> Option<PathBuf> {
+        sys::icons_dir()
+    }
+
+    fn themes_dir(&self) -> Option<PathBuf> {
+        sys::themes_dir()
+    }
+
+    fn mime_dir(&self) -> Option<PathBuf> {
+        sys::mime_dir()
+    }
+
+    fn public_data_dir(&self) -> Option<PathBuf> {
+        sys::public_data_dir()
+    }
+
+    fn shared_documents_dir(&self) -> Option<PathBuf> {
+        sys::shared_documents_dir()
+    }
+
+    fn wallpaper_dir(&self) -> Option<PathBuf> {
+        sys::wallpaper_dir()
+    }
+
+    fn glib_schemas_dir(&self) -> Option<PathBuf> {
+        sys::glib_schemas_dir()
+    }
+
+    fn keyfile_backend_dir(&self) -> Option<PathBuf> {
+        sys::keyfile_backend_dir()
+    }
+
+    fn crash_dumps_dir(&self) -> Option<PathBuf> {
+        sys::crash_dumps_dir()
+    }
+
+    fn temp_dir(&self) -> Option<PathBuf> {
+        sys::temp_dir()
+    }
+
+    fn runtime_dir(&self) -> Option<PathBuf> {
+        sys::runtime_dir()
+    }
+
+    fn system_data_dir(&self) -> Option<PathBuf> {
+        sys::system_data_dirs().into_iter().next()
+    }
+
+    fn document_dir(&self) -> Option<PathBuf> {
+        sys::document_dir()
+    }
+
+    fn download_dir(&self) -> Option<PathBuf> {
+        sys::download_dir()
+    }
+
+    fn desktop_dir(&self) -> Option<PathBuf> {
+        sys::desktop_dir()
+    }
+
+    fn public_dir(&self) -> Option<PathBuf> {
+        sys::public_dir()
+    }
+
+    fn system_template_dir(&self) -> Option<PathBuf> {
+        sys::system_template_dir()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    struct MockProvider;
+
+    impl DirectoryProvider for MockProvider {
+        fn home_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/mock/home"))
+        }
+        fn application_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/mock/apps"))
+        }
+        fn application_shared_dir(&self) -> Option<PathBuf> {
+            None
+        }
+        fn user_application_dir(&self) -> Option<PathBuf> {
+            None
+        }
+        fn cache_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/mock/cache"))
+        }
+        fn config_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/mock/config"))
+        }
+        fn data_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/mock/data"))
+        }
+        fn data_local_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/mock/local"))
+        }
+        fn favorites_dir(&self) -> Option<PathBuf> {
+            None
+        }
+        fn log_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/mock/log"))
+        }
+        fn preference_dir(&self) -> Option<PathBuf> {
+            Some(PathBuf::from("/mock/preference"))
+        }
+        fn template_dir(&self) -> Option<PathBuf> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_platform_provider() {
+        for kind in DirKind::all() {
+            if kind.has_generic_form() {
+                assert_eq!(PlatformProvider.dir(kind), kind.dir());
+            }
+            if kind.has_app_form() {
+                assert_eq!(
+                    PlatformProvider.dir_for(kind, "xdirs-provider-test"),
+                    kind.dir_for("xdirs-provider-test")
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mock_provider() {
+        assert_eq!(MockProvider.dir(DirKind::Favorites), None);
+        assert_eq!(MockProvider.dir(DirKind::AppContainer), None);
+        assert_eq!(
+            MockProvider.dir_for(DirKind::ApplicationShared, "Chrome"),
+            None
+        );
+        assert!(MockProvider
+            .dir_for(DirKind::Config, "Chrome")
+            .unwrap()
+            .starts_with(Path::new("/mock/config/Chrome")));
+        for kind in DirKind::all() {
+            if let Some(path) = MockProvider.dir_for(kind, "Chrome") {
+                assert!(path.starts_with("/mock"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_dyn_provider() {
+        let provider: &dyn DirectoryProvider = &MockProvider;
+        assert_eq!(
+            provider.dir_for(DirKind::Data, "Chrome"),
+            Some(PathBuf::from("/mock/data/Chrome"))
+        );
+    }
+}
@@ -0,0 +1,88 @@
+/*!
+Provides the detection of directories that have been redirected away from the user's profile,
+as by the folder redirection and roaming profiles of managed Windows networks, so that
+applications may keep large or frequently written files on the local machine.
+*/
+
+use crate::{sys, DirKind, Platform};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns `true` if the generic form of `kind` is on a network share, or, for a kind that is by
+/// default beneath the home directory, lies outside it. On Windows a network share is a UNC path,
+/// such as `\\server\profiles\alice`, or a drive letter mapped to one; on other platforms only
+/// the second test applies, so a directory moved elsewhere with an environment variable, such as
+/// `$XDG_CACHE_HOME`, is reported. A kind with no value is not redirected.
+///
+/// ```rust
+/// use xdirs::{is_redirected, DirKind};
+///
+/// if is_redirected(DirKind::Data) {
+///     println!("data is kept on another machine");
+/// }
+/// ```
+///
+pub fn is_redirected(kind: DirKind) -> bool {
+    match kind.dir() {
+        Some(path) => {
+            sys::is_remote(&path)
+                || (Platform::current().is_in_home(kind)
+                    && !crate::home_dir().is_some_and(|home| path.starts_with(home)))
+        }
+        None => false,
+    }
+}
+
+///
+/// Returns the kind in which files that would be placed in `kind` are better kept, if they need
+/// not follow the user between machines: the local, non-roaming, counterpart of `kind` if `kind`
+/// is redirected and the counterpart is not, otherwise `kind` itself. Only the config and data
+/// directories have local counterparts.
+///
+/// ```rust
+/// use xdirs::{prefer_local, DirKind};
+///
+/// let thumbnails = prefer_local(DirKind::Data).dir_for("MyApp");
+/// ```
+///
+pub fn prefer_local(kind: DirKind) -> DirKind {
+    match local_counterpart(kind) {
+        Some(local) if is_redirected(kind) && !is_redirected(local) => local,
+        _ => kind,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn local_counterpart(kind: DirKind) -> Option<DirKind> {
+    match kind {
+        DirKind::Config => Some(DirKind::ConfigLocal),
+        DirKind::Data => Some(DirKind::DataLocal),
+        _ => None,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_redirected() {
+        let root = std::env::temp_dir().join(format!("xdirs-redirect-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        if crate::environment::var_os("XDG_DATA_HOME").is_none() {
+            assert!(!is_redirected(DirKind::Data));
+            assert_eq!(prefer_local(DirKind::Data), DirKind::Data);
+        }
+        assert_eq!(prefer_local(DirKind::Log), DirKind::Log);
+    }
+}
@@ -0,0 +1,260 @@
+/*!
+Provides the removal of every per-user directory this crate associates with an application, for
+uninstallers and "reset" actions; this is the complement of the functions that find them.
+
+Each directory removed is reported to the sink set with [`set_audit_sink`](fn.set_audit_sink.html).
+*/
+
+use crate::audit::{self, Operation};
+use crate::usage::disk_usage;
+use crate::{sanitize_app_name, validate_app_name, DirKind};
+use std::ffi::OsStr;
+use std::fs::{remove_dir_all, symlink_metadata};
+use std::io;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Whether [`remove_all_dirs_for`](fn.remove_all_dirs_for.html) removes anything. As removal
+/// cannot be undone nothing is removed unless `confirm` is set; a policy with neither field set
+/// is an error.
+///
+/// ```rust
+/// use xdirs::{remove_all_dirs_for, RemovePolicy};
+///
+/// // List what an uninstaller would remove, without removing anything.
+/// let report = remove_all_dirs_for("MyApp", &RemovePolicy::dry_run()).unwrap();
+/// for path in &report.removed {
+///     println!("would remove {:?}", path);
+/// }
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RemovePolicy {
+    /// Report the directories that would be removed, without removing them.
+    pub dry_run: bool,
+    /// Remove the directories; this is ignored in a dry run.
+    pub confirm: bool,
+}
+
+///
+/// The outcome of [`remove_all_dirs_for`](fn.remove_all_dirs_for.html).
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RemoveReport {
+    /// The directories removed, or that would be removed in a dry run.
+    pub removed: Vec<PathBuf>,
+    /// The total size, in bytes, of the files within the directories removed.
+    pub freed: u64,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Remove each existing per-user directory of a specific application, with all of its contents,
+/// according to `policy`: the cache, config, local config, data, local data, log, preference,
+/// and state directories. Where one directory is within another, as preferences are within the
+/// config directory on Linux, only the outer is listed. Directories shared with other users, the
+/// installation directory, and the user's documents are never removed.
+///
+/// As a guard against removing more than the application's own files, a directory is skipped if
+/// it has been relocated by an [environment override](fn.env_override_var.html), if its last
+/// component is not the application name, or if it is not strictly within the generic directory
+/// of its kind. The home directory, the generic directories, and any directory containing one of
+/// these, are never removed.
+///
+/// An error with the kind `InvalidInput` is returned if `app` is not a valid application name,
+/// as checked by [`validate_app_name`](fn.validate_app_name.html), or if `policy` neither
+/// confirms the removal nor asks for a dry run. An error removing a directory stops the removal,
+/// leaving the remaining directories in place.
+///
+pub fn remove_all_dirs_for(app: &str, policy: &RemovePolicy) -> io::Result<RemoveReport> {
+    validate_app_name(app).map_err(|reason| io::Error::new(io::ErrorKind::InvalidInput, reason))?;
+    if !policy.dry_run && !policy.confirm {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "removal was neither confirmed nor a dry run",
+        ));
+    }
+    let mut report = RemoveReport::default();
+    for (kind, path) in app_dirs_for(app) {
+        report.freed += disk_usage(&path)?.0;
+        if !policy.dry_run {
+            remove_dir_all(&path)?;
+            audit::record(Operation::Delete, &path, Some(kind), REASON);
+        }
+        report.removed.push(path);
+    }
+    Ok(report)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl RemovePolicy {
+    ///
+    /// Returns a policy that reports the directories that would be removed, without removing
+    /// them.
+    ///
+    pub fn dry_run() -> Self {
+        Self {
+            dry_run: true,
+            confirm: false,
+        }
+    }
+
+    ///
+    /// Returns a policy that removes the directories.
+    ///
+    pub fn confirmed() -> Self {
+        Self {
+            dry_run: false,
+            confirm: true,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const REASON: &str = "remove_all_dirs_for";
+
+const KINDS: &[DirKind] = &[
+    DirKind::Cache,
+    DirKind::Config,
+    DirKind::ConfigLocal,
+    DirKind::Data,
+    DirKind::DataLocal,
+    DirKind::Log,
+    DirKind::Preference,
+    DirKind::State,
+];
+
+///
+/// Returns the existing, removable, directories of `app` with their kind, omitting any within
+/// another.
+///
+fn app_dirs_for(app: &str) -> Vec<(DirKind, PathBuf)> {
+    let name = sanitize_app_name(app);
+    let protected = protected_dirs();
+    let candidates: Vec<(DirKind, PathBuf)> = KINDS
+        .iter()
+        .filter(|kind| crate::env_override::dir_for(**kind, app).is_none())
+        .filter_map(|kind| kind.dir_for(app).map(|path| (*kind, path)))
+        .filter(|(kind, path)| is_removable(*kind, path, &name, &protected))
+        .filter(|(_, path)| symlink_metadata(path).is_ok())
+        .collect();
+    let mut dirs: Vec<(DirKind, PathBuf)> = Vec::new();
+    for (kind, path) in &candidates {
+        let nested = candidates
+            .iter()
+            .any(|(_, other)| other != path && path.starts_with(other));
+        if !nested && !dirs.iter().any(|(_, dir)| dir == path) {
+            dirs.push((*kind, path.clone()));
+        }
+    }
+    dirs
+}
+
+///
+/// Returns the directories that must never be removed, nor any directory containing them: the
+/// home directory and every generic directory.
+///
+fn protected_dirs() -> Vec<PathBuf> {
+    crate::home_dir()
+        .into_iter()
+        .chain(DirKind::all().filter_map(|kind| kind.dir()))
+        .collect()
+}
+
+fn is_removable(kind: DirKind, path: &Path, name: &str, protected: &[PathBuf]) -> bool {
+    path.file_name() == Some(OsStr::new(name))
+        && kind
+            .dir()
+            .is_some_and(|base| path != base && path.starts_with(&base))
+        && !protected.iter().any(|dir| dir.starts_with(path))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::EnvGuard;
+
+    #[test]
+    fn test_remove_all_dirs_for() {
+        let root = std::env::temp_dir().join(format!("xdirs-remove-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let config = crate::config_dir_for("Chrome").unwrap();
+        std::fs::create_dir_all(config.join("profiles")).unwrap();
+        std::fs::write(config.join("settings.toml"), "theme = 1\n").unwrap();
+
+        let unconfirmed = remove_all_dirs_for("Chrome", &RemovePolicy::default());
+        assert_eq!(unconfirmed.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        let invalid = remove_all_dirs_for("..", &RemovePolicy::dry_run());
+        assert_eq!(invalid.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+
+        let report = remove_all_dirs_for("Chrome", &RemovePolicy::dry_run()).unwrap();
+        assert_eq!(report.removed, vec![config.clone()]);
+        assert_eq!(report.freed, 10);
+        assert!(config.is_dir());
+
+        let report = remove_all_dirs_for("Chrome", &RemovePolicy::confirmed()).unwrap();
+        assert_eq!(report.removed, vec![config.clone()]);
+        assert!(!config.exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_remove_skips_env_override() {
+        let mut env = EnvGuard::lock();
+        // a directory that would pass every other check, but which the application may share.
+        let shared = DirKind::Cache
+            .dir()
+            .unwrap()
+            .join("shared")
+            .join("XdirsRemove");
+        env.set("XDIRSREMOVE_CACHE_DIR", &shared);
+        crate::refresh();
+        let root = std::env::temp_dir().join(format!("xdirs-remove-env-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let elsewhere = crate::cache_dir_for("XdirsRemove").unwrap();
+        assert!(elsewhere.ends_with(Path::new("shared").join("XdirsRemove")));
+        std::fs::create_dir_all(&elsewhere).unwrap();
+
+        let report = remove_all_dirs_for("XdirsRemove", &RemovePolicy::confirmed()).unwrap();
+        assert!(report.removed.is_empty());
+        assert!(elsewhere.is_dir());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_remove_refuses_generic_dir() {
+        let root = std::env::temp_dir().join(format!("xdirs-remove-base-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        // the local data directory of an application named "favorites" is the generic
+        // favorites directory.
+        let favorites = crate::favorites_dir().unwrap();
+        assert_eq!(
+            crate::data_local_dir_for("favorites"),
+            Some(favorites.clone())
+        );
+        std::fs::create_dir_all(&favorites).unwrap();
+
+        let report = remove_all_dirs_for("favorites", &RemovePolicy::confirmed()).unwrap();
+        assert!(report.removed.is_empty());
+        assert!(favorites.is_dir());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
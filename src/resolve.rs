@@ -0,0 +1,468 @@
+/*!
+The resolution pipeline shared by all of the directory functions.
+*/
+
+use crate::metrics::timed;
+use crate::naming::naming;
+use crate::nesting::nesting_scheme;
+use crate::{
+    env_override, override_root, strict_xdg, sys, warm, Convention, DirKind, DirectoryProvider,
+    Error, Platform, PlatformProvider,
+};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Discard all previously resolved directories, including those cached by
+/// [`warm`](fn.warm.html) and the locations in which applications were found installed, so that
+/// each is resolved again on next use, and take a new snapshot of the environment, as
+/// [`reload_env`](fn.reload_env.html) does.
+///
+/// The generic directories are resolved once, on first use, and the result reused by every
+/// subsequent call, including those for the application-specific directories derived from them.
+/// An application that changes its environment, for example setting `XDG_CONFIG_HOME`, after a
+/// directory has been resolved must call this function for the change to be seen.
+///
+/// ```rust
+/// let before = xdirs::config_dir();
+/// xdirs::refresh();
+/// assert_eq!(xdirs::config_dir(), before);
+/// ```
+///
+pub fn refresh() {
+    let mut memo = match memo().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    crate::environment::reload_env();
+    memo.clear();
+    clear(installed());
+    warm::clear();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Resolve the generic form of `kind`, as for [`try_dir`](fn.try_dir.html), discarding the
+/// reason it has no value.
+///
+pub(crate) fn dir(kind: DirKind) -> Option<PathBuf> {
+    try_dir(kind).ok()
+}
+
+///
+/// Resolve the generic form of `kind`, re-rooted by any [`override_root`](../fn.override_root.html)
+/// in effect, or the reason it has no value.
+///
+pub(crate) fn try_dir(kind: DirKind) -> Result<PathBuf, Error> {
+    timed(kind, || override_root::apply(platform_dir(kind))).ok_or_else(|| unavailable(kind))
+}
+
+///
+/// Resolve the application-specific form of `kind`, as for [`try_dir_for`](fn.try_dir_for.html),
+/// discarding the reason it has no value.
+///
+pub(crate) fn dir_for(kind: DirKind, app: &str) -> Option<PathBuf> {
+    try_dir_for(kind, app).ok()
+}
+
+///
+/// Resolve the application-specific form of `kind`; this applies, in order, any environment
+/// override, any value cached by [`warm`](../fn.warm.html), and finally the platform's value. The
+/// result is then re-rooted by any [`override_root`](../fn.override_root.html) in effect. If there
+/// is no value the reason is returned.
+///
+pub(crate) fn try_dir_for(kind: DirKind, app: &str) -> Result<PathBuf, Error> {
+    timed(kind, || {
+        override_root::apply(
+            env_override::dir_for(kind, app)
+                .or_else(|| warm::cached(kind, app).unwrap_or_else(|| platform_dir_for(kind, app))),
+        )
+    })
+    .ok_or_else(|| unavailable(kind))
+}
+
+///
+/// Resolve the generic form of `kind` using only the platform's rules.
+///
+pub(crate) fn platform_dir(kind: DirKind) -> Option<PathBuf> {
+    MemoizedProvider.dir(kind)
+}
+
+///
+/// Resolve the application-specific form of `kind` using only the platform's rules, and the
+/// application's naming policy and nesting scheme. For application containers the platform is
+/// first asked where the application is actually installed, and where strict XDG mode applies
+/// the XDG layout is used instead.
+///
+pub(crate) fn platform_dir_for(kind: DirKind, app: &str) -> Option<PathBuf> {
+    match strict_xdg::dir_for(kind, app) {
+        Some(path) => path,
+        None => native_dir_for(kind, app),
+    }
+}
+
+///
+/// Resolve the application-specific form of `kind` following `convention`; this applies any
+/// environment override, but not any value cached by [`warm`](../fn.warm.html), which follows
+/// the default convention. The result is then re-rooted by any
+/// [`override_root`](../fn.override_root.html) in effect.
+///
+pub(crate) fn convention_dir_for(
+    kind: DirKind,
+    app: &str,
+    convention: Convention,
+) -> Option<PathBuf> {
+    timed(kind, || {
+        override_root::apply(
+            env_override::dir_for(kind, app).or_else(|| match convention {
+                Convention::Native => native_dir_for(kind, app),
+                Convention::Xdg => {
+                    strict_xdg::xdg_dir_for(kind, app).unwrap_or_else(|| native_dir_for(kind, app))
+                }
+            }),
+        )
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A provider that asks the [`PlatformProvider`](../struct.PlatformProvider.html) for each
+/// generic directory only once, until the next [`refresh`](fn.refresh.html).
+///
+struct MemoizedProvider;
+
+/// Memoized generic directories, keyed by kind; the home directory has no kind and is `None`.
+type Memo = HashMap<Option<DirKind>, Option<PathBuf>>;
+
+/// Memoized locations in which applications were found installed, keyed by the
+/// [`override_root`](../fn.override_root.html) in effect, kind, and application.
+type InstalledMemo = HashMap<(Option<PathBuf>, DirKind, String), Option<PathBuf>>;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl DirectoryProvider for MemoizedProvider {
+    fn home_dir(&self) -> Option<PathBuf> {
+        memoized(None, || PlatformProvider.home_dir())
+    }
+
+    fn application_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Application)
+    }
+
+    fn application_shared_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::ApplicationShared)
+    }
+
+    fn user_application_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::UserApplication)
+    }
+
+    fn user_application_shared_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::UserApplicationShared)
+    }
+
+    fn cache_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Cache)
+    }
+
+    fn config_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Config)
+    }
+
+    fn config_local_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::ConfigLocal)
+    }
+
+    fn data_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Data)
+    }
+
+    fn data_local_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::DataLocal)
+    }
+
+    fn favorites_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Favorites)
+    }
+
+    fn log_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Log)
+    }
+
+    fn system_log_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::SystemLog)
+    }
+
+    fn preference_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Preference)
+    }
+
+    fn state_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::State)
+    }
+
+    fn template_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Template)
+    }
+
+    fn saved_games_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::SavedGames)
+    }
+
+    fn icons_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Icons)
+    }
+
+    fn themes_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Themes)
+    }
+
+    fn mime_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Mime)
+    }
+
+    fn public_data_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::PublicData)
+    }
+
+    fn shared_documents_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::SharedDocuments)
+    }
+
+    fn wallpaper_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Wallpaper)
+    }
+
+    fn glib_schemas_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::GlibSchemas)
+    }
+
+    fn keyfile_backend_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::KeyfileBackend)
+    }
+
+    fn crash_dumps_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::CrashDumps)
+    }
+
+    fn temp_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Temp)
+    }
+
+    fn runtime_dir(&self) -> Option<PathBuf> {
+        PlatformProvider.runtime_dir()
+    }
+
+    fn system_data_dir(&self) -> Option<PathBuf> {
+        PlatformProvider.system_data_dir()
+    }
+
+    fn document_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Document)
+    }
+
+    fn download_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Download)
+    }
+
+    fn desktop_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::Desktop)
+    }
+
+    fn public_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::PublicShare)
+    }
+
+    fn system_template_dir(&self) -> Option<PathBuf> {
+        self.get(DirKind::SystemTemplate)
+    }
+}
+
+impl MemoizedProvider {
+    fn get(&self, kind: DirKind) -> Option<PathBuf> {
+        memoized(Some(kind), || PlatformProvider.dir(kind))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Resolve the application-specific form of `kind` using the platform's own layout, regardless
+/// of strict XDG mode.
+///
+fn native_dir_for(kind: DirKind, app: &str) -> Option<PathBuf> {
+    installed_container_dir_for(kind, app).or_else(|| {
+        Platform::current().named_dir_for(
+            &MemoizedProvider,
+            kind,
+            app,
+            &naming(app),
+            nesting_scheme(app),
+        )
+    })
+}
+
+///
+/// Returns where the platform finds `app` installed, for the application container kinds. As
+/// this may search the file system, or on Windows the registry, each result is kept until the
+/// next [`refresh`](fn.refresh.html). A search of the file system is made beneath any
+/// [`override_root`](../fn.override_root.html) in effect, but returns the path before it is
+/// re-rooted.
+///
+fn installed_container_dir_for(kind: DirKind, app: &str) -> Option<PathBuf> {
+    if matches!(
+        kind,
+        DirKind::AppContainer
+            | DirKind::AppContainerExecutable
+            | DirKind::UserAppContainer
+            | DirKind::UserAppContainerExecutable
+    ) {
+        let key = (override_root::current(), kind, app.to_string());
+        memoized_in(installed(), key, || {
+            sys::installed_container_dir_for(kind, app)
+        })
+    } else {
+        None
+    }
+}
+
+///
+/// Returns the reason `kind` has no value: that it is beneath the home directory, which could
+/// not be found, or that the platform has none.
+///
+fn unavailable(kind: DirKind) -> Error {
+    if Platform::current().is_in_home(kind) && sys::home_dir().is_none() {
+        Error::HomeUnavailable(kind)
+    } else {
+        Error::Unavailable(kind)
+    }
+}
+
+fn memo() -> &'static RwLock<Memo> {
+    static MEMO: OnceLock<RwLock<Memo>> = OnceLock::new();
+    MEMO.get_or_init(Default::default)
+}
+
+fn installed() -> &'static RwLock<InstalledMemo> {
+    static INSTALLED: OnceLock<RwLock<InstalledMemo>> = OnceLock::new();
+    INSTALLED.get_or_init(Default::default)
+}
+
+fn clear<K>(memo: &RwLock<HashMap<K, Option<PathBuf>>>) {
+    match memo.write() {
+        Ok(mut guard) => guard.clear(),
+        Err(poisoned) => poisoned.into_inner().clear(),
+    }
+}
+
+fn memoized<F>(key: Option<DirKind>, resolve: F) -> Option<PathBuf>
+where
+    F: FnOnce() -> Option<PathBuf>,
+{
+    memoized_in(memo(), key, resolve)
+}
+
+fn memoized_in<K, F>(
+    memo: &RwLock<HashMap<K, Option<PathBuf>>>,
+    key: K,
+    resolve: F,
+) -> Option<PathBuf>
+where
+    K: Eq + Hash,
+    F: FnOnce() -> Option<PathBuf>,
+{
+    {
+        let memo = match memo.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(path) = memo.get(&key) {
+            return path.clone();
+        }
+    }
+    // resolved without holding the lock, as resolution may itself ask for the home directory.
+    let path = resolve();
+    let mut memo = match memo.write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    memo.entry(key).or_insert(path).clone()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memoized_provider() {
+        for kind in DirKind::all() {
+            assert_eq!(MemoizedProvider.dir(kind), PlatformProvider.dir(kind));
+            assert_eq!(MemoizedProvider.dir(kind), PlatformProvider.dir(kind));
+        }
+        assert_eq!(MemoizedProvider.home_dir(), PlatformProvider.home_dir());
+    }
+
+    #[test]
+    fn test_refresh() {
+        let _ = platform_dir(DirKind::Cache);
+        refresh();
+        assert_eq!(platform_dir(DirKind::Cache), PlatformProvider.cache_dir());
+    }
+
+    #[test]
+    fn test_memoized_in() {
+        let memo: RwLock<InstalledMemo> = Default::default();
+        let key = || (None, DirKind::AppContainer, String::from("Chrome"));
+        let probes = std::cell::Cell::new(0);
+        let probe = || {
+            probes.set(probes.get() + 1);
+            Some(PathBuf::from("/opt/Chrome"))
+        };
+        assert_eq!(
+            memoized_in(&memo, key(), probe),
+            Some(PathBuf::from("/opt/Chrome"))
+        );
+        assert_eq!(
+            memoized_in(&memo, key(), probe),
+            Some(PathBuf::from("/opt/Chrome"))
+        );
+        assert_eq!(probes.get(), 1);
+        clear(&memo);
+        let _ = memoized_in(&memo, key(), probe);
+        assert_eq!(probes.get(), 2);
+    }
+
+    #[test]
+    fn test_refresh_environment() {
+        let mut env = crate::environment::EnvGuard::lock();
+        let app = "xdirs-refresh-test";
+        let before = dir_for(DirKind::Config, app);
+        let path = std::env::temp_dir().join(app);
+        env.set("XDIRS_REFRESH_TEST_CONFIG_DIR", &path);
+        refresh();
+        assert_eq!(dir_for(DirKind::Config, app), Some(path));
+        env.remove("XDIRS_REFRESH_TEST_CONFIG_DIR");
+        refresh();
+        assert_eq!(dir_for(DirKind::Config, app), before);
+    }
+}
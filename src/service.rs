@@ -0,0 +1,70 @@
+/*!
+Provides the conventional directories of a system service, or daemon, which runs without a user
+profile and so has none of the user's directories.
+*/
+
+use crate::{override_root, sys};
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The directories of a system service, as returned by
+/// [`service_dirs_for`](fn.service_dirs_for.html); each is `None` if the platform has no
+/// conventional location for it.
+///
+/// |Directory | Linux            | macOS                               | Windows                          |
+/// | -------- | ---------------- | ----------------------------------- | -------------------------------- |
+/// | config   | /etc/{app}       | /Library/Application Support/{app}  | `{FOLDERID_ProgramData}`\{app}\Config |
+/// | data     | /var/lib/{app}   | /Library/Application Support/{app}  | `{FOLDERID_ProgramData}`\{app}\Data   |
+/// | cache    | /var/cache/{app} | /Library/Caches/{app}               | `{FOLDERID_ProgramData}`\{app}\Cache  |
+/// | runtime  | /run/{app}       | /var/run/{app}                      | -                                |
+/// | log      | /var/log/{app}   | /Library/Logs/{app}                 | `{FOLDERID_ProgramData}`\{app}\Logs   |
+///
+/// The other Unix platforms use the Linux locations, other than `/var/run` for the runtime
+/// directory.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServiceDirs {
+    /// The service's configuration, usually maintained by an administrator.
+    pub config: Option<PathBuf>,
+    /// The service's persistent state.
+    pub data: Option<PathBuf>,
+    /// Cached data the service can regenerate.
+    pub cache: Option<PathBuf>,
+    /// Runtime files, such as sockets and PID files, removed on reboot.
+    pub runtime: Option<PathBuf>,
+    /// The service's log files.
+    pub log: Option<PathBuf>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the conventional directories of the system service `app`; unlike the other `_for`
+/// functions these do not depend on the current user, and so are suitable for a daemon that runs
+/// without a user profile, or as a different user to the one that configures it.
+///
+/// ```rust
+/// use xdirs::service_dirs_for;
+///
+/// let dirs = service_dirs_for("my-daemon");
+/// if cfg!(target_os = "linux") {
+///     assert_eq!(dirs.data, Some("/var/lib/my-daemon".into()));
+/// }
+/// ```
+///
+pub fn service_dirs_for(app: &str) -> ServiceDirs {
+    let dirs = sys::service_dirs_for(app);
+    ServiceDirs {
+        config: override_root::apply(dirs.config),
+        data: override_root::apply(dirs.data),
+        cache: override_root::apply(dirs.cache),
+        runtime: override_root::apply(dirs.runtime),
+        log: override_root::apply(dirs.log),
+    }
+}
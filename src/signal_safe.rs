@@ -0,0 +1,128 @@
+/*!
+Provides access to paths from within crash and signal handlers.
+
+Resolving a directory allocates memory, reads environment variables, and on some platforms calls
+into the OS, none of which is safe within a signal handler or after a crash. Instead, an
+application resolves the paths it will need once at startup, using the `init_` functions in this
+module, and the handler then retrieves them using the corresponding accessor. The accessors do not
+allocate or take locks; each is a single atomic load of a value held in static storage.
+
+```rust
+use xdirs::signal_safe;
+
+// at startup
+signal_safe::init_minidump_path_for("MyApp");
+
+// within the crash handler
+if let Some(path) = signal_safe::minidump_path() {
+    // write the dump ...
+}
+```
+*/
+
+#[cfg(unix)]
+use std::ffi::{CStr, CString};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Resolve, and retain, the minidump path for the given application; see
+/// [`minidump_path_for`](../fn.minidump_path_for.html). This allocates and must be called before
+/// any handler is installed.
+///
+/// Only the first call has any effect; subsequent calls, even for a different application name,
+/// return the path retained by the first.
+///
+pub fn init_minidump_path_for(app: &str) -> Option<&'static Path> {
+    MINIDUMP_PATH
+        .get_or_init(|| crate::minidump_path_for(app).map(Resolved::new))
+        .as_ref()
+        .map(|resolved| resolved.path.as_path())
+}
+
+///
+/// Returns the minidump path retained by
+/// [`init_minidump_path_for`](fn.init_minidump_path_for.html), or `None` if it has not been
+/// called or the path could not be resolved. This function does not allocate or lock.
+///
+pub fn minidump_path() -> Option<&'static Path> {
+    resolved().map(|resolved| resolved.path.as_path())
+}
+
+///
+/// Returns the minidump path retained by
+/// [`init_minidump_path_for`](fn.init_minidump_path_for.html) as a NUL-terminated string, suitable
+/// for passing directly to `open(2)` and similar calls. This function does not allocate or lock.
+///
+#[cfg(unix)]
+pub fn minidump_path_c() -> Option<&'static CStr> {
+    resolved().and_then(|resolved| resolved.c_path.as_deref())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Resolved {
+    path: PathBuf,
+    #[cfg(unix)]
+    c_path: Option<CString>,
+}
+
+static MINIDUMP_PATH: OnceLock<Option<Resolved>> = OnceLock::new();
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn resolved() -> Option<&'static Resolved> {
+    MINIDUMP_PATH.get().and_then(Option::as_ref)
+}
+
+impl Resolved {
+    fn new(path: PathBuf) -> Self {
+        #[cfg(unix)]
+        let c_path = {
+            use std::os::unix::ffi::OsStrExt;
+            CString::new(path.as_os_str().as_bytes()).ok()
+        };
+        Self {
+            path,
+            #[cfg(unix)]
+            c_path,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minidump_path() {
+        let expected = crate::minidump_path_for("Chrome");
+        assert_eq!(
+            init_minidump_path_for("Chrome").map(Path::to_path_buf),
+            expected
+        );
+        assert_eq!(
+            init_minidump_path_for("Firefox").map(Path::to_path_buf),
+            expected
+        );
+        assert_eq!(minidump_path().map(Path::to_path_buf), expected);
+        #[cfg(unix)]
+        assert_eq!(
+            minidump_path_c().map(|c| PathBuf::from(c.to_str().unwrap())),
+            expected
+        );
+    }
+}
@@ -0,0 +1,192 @@
+/*!
+Provides an opt-in mode under which the configuration, data, cache, and log directories of an
+application follow the XDG layout on macOS, as many command-line tools prefer, rather than the
+`~/Library` layout.
+*/
+
+use crate::naming::naming;
+use crate::nesting::nesting_scheme;
+use crate::xdg::resolve;
+use crate::{BaseKind, DirKind, DirectoryProvider, Platform};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Enable, or disable, strict XDG mode. When enabled, on macOS the directories returned by
+/// [`config_dir_for`](fn.config_dir_for.html), [`config_local_dir_for`](fn.config_local_dir_for.html),
+/// [`data_dir_for`](fn.data_dir_for.html), [`data_local_dir_for`](fn.data_local_dir_for.html),
+/// [`cache_dir_for`](fn.cache_dir_for.html), and [`log_dir_for`](fn.log_dir_for.html), and all
+/// other ways of resolving the same kinds, are laid out as they are on Linux, honoring the
+/// `XDG_*` environment variables if set. This has no effect on other platforms, and values
+/// already cached by [`warm`](fn.warm.html) are not affected, so this should be called first.
+///
+/// |Kind       | Value, strict XDG mode on macOS                  | Example                        |
+/// | --------- | ------------------------------------------------ | ------------------------------ |
+/// | Config    | `$XDG_CONFIG_HOME` or `$HOME`/.config/{app}      | /Users/Alice/.config/MyApp     |
+/// | Data      | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}   | /Users/Alice/.local/share/MyApp |
+/// | Cache     | `$XDG_CACHE_HOME` or `$HOME`/.cache/{app}        | /Users/Alice/.cache/MyApp      |
+/// | Log       | `$XDG_DATA_HOME` or `$HOME`/.local/share/{app}/logs | /Users/Alice/.local/share/MyApp/logs |
+///
+/// ```rust
+/// use xdirs::{config_dir_for, set_strict_xdg};
+///
+/// set_strict_xdg(true);
+/// if cfg!(target_os = "macos") {
+///     assert!(config_dir_for("MyApp").unwrap().ends_with(".config/MyApp"));
+/// }
+/// ```
+///
+pub fn set_strict_xdg(enabled: bool) {
+    STRICT_XDG.store(enabled, Ordering::SeqCst);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the XDG form of `kind` for `app` if strict XDG mode is enabled, the current platform
+/// is macOS, and `kind` is one to which the mode applies; otherwise `None`, and the platform's
+/// own layout applies.
+///
+pub(crate) fn dir_for(kind: DirKind, app: &str) -> Option<Option<PathBuf>> {
+    if cfg!(target_os = "macos") && STRICT_XDG.load(Ordering::SeqCst) {
+        xdg_dir_for(kind, app)
+    } else {
+        None
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A provider whose generic directories are the XDG base directories, for use with the Linux
+/// layout.
+///
+struct XdgProvider;
+
+static STRICT_XDG: AtomicBool = AtomicBool::new(false);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl DirectoryProvider for XdgProvider {
+    fn home_dir(&self) -> Option<PathBuf> {
+        crate::sys::home_dir()
+    }
+
+    fn application_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    fn application_shared_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    fn user_application_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    fn cache_dir(&self) -> Option<PathBuf> {
+        resolve(BaseKind::CacheHome).value
+    }
+
+    fn config_dir(&self) -> Option<PathBuf> {
+        resolve(BaseKind::ConfigHome).value
+    }
+
+    fn data_dir(&self) -> Option<PathBuf> {
+        resolve(BaseKind::DataHome).value
+    }
+
+    fn data_local_dir(&self) -> Option<PathBuf> {
+        resolve(BaseKind::DataHome).value
+    }
+
+    fn favorites_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    fn log_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    fn preference_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    fn state_dir(&self) -> Option<PathBuf> {
+        resolve(BaseKind::StateHome).value
+    }
+
+    fn template_dir(&self) -> Option<PathBuf> {
+        None
+    }
+}
+
+///
+/// Returns the XDG form of `kind` for `app`, on any platform, if `kind` is one to which strict XDG
+/// mode applies.
+///
+pub(crate) fn xdg_dir_for(kind: DirKind, app: &str) -> Option<Option<PathBuf>> {
+    match kind {
+        DirKind::Config
+        | DirKind::ConfigLocal
+        | DirKind::Data
+        | DirKind::DataLocal
+        | DirKind::Cache
+        | DirKind::Log
+        | DirKind::State
+        | DirKind::History
+        | DirKind::Backup
+        | DirKind::Secrets
+        | DirKind::Plugins
+        | DirKind::Locale => Some(Platform::Linux.named_dir_for(
+            &XdgProvider,
+            kind,
+            app,
+            &naming(app),
+            nesting_scheme(app),
+        )),
+        _ => None,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xdg_dir_for() {
+        let cache = resolve(BaseKind::CacheHome).value.unwrap();
+        assert_eq!(
+            xdg_dir_for(DirKind::Cache, "Chrome"),
+            Some(Some(cache.join("Chrome")))
+        );
+        let data = resolve(BaseKind::DataHome).value.unwrap();
+        assert_eq!(
+            xdg_dir_for(DirKind::Log, "Chrome"),
+            Some(Some(data.join("Chrome").join("logs")))
+        );
+        assert_eq!(xdg_dir_for(DirKind::Favorites, "Chrome"), None);
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn test_no_effect() {
+        set_strict_xdg(true);
+        assert_eq!(dir_for(DirKind::Config, "Chrome"), None);
+        set_strict_xdg(false);
+    }
+}
@@ -0,0 +1,175 @@
+/*!
+Provides the resolution of the directories of the user who invoked `sudo`, rather than those of
+the target user, usually root.
+
+Depending on its configuration and flags, `sudo` either keeps `HOME` as the invoking user's home
+or sets it to the target user's, and so a tool run under `sudo` may silently write root-owned
+files into the user's configuration directory, or read root's configuration rather than the
+user's. The functions in this module resolve the invoking user's directories in either case, and
+[`check_sudo_home`](fn.check_sudo_home.html) detects the mismatch.
+*/
+
+use crate::{home_dir_for_user, DirKind, Error};
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the name of the user who invoked `sudo`, from `SUDO_USER`, if the process is running
+/// under `sudo` on a Unix platform.
+///
+pub fn sudo_user() -> Option<String> {
+    if !cfg!(unix) {
+        return None;
+    }
+    crate::environment::non_empty_var(E_SUDO_USER)
+}
+
+///
+/// Returns the home directory of the user who invoked `sudo`, as returned by
+/// [`home_dir_for_user`](fn.home_dir_for_user.html). Returns `None` if the process is not running
+/// under `sudo`, or the home directory cannot be determined.
+///
+pub fn sudo_user_home() -> Option<PathBuf> {
+    home_dir_for_user(&sudo_user()?)
+}
+
+///
+/// Returns the application-specific form of the directory `kind` for the user who invoked `sudo`;
+/// the directory is resolved as usual, and any part beneath the current home directory is moved
+/// beneath the invoking user's home. When not running under `sudo` this is the same as
+/// [`DirKind::dir_for`](enum.DirKind.html#method.dir_for). Returns `None` if the invoking user's
+/// home directory cannot be determined, rather than fall back to the target user's.
+///
+/// Directories configured for the target user by environment variables, such as
+/// `XDG_CONFIG_HOME`, are moved only if they are beneath the current home directory.
+///
+/// ```rust
+/// use xdirs::{dir_for_sudo_user, DirKind};
+///
+/// // Under `sudo`, this is still the invoking user's configuration, not root's.
+/// let config = dir_for_sudo_user(DirKind::Config, "MyApp");
+/// ```
+///
+pub fn dir_for_sudo_user(kind: DirKind, app: &str) -> Option<PathBuf> {
+    let path = kind.dir_for(app)?;
+    if sudo_user().is_none() {
+        return Some(path);
+    }
+    let home = sudo_user_home()?;
+    match crate::home_dir() {
+        Some(current) => Some(rebase(path, &current, &home)),
+        None => Some(path),
+    }
+}
+
+///
+/// Returns the path to the cache directory of the user who invoked `sudo` for a specific
+/// application. See [`dir_for_sudo_user`](fn.dir_for_sudo_user.html).
+///
+pub fn cache_dir_for_sudo_user(app: &str) -> Option<PathBuf> {
+    dir_for_sudo_user(DirKind::Cache, app)
+}
+
+///
+/// Returns the path to the config directory of the user who invoked `sudo` for a specific
+/// application. See [`dir_for_sudo_user`](fn.dir_for_sudo_user.html).
+///
+pub fn config_dir_for_sudo_user(app: &str) -> Option<PathBuf> {
+    dir_for_sudo_user(DirKind::Config, app)
+}
+
+///
+/// Returns the path to the data directory of the user who invoked `sudo` for a specific
+/// application. See [`dir_for_sudo_user`](fn.dir_for_sudo_user.html).
+///
+pub fn data_dir_for_sudo_user(app: &str) -> Option<PathBuf> {
+    dir_for_sudo_user(DirKind::Data, app)
+}
+
+///
+/// Returns the path to the local data directory of the user who invoked `sudo` for a specific
+/// application. See [`dir_for_sudo_user`](fn.dir_for_sudo_user.html).
+///
+pub fn data_local_dir_for_sudo_user(app: &str) -> Option<PathBuf> {
+    dir_for_sudo_user(DirKind::DataLocal, app)
+}
+
+///
+/// Returns the path to the log directory of the user who invoked `sudo` for a specific
+/// application. See [`dir_for_sudo_user`](fn.dir_for_sudo_user.html).
+///
+pub fn log_dir_for_sudo_user(app: &str) -> Option<PathBuf> {
+    dir_for_sudo_user(DirKind::Log, app)
+}
+
+///
+/// Check that, if the process is running under `sudo`, its home directory is that of the invoking
+/// user; otherwise the `_for` functions resolve the target user's directories, and files written
+/// there by a tool the user believes to be writing to their own directories will be owned by the
+/// target user. Returns [`Error::SudoHomeMismatch`](enum.Error.html#variant.SudoHomeMismatch) on
+/// a mismatch, so that the caller may warn, refuse to continue, or switch to the `_for_sudo_user`
+/// functions.
+///
+/// ```rust
+/// if let Err(e) = xdirs::check_sudo_home() {
+///     eprintln!("warning: {}", e);
+/// }
+/// ```
+///
+pub fn check_sudo_home() -> Result<(), Error> {
+    match (sudo_user(), sudo_user_home(), crate::home_dir()) {
+        (Some(user), Some(expected), Some(home)) if home != expected => {
+            Err(Error::SudoHomeMismatch {
+                user,
+                home,
+                expected,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const E_SUDO_USER: &str = "SUDO_USER";
+
+fn rebase(path: PathBuf, from: &Path, to: &Path) -> PathBuf {
+    match path.strip_prefix(from) {
+        Ok(relative) => to.join(relative),
+        Err(_) => path,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rebase() {
+        assert_eq!(
+            rebase(
+                PathBuf::from("/root/.config/MyApp"),
+                Path::new("/root"),
+                Path::new("/home/alice")
+            ),
+            PathBuf::from("/home/alice/.config/MyApp")
+        );
+        assert_eq!(
+            rebase(
+                PathBuf::from("/var/log/MyApp"),
+                Path::new("/root"),
+                Path::new("/home/alice")
+            ),
+            PathBuf::from("/var/log/MyApp")
+        );
+    }
+}
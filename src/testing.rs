@@ -0,0 +1,421 @@
+/*!
+Provides support for testing code that uses this crate; only available with the `testing`
+feature.
+
+The [proptest](https://crates.io/crates/proptest) strategies [`any_app_name`](fn.any_app_name.html)
+and [`any_platform_env`](fn.any_platform_env.html) generate application names and environments
+that include the malformed and hostile cases that code feeding names into this crate should cope
+with.
+
+```rust
+use proptest::test_runner::TestRunner;
+use xdirs::testing::any_app_name;
+
+let mut runner = TestRunner::default();
+runner
+    .run(&any_app_name(), |app| {
+        let _ = xdirs::config_dir_for(&app);
+        Ok(())
+    })
+    .unwrap();
+```
+
+A [`FakeHome`](struct.FakeHome.html) creates a temporary directory tree laid out as the current
+platform's file system, with a home directory containing the usual skeleton, such as `.config`
+and `.cache`, `Library/...`, or `AppData\...`, and resolves directories against it rather than
+against the real user's home.
+
+```rust
+use xdirs::testing::FakeHome;
+use xdirs::{DirKind, DirectoryProvider};
+
+let home = FakeHome::new().unwrap();
+let config = home.dir_for(DirKind::Config, "MyApp").unwrap();
+assert!(config.starts_with(home.home()));
+assert!(config.parent().unwrap().is_dir());
+```
+*/
+
+use crate::{DirKind, DirectoryProvider, Platform};
+use proptest::collection::hash_map;
+use proptest::prelude::*;
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A temporary file system tree, laid out as a platform's, by default the current one, with a home directory
+/// containing the skeleton of the generic directories. The tree is removed when the value is
+/// dropped.
+///
+/// The generic directories are all found beneath the tree's root, including those, such as the
+/// application directory, which are not beneath the user's home. As a
+/// [`DirectoryProvider`](../trait.DirectoryProvider.html) the application-specific directories
+/// are resolved according to the platform's rules, but are not themselves created.
+///
+#[derive(Debug)]
+pub struct FakeHome {
+    platform: Platform,
+    root: PathBuf,
+    home: PathBuf,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns a strategy generating application names; these are a mix of conventional names, such
+/// as `MyApp` or `my-app`, and hostile names containing path separators, parent references,
+/// control characters, Windows reserved device names, and non-ASCII text.
+///
+pub fn any_app_name() -> impl Strategy<Value = String> {
+    prop_oneof![
+        4 => "[A-Za-z][A-Za-z0-9]{0,15}",
+        2 => "[a-z][a-z0-9]{0,7}([-_. ][a-z0-9]{1,7}){0,3}",
+        1 => prop::sample::select(HOSTILE_NAMES).prop_map(str::to_string),
+        1 => "[./\\\\]{1,4}[A-Za-z]{0,8}",
+        1 => "[A-Za-z]{1,4}[\\x00-\\x1f/\\\\:*?\"<>|][A-Za-z]{0,4}",
+        1 => "\\PC{1,24}",
+    ]
+}
+
+///
+/// Returns a strategy generating sets of environment variables that affect directory resolution,
+/// such as `HOME`, the `XDG_*` base directory variables, and their Windows equivalents. Each
+/// variable may be absent, empty, relative, or an absolute path.
+///
+pub fn any_platform_env() -> impl Strategy<Value = HashMap<String, String>> {
+    hash_map(
+        prop::sample::select(ENV_VARS).prop_map(str::to_string),
+        any_env_value(),
+        0..ENV_VARS.len(),
+    )
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FakeHome {
+    ///
+    /// Create a new, uniquely named, tree beneath the system's temporary directory, laid out as
+    /// the current platform's.
+    ///
+    pub fn new() -> io::Result<Self> {
+        Self::with_platform(Platform::current())
+    }
+
+    ///
+    /// Create a new, uniquely named, tree beneath the system's temporary directory, laid out as
+    /// `platform`'s; directories resolved against it also follow `platform`'s rules.
+    ///
+    pub fn with_platform(platform: Platform) -> io::Result<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let root = std::env::temp_dir().join(format!(
+            "xdirs-fake-home-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let home = platform.default_home_dir(&root, FAKE_USER);
+        let fake_home = Self {
+            platform,
+            root,
+            home,
+        };
+        std::fs::create_dir_all(&fake_home.home)?;
+        for kind in DirKind::all() {
+            if let Some(path) = fake_home.dir(kind) {
+                std::fs::create_dir_all(path)?;
+            }
+        }
+        Ok(fake_home)
+    }
+
+    ///
+    /// Returns the root of the tree, which stands in for the root of the file system.
+    ///
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    ///
+    /// Returns the fake user's home directory.
+    ///
+    pub fn home(&self) -> &Path {
+        &self.home
+    }
+
+    ///
+    /// Returns the platform the tree is laid out as.
+    ///
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    fn generic(&self, kind: DirKind) -> Option<PathBuf> {
+        self.platform.default_dir(&self.root, &self.home, kind)
+    }
+}
+
+impl DirectoryProvider for FakeHome {
+    fn home_dir(&self) -> Option<PathBuf> {
+        Some(self.home.clone())
+    }
+
+    fn application_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Application)
+    }
+
+    fn application_shared_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::ApplicationShared)
+    }
+
+    fn user_application_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::UserApplication)
+    }
+
+    fn user_application_shared_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::UserApplicationShared)
+    }
+
+    fn cache_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Cache)
+    }
+
+    fn config_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Config)
+    }
+
+    fn config_local_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::ConfigLocal)
+    }
+
+    fn data_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Data)
+    }
+
+    fn data_local_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::DataLocal)
+    }
+
+    fn favorites_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Favorites)
+    }
+
+    fn log_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Log)
+    }
+
+    fn system_log_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::SystemLog)
+    }
+
+    fn preference_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Preference)
+    }
+
+    fn state_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::State)
+    }
+
+    fn template_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Template)
+    }
+
+    fn saved_games_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::SavedGames)
+    }
+
+    fn icons_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Icons)
+    }
+
+    fn themes_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Themes)
+    }
+
+    fn mime_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Mime)
+    }
+
+    fn public_data_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::PublicData)
+    }
+
+    fn shared_documents_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::SharedDocuments)
+    }
+
+    fn wallpaper_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Wallpaper)
+    }
+
+    fn glib_schemas_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::GlibSchemas)
+    }
+
+    fn keyfile_backend_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::KeyfileBackend)
+    }
+
+    fn crash_dumps_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::CrashDumps)
+    }
+
+    fn temp_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Temp)
+    }
+
+    fn document_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Document)
+    }
+
+    fn download_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Download)
+    }
+
+    fn desktop_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Desktop)
+    }
+
+    fn public_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::PublicShare)
+    }
+
+    fn system_template_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::SystemTemplate)
+    }
+
+    fn dir_for(&self, kind: DirKind, app: &str) -> Option<PathBuf> {
+        self.platform.dir_for(self, kind, app)
+    }
+}
+
+impl Drop for FakeHome {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const FAKE_USER: &str = "xdirs";
+
+const HOSTILE_NAMES: &[&str] = &[
+    "",
+    ".",
+    "..",
+    "../../etc",
+    "/",
+    "/etc",
+    "\\",
+    "C:\\Windows",
+    "a/b",
+    "a\\b",
+    "nul\0byte",
+    "CON",
+    "nul",
+    "COM1",
+    "LPT9.txt",
+    "trailing.",
+    "trailing ",
+    " leading",
+    "~",
+    "$HOME",
+    "%APPDATA%",
+];
+
+const ENV_VARS: &[&str] = &[
+    "HOME",
+    "XDG_CACHE_HOME",
+    "XDG_CONFIG_HOME",
+    "XDG_DATA_HOME",
+    "XDG_STATE_HOME",
+    "XDG_RUNTIME_DIR",
+    "XDG_DATA_DIRS",
+    "XDG_CONFIG_DIRS",
+    "USERPROFILE",
+    "APPDATA",
+    "LOCALAPPDATA",
+];
+
+fn any_env_value() -> impl Strategy<Value = String> {
+    prop_oneof![
+        4 => "(/[a-z][a-z0-9._-]{0,8}){1,4}",
+        1 => "[A-Z]:(\\\\[A-Za-z][A-Za-z0-9 ._-]{0,8}){1,4}",
+        1 => "[a-z][a-z0-9._-]{0,8}(/[a-z]{1,8}){0,2}",
+        1 => Just(String::new()),
+        1 => "\\PC{0,16}",
+    ]
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{override_root, try_dir_for, DirKind};
+
+    #[test]
+    fn test_fake_home() {
+        let fake = FakeHome::new().unwrap();
+        let root = fake.root().to_path_buf();
+        assert!(fake.home().starts_with(&root));
+        assert!(fake.home().is_dir());
+        for kind in DirKind::all() {
+            if let Some(path) = fake.dir(kind) {
+                assert!(path.starts_with(&root));
+                assert!(path.is_dir());
+            }
+            if let Some(path) = fake.dir_for(kind, "Chrome") {
+                assert!(path.starts_with(&root));
+            }
+        }
+        assert_eq!(
+            fake.dir(DirKind::Config).is_some(),
+            crate::config_dir().is_some()
+        );
+        drop(fake);
+        assert!(!root.exists());
+    }
+
+    #[test]
+    fn test_fake_home_with_platform() {
+        let fake = FakeHome::with_platform(Platform::MacOS).unwrap();
+        assert!(fake.root().join("Applications").is_dir());
+        assert_eq!(
+            fake.dir_for(DirKind::AppContainer, "Chrome"),
+            Some(fake.root().join("Applications").join("Chrome.app"))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_app_names_resolve_without_panic(app in any_app_name()) {
+            let _guard = override_root(std::env::temp_dir().join("xdirs-proptest"));
+            for kind in DirKind::all() {
+                if let Some(path) = kind.dir_for(&app) {
+                    prop_assert!(path.is_absolute());
+                }
+                let _ = try_dir_for(kind, &app);
+            }
+        }
+
+        #[test]
+        fn test_platform_env_values(env in any_platform_env()) {
+            for (name, _) in env {
+                prop_assert!(ENV_VARS.contains(&name.as_str()));
+            }
+        }
+    }
+}
@@ -0,0 +1,452 @@
+/*!
+Provides transactional directory operations, for installers that must be able to back out a
+partially-failed reorganization of an application's directories.
+
+A [`Transaction`](struct.Transaction.html) stages each change so that it may be undone: created
+directories are recorded, moves are recorded so that they may be reversed, and removed directories
+are renamed aside until the transaction is committed. The steps taken are written to an undo
+journal in the application's state directory, so that a transaction interrupted by a crash
+may be rolled back later with [`rollback`](fn.rollback.html).
+*/
+
+use crate::audit::{self, missing_dirs, Operation};
+use crate::{DirKind, Error};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A set of directory changes that are either all committed, or all rolled back. A transaction
+/// that is dropped without being committed is rolled back.
+///
+/// ```rust,no_run
+/// use xdirs::{DirKind, Transaction};
+///
+/// let mut transaction = Transaction::for_app("MyApp")?;
+/// let data = transaction.create_dir_for(DirKind::Data, "MyApp")?;
+/// transaction.move_dir("/tmp/MyApp-import", data.join("imported"))?;
+/// transaction.commit()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+#[derive(Debug)]
+pub struct Transaction {
+    steps: Vec<Step>,
+    journal: Option<(PathBuf, File)>,
+    finished: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Roll back the last transaction of `app` that was neither committed nor rolled back, for
+/// example because the process exited while it was in progress. Returns `true` if there was
+/// such a transaction.
+///
+pub fn rollback(app: &str) -> io::Result<bool> {
+    let path = journal_path(app)?;
+    if !path.is_file() {
+        return Ok(false);
+    }
+    let steps = read_journal(&path)?;
+    undo(&steps)?;
+    fs::remove_file(&path)?;
+    Ok(true)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Transaction {
+    ///
+    /// Construct a transaction with no undo journal; it may be rolled back, but not after the
+    /// process exits.
+    ///
+    pub fn new() -> Self {
+        Self {
+            steps: Default::default(),
+            journal: None,
+            finished: false,
+        }
+    }
+
+    ///
+    /// Construct a transaction for `app`, with an undo journal in the application's state
+    /// directory. It is an error, of kind `AlreadyExists`, if an earlier transaction for the
+    /// application was neither committed nor rolled back; see [`rollback`](fn.rollback.html).
+    ///
+    pub fn for_app(app: &str) -> io::Result<Self> {
+        let path = journal_path(app)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        Ok(Self {
+            steps: Default::default(),
+            journal: Some((path, file)),
+            finished: false,
+        })
+    }
+
+    ///
+    /// Create the directory `path`, and any missing parents.
+    ///
+    pub fn create_dir<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        self.create(path.as_ref(), None)
+    }
+
+    ///
+    /// Create the application-specific form of the directory `kind`, and any missing parents,
+    /// returning its path; errors are reported as for
+    /// [`create_dir_for`](fn.create_dir_for.html).
+    ///
+    pub fn create_dir_for(&mut self, kind: DirKind, app: &str) -> io::Result<PathBuf> {
+        let path = kind
+            .dir_for(app)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, Error::Unavailable(kind)))?;
+        self.create(&path, Some(kind))?;
+        Ok(path)
+    }
+
+    ///
+    /// Move the directory `from` to `to`, creating any missing parents of `to`; both must be on
+    /// the same file system.
+    ///
+    pub fn move_dir<P: AsRef<Path>, Q: AsRef<Path>>(&mut self, from: P, to: Q) -> io::Result<()> {
+        let (from, to) = (from.as_ref(), to.as_ref());
+        if let Some(parent) = to.parent() {
+            self.create(parent, None)?;
+        }
+        fs::rename(from, to)?;
+        audit::record(Operation::Move, to, None, REASON);
+        self.push(Step::Moved(from.to_path_buf(), to.to_path_buf()))
+    }
+
+    ///
+    /// Remove the directory `path`, and its contents. The directory is renamed aside, and only
+    /// deleted when the transaction is committed.
+    ///
+    pub fn remove_dir<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let staged = staged_path(path)?;
+        fs::rename(path, &staged)?;
+        self.push(Step::Removed(path.to_path_buf(), staged))
+    }
+
+    ///
+    /// Commit the transaction; directories removed are now deleted, and the undo journal is
+    /// removed.
+    ///
+    pub fn commit(mut self) -> io::Result<()> {
+        self.finished = true;
+        for step in &self.steps {
+            if let Step::Removed(path, staged) = step {
+                fs::remove_dir_all(staged)?;
+                audit::record(Operation::Delete, path, None, REASON);
+            }
+        }
+        self.remove_journal()
+    }
+
+    ///
+    /// Roll back the transaction, undoing each change in the reverse order to which it was made.
+    ///
+    pub fn rollback(mut self) -> io::Result<()> {
+        self.finished = true;
+        undo(&self.steps)?;
+        self.remove_journal()
+    }
+
+    fn create(&mut self, path: &Path, kind: Option<DirKind>) -> io::Result<()> {
+        for created in missing_dirs(path) {
+            fs::create_dir(&created)?;
+            audit::record(Operation::Create, &created, kind, REASON);
+            self.push(Step::Created(created))?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, step: Step) -> io::Result<()> {
+        let line = step.to_line();
+        // the step is retained even if it cannot be journaled, so that it is still rolled back.
+        self.steps.push(step);
+        if let Some((_, file)) = &mut self.journal {
+            writeln!(file, "{}", line?)?;
+            file.sync_all()?;
+        }
+        Ok(())
+    }
+
+    fn remove_journal(&mut self) -> io::Result<()> {
+        match self.journal.take() {
+            Some((path, file)) => {
+                drop(file);
+                fs::remove_file(path)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for Transaction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = undo(&self.steps);
+            let _ = self.remove_journal();
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Step {
+    Created(PathBuf),
+    Moved(PathBuf, PathBuf),
+    Removed(PathBuf, PathBuf),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const REASON: &str = "Transaction";
+
+const JOURNAL_FILE_NAME: &str = "transaction.journal";
+
+impl Step {
+    fn to_line(&self) -> io::Result<String> {
+        Ok(match self {
+            Step::Created(path) => format!("create\t{}", journal_str(path)?),
+            Step::Moved(from, to) => format!("move\t{}\t{}", journal_str(from)?, journal_str(to)?),
+            Step::Removed(path, staged) => {
+                format!("remove\t{}\t{}", journal_str(path)?, journal_str(staged)?)
+            }
+        })
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields.as_slice() {
+            ["create", path] => Some(Step::Created(PathBuf::from(path))),
+            ["move", from, to] => Some(Step::Moved(PathBuf::from(from), PathBuf::from(to))),
+            ["remove", path, staged] => {
+                Some(Step::Removed(PathBuf::from(path), PathBuf::from(staged)))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn journal_path(app: &str) -> io::Result<PathBuf> {
+    crate::state_dir_for(app)
+        .map(|path| path.join(JOURNAL_FILE_NAME))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, Error::Unavailable(DirKind::State)))
+}
+
+fn journal_str(path: &Path) -> io::Result<&str> {
+    match path.to_str() {
+        Some(s) if !s.contains(['\t', '\n', '\r']) => Ok(s),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the path cannot be recorded in the undo journal",
+        )),
+    }
+}
+
+fn read_journal(path: &Path) -> io::Result<Vec<Step>> {
+    let mut steps = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        match Step::from_line(&line) {
+            Some(step) => steps.push(step),
+            // a partially written last line is ignored.
+            None if line.is_empty() => {}
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "the undo journal is not valid",
+                ))
+            }
+        }
+    }
+    Ok(steps)
+}
+
+///
+/// Returns an unused path beside `path` to which it may be renamed aside; the name includes the
+/// process id and a counter, so that neither another process nor another removal of the same
+/// path in this process may use it.
+///
+fn staged_path(path: &Path) -> io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => loop {
+            let mut staged = std::ffi::OsString::from(".");
+            staged.push(name);
+            staged.push(format!(
+                ".xdirs-removed-{}-{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            let staged = parent.join(staged);
+            if fs::symlink_metadata(&staged).is_err() {
+                return Ok(staged);
+            }
+        },
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the root directory cannot be removed",
+        )),
+    }
+}
+
+fn undo(steps: &[Step]) -> io::Result<()> {
+    for step in steps.iter().rev() {
+        match step {
+            Step::Created(path) => {
+                if path.exists() {
+                    fs::remove_dir_all(path)?;
+                    audit::record(Operation::Delete, path, None, REASON);
+                }
+            }
+            Step::Moved(from, to) => {
+                if to.exists() && !from.exists() {
+                    fs::rename(to, from)?;
+                    audit::record(Operation::Move, from, None, REASON);
+                }
+            }
+            Step::Removed(path, staged) => {
+                if staged.exists() && !path.exists() {
+                    fs::rename(staged, path)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("xdirs-tx-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_rollback() {
+        let root = test_root("rollback");
+        let (kept, moved, removed) = (root.join("kept"), root.join("moved"), root.join("removed"));
+        fs::create_dir(&kept).unwrap();
+        fs::create_dir(&removed).unwrap();
+
+        let mut transaction = Transaction::new();
+        transaction.create_dir(root.join("new/child")).unwrap();
+        transaction.move_dir(&kept, &moved).unwrap();
+        transaction.remove_dir(&removed).unwrap();
+        assert!(root.join("new/child").is_dir() && moved.is_dir() && !removed.exists());
+        transaction.rollback().unwrap();
+
+        assert!(!root.join("new").exists());
+        assert!(kept.is_dir() && !moved.exists() && removed.is_dir());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_commit() {
+        let root = test_root("commit");
+        let removed = root.join("removed");
+        fs::create_dir(&removed).unwrap();
+
+        let mut transaction = Transaction::new();
+        transaction.create_dir(root.join("new")).unwrap();
+        transaction.remove_dir(&removed).unwrap();
+        transaction.commit().unwrap();
+
+        assert!(root.join("new").is_dir() && !removed.exists());
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 1);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_drop_rolls_back() {
+        let root = test_root("drop");
+        {
+            let mut transaction = Transaction::new();
+            transaction.create_dir(root.join("new")).unwrap();
+        }
+        assert!(!root.join("new").exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_remove_same_path_twice() {
+        let root = test_root("twice");
+        let removed = root.join("removed");
+        fs::create_dir(&removed).unwrap();
+        fs::write(removed.join("first"), "").unwrap();
+
+        let mut transaction = Transaction::new();
+        transaction.remove_dir(&removed).unwrap();
+        fs::create_dir(&removed).unwrap();
+        fs::write(removed.join("second"), "").unwrap();
+        transaction.remove_dir(&removed).unwrap();
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 2);
+        transaction.commit().unwrap();
+
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 0);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_journal() {
+        let app = "xdirs-tx-journal";
+        let root = test_root("journal");
+        let _guard = crate::override_root(root.join("home"));
+        let mut transaction = Transaction::for_app(app).unwrap();
+        transaction.create_dir(root.join("new")).unwrap();
+        assert!(Transaction::for_app(app).is_err());
+        assert!(journal_path(app).unwrap().starts_with(&root));
+        // simulate the process exiting without committing.
+        std::mem::forget(transaction);
+
+        assert!(root.join("new").is_dir());
+        assert!(rollback(app).unwrap());
+        assert!(!root.join("new").exists());
+        assert!(!rollback(app).unwrap());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_step_lines() {
+        let step = Step::Moved(PathBuf::from("/a b"), PathBuf::from("/c"));
+        assert_eq!(Step::from_line(&step.to_line().unwrap()), Some(step));
+        assert!(Step::Created(PathBuf::from("/a\tb")).to_line().is_err());
+    }
+}
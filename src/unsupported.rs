@@ -0,0 +1,268 @@
+/*!
+The value of each directory, and directory-related setting, on those platforms that do not
+support it. Each `sys` module re-exports from here the functions it does not implement, rather
+than repeating the same stub.
+*/
+
+use crate::{DirKind, FolderId};
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn application_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn application_shared_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn user_application_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn user_application_shared_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn executable_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn document_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn download_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn desktop_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn public_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn favorites_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn log_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_log_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn template_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_template_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn saved_games_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn icons_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn themes_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn crash_dumps_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn known_folder_dir(_: FolderId) -> Option<PathBuf> {
+    None
+}
+
+pub fn runtime_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn pipe_name_for(_: &str) -> Option<String> {
+    None
+}
+
+pub fn trash_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn recent_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn extra_plugin_dirs_for(_: &str) -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn system_locale_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn sandbox_container_id() -> Option<String> {
+    None
+}
+
+pub fn sandbox_container_dir_for(_: &str) -> Option<PathBuf> {
+    None
+}
+
+pub fn flatpak_id() -> Option<String> {
+    None
+}
+
+pub fn snap_name() -> Option<String> {
+    None
+}
+
+pub fn sandboxed_app_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn snap_user_common_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn host_home_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn package_family_name() -> Option<String> {
+    None
+}
+
+pub fn package_dir_for(_: &str) -> Option<PathBuf> {
+    None
+}
+
+pub fn group_container_dir_for(_: &str) -> Option<PathBuf> {
+    None
+}
+
+pub fn installed_container_dir_for(_: DirKind, _: &str) -> Option<PathBuf> {
+    None
+}
+
+pub fn registered_install_dir_for(_: &str) -> Option<PathBuf> {
+    None
+}
+
+pub fn crash_reports_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_crash_reports_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn icloud_drive_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn icloud_container_dir_for(_: &str) -> Option<PathBuf> {
+    None
+}
+
+pub fn application_scripts_dir_for(_: &str) -> Option<PathBuf> {
+    None
+}
+
+pub fn launch_agents_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_launch_agents_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn launch_daemons_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn autostart_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_autostart_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn desktop_entry_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_desktop_entry_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn mime_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_mime_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn glib_schemas_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_glib_schemas_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn keyfile_backend_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn public_data_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn shared_documents_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn font_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_font_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn man_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_man_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn wallpaper_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn system_wallpaper_dir() -> Option<PathBuf> {
+    None
+}
+
+pub fn fallback_source(_: DirKind) -> Option<&'static str> {
+    None
+}
+
+pub fn user_id() -> Option<String> {
+    None
+}
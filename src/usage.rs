@@ -0,0 +1,183 @@
+/*!
+Provides the disk space used by an application's directories, for example for a "storage used"
+display in its settings.
+*/
+
+use crate::{DirKind, Error};
+use std::fs::{read_dir, symlink_metadata};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The disk space used by one of an application's directories.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirUsage {
+    /// The kind of directory.
+    pub kind: DirKind,
+    /// The directory's path.
+    pub path: PathBuf,
+    /// The total size, in bytes, of the files within the directory.
+    pub size: u64,
+}
+
+///
+/// The disk space used by each of an application's directories, as returned by
+/// [`usage_report_for`](fn.usage_report_for.html).
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UsageReport {
+    /// Each of the application's directories that exists.
+    pub dirs: Vec<DirUsage>,
+    /// The total size, in bytes, of all of the directories; where one directory is the same as,
+    /// or within, another it is only counted once.
+    pub total: u64,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the total size, in bytes, of the files within the application-specific form of the
+/// directory `kind`; a directory that does not exist has a size of zero. Symbolic links are not
+/// followed.
+///
+/// If the directory has no value on the current platform the returned error has the kind
+/// `NotFound`, and wraps an [`Error::Unavailable`](enum.Error.html#variant.Unavailable).
+///
+/// ```rust
+/// use xdirs::{dir_size, DirKind};
+///
+/// if let Ok(size) = dir_size(DirKind::Cache, "MyApp") {
+///     println!("the cache uses {} bytes", size);
+/// }
+/// ```
+///
+pub fn dir_size(kind: DirKind, app: &str) -> io::Result<u64> {
+    match kind.dir_for(app) {
+        Some(path) => size_of(&path),
+        None => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            Error::Unavailable(kind),
+        )),
+    }
+}
+
+///
+/// Returns the disk space used by each of the cache, config, data, and log directories of a
+/// specific application, and their total.
+///
+pub fn usage_report_for(app: &str) -> io::Result<UsageReport> {
+    let mut report = UsageReport::default();
+    for kind in REPORTED_KINDS {
+        if let Some(path) = kind.dir_for(app).filter(|path| path.is_dir()) {
+            if !report.dirs.iter().any(|dir| dir.path == path) {
+                report.dirs.push(DirUsage {
+                    kind: *kind,
+                    size: size_of(&path)?,
+                    path,
+                });
+            }
+        }
+    }
+    report.total = report
+        .dirs
+        .iter()
+        .filter(|dir| {
+            !report
+                .dirs
+                .iter()
+                .any(|other| other.path != dir.path && dir.path.starts_with(&other.path))
+        })
+        .map(|dir| dir.size)
+        .sum();
+    Ok(report)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the total size of `path`, and the most recent modification time of it or anything
+/// within it; symbolic links are not followed. Entries removed while the directory is walked are
+/// skipped, but if `path` itself does not exist the error is returned.
+///
+pub(crate) fn disk_usage(path: &Path) -> io::Result<(u64, SystemTime)> {
+    let metadata = symlink_metadata(path)?;
+    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    if metadata.is_dir() {
+        let mut total = (0, modified);
+        for entry in read_dir(path)? {
+            let (size, modified) = match disk_usage(&entry?.path()) {
+                Ok(usage) => usage,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+            total = (total.0 + size, total.1.max(modified));
+        }
+        Ok(total)
+    } else {
+        Ok((metadata.len(), modified))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const REPORTED_KINDS: &[DirKind] = &[
+    DirKind::Cache,
+    DirKind::Config,
+    DirKind::ConfigLocal,
+    DirKind::Data,
+    DirKind::DataLocal,
+    DirKind::Log,
+];
+
+fn size_of(path: &Path) -> io::Result<u64> {
+    // a directory that does not exist has a size of zero.
+    match disk_usage(path) {
+        Ok((size, _)) => Ok(size),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(0),
+        Err(e) => Err(e),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+
+    #[test]
+    fn test_size_of() {
+        let root = std::env::temp_dir().join(format!("xdirs-usage-{}", std::process::id()));
+        create_dir_all(root.join("a/b")).unwrap();
+        write(root.join("a/b/c"), [0u8; 8]).unwrap();
+        write(root.join("d"), [0u8; 4]).unwrap();
+        assert_eq!(size_of(&root).unwrap(), 12);
+        remove_dir_all(&root).unwrap();
+        assert_eq!(size_of(&root).unwrap(), 0);
+        assert_eq!(
+            disk_usage(&root).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn test_usage_report_for() {
+        let report = usage_report_for("xdirs-no-such-app").unwrap();
+        assert!(report.dirs.is_empty());
+        assert_eq!(report.total, 0);
+    }
+}
@@ -0,0 +1,310 @@
+/*!
+Provides the resolution of the directories of a user other than the current one, given their home
+directory or user name, for backup tools, multi-user daemons, and migration utilities.
+
+The directories are laid out according to the current platform's rules, at their default
+locations beneath the given home directory; environment variables, such as `XDG_CONFIG_HOME`,
+and Known Folder redirection apply only to the current user and so are ignored.
+*/
+
+use crate::naming::naming;
+use crate::nesting::nesting_scheme;
+use crate::{override_root, DirKind, DirectoryProvider, Platform};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the home directory of the user named `user`; on Unix platforms this is as recorded in
+/// the user database, such as `/etc/passwd` or a directory service configured with NSS,
+/// otherwise, or if the user is not listed there, it is the platform's default location for the
+/// user's home, such as `/Users/{user}` on macOS, if that directory exists.
+///
+/// ```rust
+/// use xdirs::home_dir_for_user;
+///
+/// if cfg!(target_os = "linux") {
+///     assert_eq!(home_dir_for_user("root"), Some("/root".into()));
+/// }
+/// ```
+///
+pub fn home_dir_for_user(user: &str) -> Option<PathBuf> {
+    if user.is_empty() {
+        return None;
+    }
+    #[cfg(unix)]
+    let listed = crate::passwd::home_dir_for_name(user);
+    #[cfg(not(unix))]
+    let listed = None;
+    listed.or_else(|| {
+        let root = crate::sys::home_dir().map_or_else(|| PathBuf::from("/"), |home| root_of(&home));
+        Some(Platform::current().default_home_dir(&root, user)).filter(|home| home.is_dir())
+    })
+}
+
+///
+/// Returns the application-specific form of the directory `kind` for the user whose home
+/// directory is `home`, laid out according to the current platform's rules, the application's
+/// naming policy and nesting scheme, at the default locations beneath `home`. Directories that are
+/// not beneath the home directory, such as the system-wide application directory, are at their
+/// default locations on the same file system.
+///
+/// ```rust
+/// use xdirs::{dir_for_user, DirKind};
+///
+/// let home = if cfg!(windows) { "C:\\Users\\bob" } else { "/home/bob" };
+/// let config = dir_for_user(DirKind::Config, "MyApp", home.as_ref()).unwrap();
+/// assert!(config.starts_with(home));
+/// ```
+///
+pub fn dir_for_user(kind: DirKind, app: &str, home: &Path) -> Option<PathBuf> {
+    let provider = HomeProvider::new(home, None);
+    override_root::apply(Platform::current().named_dir_for(
+        &provider,
+        kind,
+        app,
+        &naming(app),
+        nesting_scheme(app),
+    ))
+}
+
+///
+/// Returns the path to the cache directory for a specific application of the user whose home
+/// directory is `home`. See [`dir_for_user`](fn.dir_for_user.html).
+///
+pub fn cache_dir_for_user(app: &str, home: &Path) -> Option<PathBuf> {
+    dir_for_user(DirKind::Cache, app, home)
+}
+
+///
+/// Returns the path to the config directory for a specific application of the user whose home
+/// directory is `home`. See [`dir_for_user`](fn.dir_for_user.html).
+///
+pub fn config_dir_for_user(app: &str, home: &Path) -> Option<PathBuf> {
+    dir_for_user(DirKind::Config, app, home)
+}
+
+///
+/// Returns the path to the data directory for a specific application of the user whose home
+/// directory is `home`. See [`dir_for_user`](fn.dir_for_user.html).
+///
+pub fn data_dir_for_user(app: &str, home: &Path) -> Option<PathBuf> {
+    dir_for_user(DirKind::Data, app, home)
+}
+
+///
+/// Returns the path to the local data directory for a specific application of the user whose
+/// home directory is `home`. See [`dir_for_user`](fn.dir_for_user.html).
+///
+pub fn data_local_dir_for_user(app: &str, home: &Path) -> Option<PathBuf> {
+    dir_for_user(DirKind::DataLocal, app, home)
+}
+
+///
+/// Returns the path to the log directory for a specific application of the user whose home
+/// directory is `home`. See [`dir_for_user`](fn.dir_for_user.html).
+///
+pub fn log_dir_for_user(app: &str, home: &Path) -> Option<PathBuf> {
+    dir_for_user(DirKind::Log, app, home)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A provider whose generic directories are the platform's defaults beneath a given home
+/// directory, unless relocated by the platform's environment variables in `vars`.
+///
+pub(crate) struct HomeProvider<'a> {
+    root: PathBuf,
+    home: PathBuf,
+    vars: Option<&'a HashMap<String, OsString>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> HomeProvider<'a> {
+    pub(crate) fn new(home: &Path, vars: Option<&'a HashMap<String, OsString>>) -> Self {
+        Self {
+            root: root_of(home),
+            home: home.to_path_buf(),
+            vars,
+        }
+    }
+
+    fn generic(&self, kind: DirKind) -> Option<PathBuf> {
+        let platform = Platform::current();
+        self.vars
+            .and_then(|vars| platform.env_dir(vars, kind))
+            .or_else(|| platform.default_dir(&self.root, &self.home, kind))
+    }
+}
+
+impl DirectoryProvider for HomeProvider<'_> {
+    fn home_dir(&self) -> Option<PathBuf> {
+        Some(self.home.clone())
+    }
+
+    fn application_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Application)
+    }
+
+    fn application_shared_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::ApplicationShared)
+    }
+
+    fn user_application_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::UserApplication)
+    }
+
+    fn user_application_shared_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::UserApplicationShared)
+    }
+
+    fn cache_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Cache)
+    }
+
+    fn config_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Config)
+    }
+
+    fn config_local_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::ConfigLocal)
+    }
+
+    fn data_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Data)
+    }
+
+    fn data_local_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::DataLocal)
+    }
+
+    fn favorites_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Favorites)
+    }
+
+    fn log_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Log)
+    }
+
+    fn system_log_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::SystemLog)
+    }
+
+    fn preference_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Preference)
+    }
+
+    fn state_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::State)
+    }
+
+    fn template_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Template)
+    }
+
+    fn saved_games_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::SavedGames)
+    }
+
+    fn icons_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Icons)
+    }
+
+    fn themes_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Themes)
+    }
+
+    fn mime_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Mime)
+    }
+
+    fn public_data_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::PublicData)
+    }
+
+    fn shared_documents_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::SharedDocuments)
+    }
+
+    fn wallpaper_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Wallpaper)
+    }
+
+    fn glib_schemas_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::GlibSchemas)
+    }
+
+    fn keyfile_backend_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::KeyfileBackend)
+    }
+
+    fn crash_dumps_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::CrashDumps)
+    }
+
+    fn temp_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Temp)
+    }
+
+    fn document_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Document)
+    }
+
+    fn download_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Download)
+    }
+
+    fn desktop_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::Desktop)
+    }
+
+    fn public_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::PublicShare)
+    }
+
+    fn system_template_dir(&self) -> Option<PathBuf> {
+        self.generic(DirKind::SystemTemplate)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the root of the file system on which `path` lies, such as `/` or `C:\`.
+///
+fn root_of(path: &Path) -> PathBuf {
+    path.ancestors()
+        .last()
+        .map_or_else(PathBuf::new, Path::to_path_buf)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir_for_user() {
+        let home = Platform::current().default_home_dir(&root_of(&std::env::temp_dir()), "bob");
+        assert!(cache_dir_for_user("Chrome", &home)
+            .unwrap()
+            .starts_with(&home));
+        assert_eq!(home_dir_for_user(""), None);
+        assert_eq!(home_dir_for_user("xdirs-no-such-user"), None);
+    }
+}
@@ -0,0 +1,351 @@
+/*!
+Provides direct access to the XDG user directories file, `user-dirs.dirs`, which records the
+locations of the user's well-known directories, such as Downloads and Templates, including those
+that have no function of their own in this crate.
+
+The file is read and written as `xdg-user-dirs-update` does; each entry has the form
+`XDG_{NAME}_DIR="$HOME/relative"` or `XDG_{NAME}_DIR="/absolute"`, and all other lines, such as
+comments, are preserved when an entry is updated.
+*/
+
+use crate::xdg::resolve;
+use crate::BaseKind;
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, read_to_string, remove_file, rename, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path of the user directories file, `$XDG_CONFIG_HOME/user-dirs.dirs`, whether or
+/// not it exists.
+///
+pub fn user_dirs_file() -> Option<PathBuf> {
+    crate::override_root::apply(
+        resolve(BaseKind::ConfigHome)
+            .value
+            .map(|path| path.join(F_USER_DIRS)),
+    )
+}
+
+///
+/// Returns every entry in the user directories file, keyed by name, such as `DOWNLOAD` for
+/// `XDG_DOWNLOAD_DIR`. A file that does not exist has no entries, and entries whose value is not
+/// an absolute path or relative to `$HOME` are ignored, as they are by `xdg-user-dirs`.
+///
+/// ```rust
+/// use xdirs::read_user_dirs;
+///
+/// for (name, path) in read_user_dirs().unwrap_or_default() {
+///     println!("{} = {:?}", name, path);
+/// }
+/// ```
+///
+pub fn read_user_dirs() -> io::Result<BTreeMap<String, PathBuf>> {
+    let (file, home) = locations()?;
+    match read_to_string(file) {
+        Ok(contents) => Ok(parse(&contents, &home)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e),
+    }
+}
+
+///
+/// Returns the entry named `name`, such as `TEMPLATES`, from the user directories file, if it is
+/// present.
+///
+pub fn user_dir(name: &str) -> Option<PathBuf> {
+    read_user_dirs().ok()?.remove(name)
+}
+
+///
+/// Set the entry named `name`, such as `TEMPLATES`, in the user directories file to `path`, as
+/// `xdg-user-dirs-update --set` does; the entry is replaced if present and appended otherwise, and
+/// the file is created if necessary. A path beneath the home directory is written relative to
+/// `$HOME`.
+///
+/// The file is replaced atomically, and previously resolved directories are discarded, as by
+/// [`refresh`](fn.refresh.html), so that the change is seen by functions such as
+/// [`template_dir`](fn.template_dir.html). The directory itself is not created. The file is only
+/// used on the platforms that follow the XDG specifications; elsewhere the returned error has the
+/// kind `Unsupported`.
+///
+/// ```rust,no_run
+/// use xdirs::set_user_dir;
+///
+/// set_user_dir("DOWNLOAD", "/data/downloads".as_ref()).unwrap();
+/// ```
+///
+pub fn set_user_dir(name: &str, path: &Path) -> io::Result<()> {
+    if !IS_XDG {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "the user directories file is not used on this platform",
+        ));
+    }
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid user directory name {:?}", name),
+        ));
+    }
+    if !path.is_absolute() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("user directory {:?} is not absolute", path),
+        ));
+    }
+    let (file, home) = locations()?;
+    let contents = match read_to_string(&file) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    if let Some(parent) = file.parent() {
+        create_dir_all(parent)?;
+    }
+    let (temp, mut writer) = create_temp(&file)?;
+    let written = writer
+        .write_all(update(&contents, name, &format_value(path, &home)).as_bytes())
+        .and_then(|_| writer.sync_all())
+        .and_then(|_| rename(&temp, &file));
+    if written.is_err() {
+        let _ = remove_file(&temp);
+    }
+    written?;
+    crate::refresh();
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the entry named `name` from the user directories file, as for `user_dir`, but without
+/// any root override applied; this is for the platform modules, whose results are overridden by
+/// their callers. An entry that is the home directory itself has been disabled, as described by
+/// `xdg-user-dirs`, and is ignored.
+///
+#[cfg(not(any(
+    target_arch = "wasm32",
+    windows,
+    target_os = "android",
+    target_os = "haiku",
+    target_os = "macos",
+    target_os = "ios"
+)))]
+pub(crate) fn resolve_user_dir(name: &str) -> Option<PathBuf> {
+    let file = resolve(BaseKind::ConfigHome).value?.join(F_USER_DIRS);
+    let home = crate::sys::home_dir()?;
+    let contents = read_to_string(file).ok()?;
+    parse(&contents, &home)
+        .remove(name)
+        .filter(|path| path != &home)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const F_USER_DIRS: &str = "user-dirs.dirs";
+const IS_XDG: bool = cfg!(not(any(
+    target_arch = "wasm32",
+    windows,
+    target_os = "android",
+    target_os = "haiku",
+    target_os = "macos",
+    target_os = "ios"
+)));
+const V_HOME: &str = "$HOME";
+const P_VARIABLE: &str = "XDG_";
+const S_VARIABLE: &str = "_DIR";
+
+fn locations() -> io::Result<(PathBuf, PathBuf)> {
+    match (user_dirs_file(), crate::home_dir()) {
+        (Some(file), Some(home)) => Ok((file, home)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "the home directory is not known",
+        )),
+    }
+}
+
+///
+/// Create a new, empty, file beside `file` into which its replacement is written; the name
+/// includes the process id and a counter, so that no other writer, in this or another process,
+/// uses the same file.
+///
+fn create_temp(file: &Path) -> io::Result<(PathBuf, File)> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    loop {
+        let temp = file.with_file_name(format!(
+            ".{}.{}-{}.tmp",
+            F_USER_DIRS,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        match OpenOptions::new().write(true).create_new(true).open(&temp) {
+            Ok(writer) => return Ok((temp, writer)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+///
+/// Returns the name of the entry on `line`, and its raw, still quoted, value.
+///
+fn entry(line: &str) -> Option<(&str, &str)> {
+    let (variable, value) = line.trim().split_once('=')?;
+    let name = variable
+        .trim()
+        .strip_prefix(P_VARIABLE)?
+        .strip_suffix(S_VARIABLE)?;
+    Some((name, value.trim())).filter(|(name, _)| !name.is_empty())
+}
+
+fn parse(contents: &str, home: &Path) -> BTreeMap<String, PathBuf> {
+    contents
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(entry)
+        .filter_map(|(name, value)| parse_value(value, home).map(|path| (name.to_string(), path)))
+        .collect()
+}
+
+fn parse_value(value: &str, home: &Path) -> Option<PathBuf> {
+    let value = value.strip_prefix('"')?.strip_suffix('"')?;
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+    if unescaped == V_HOME {
+        Some(home.to_path_buf())
+    } else if let Some(relative) = unescaped.strip_prefix("$HOME/") {
+        Some(home.join(relative))
+    } else if unescaped.starts_with('/') {
+        Some(PathBuf::from(unescaped))
+    } else {
+        None
+    }
+}
+
+fn format_value(path: &Path, home: &Path) -> String {
+    let (prefix, rest) = match path.strip_prefix(home) {
+        Ok(relative) if relative.as_os_str().is_empty() => (V_HOME, String::new()),
+        Ok(relative) => (V_HOME, format!("/{}", relative.to_string_lossy())),
+        Err(_) => ("", path.to_string_lossy().into_owned()),
+    };
+    let mut value = format!("\"{}", prefix);
+    for c in rest.chars() {
+        if matches!(c, '"' | '\\' | '$' | '`') {
+            value.push('\\');
+        }
+        value.push(c);
+    }
+    value.push('"');
+    value
+}
+
+fn update(contents: &str, name: &str, value: &str) -> String {
+    let line = format!("{}{}{}={}", P_VARIABLE, name, S_VARIABLE, value);
+    let mut found = false;
+    let mut lines: Vec<String> = contents
+        .lines()
+        .map(|existing| match entry(existing) {
+            Some((existing_name, _))
+                if existing_name == name && !existing.trim_start().starts_with('#') =>
+            {
+                found = true;
+                line.clone()
+            }
+            _ => existing.to_string(),
+        })
+        .collect();
+    if !found {
+        lines.push(line);
+    }
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENTS: &str = "# This file is written by xdg-user-dirs-update\n\
+                            XDG_DESKTOP_DIR=\"$HOME/Desktop\"\n\
+                            XDG_DOWNLOAD_DIR=\"/data/My \\\"Downloads\\\"\"\n\
+                            XDG_PUBLICSHARE_DIR=\"$HOME\"\n\
+                            XDG_TEMPLATES_DIR=\"relative\"\n\
+                            # XDG_MUSIC_DIR=\"$HOME/Music\"\n";
+
+    #[test]
+    fn test_parse() {
+        let home = Path::new("/home/alice");
+        let dirs = parse(CONTENTS, home);
+        assert_eq!(dirs.len(), 3);
+        assert_eq!(dirs["DESKTOP"], PathBuf::from("/home/alice/Desktop"));
+        assert_eq!(dirs["DOWNLOAD"], PathBuf::from("/data/My \"Downloads\""));
+        assert_eq!(dirs["PUBLICSHARE"], PathBuf::from("/home/alice"));
+    }
+
+    #[test]
+    fn test_update() {
+        let home = Path::new("/home/alice");
+        let value = format_value(Path::new("/home/alice/Templates"), home);
+        assert_eq!(value, "\"$HOME/Templates\"");
+        let updated = update(CONTENTS, "TEMPLATES", &value);
+        assert!(updated.starts_with("# This file"));
+        assert_eq!(
+            parse(&updated, home)["TEMPLATES"],
+            PathBuf::from("/home/alice/Templates")
+        );
+
+        let value = format_value(Path::new("/data/$Music"), home);
+        let updated = update(&updated, "MUSIC", &value);
+        assert!(updated.contains("# XDG_MUSIC_DIR"));
+        assert_eq!(
+            parse(&updated, home)["MUSIC"],
+            PathBuf::from("/data/$Music")
+        );
+        assert_eq!(parse(&updated, home).len(), 5);
+    }
+
+    #[test]
+    fn test_set_user_dir() {
+        let root = std::env::temp_dir().join(format!("xdirs-user-dirs-{}", std::process::id()));
+        let _guard = crate::override_root(&root);
+        let path = crate::home_dir().unwrap().join("Scans");
+        if IS_XDG {
+            set_user_dir("XDIRSSCANS", &path).unwrap();
+            assert_eq!(user_dir("XDIRSSCANS"), Some(path));
+            let file = user_dirs_file().unwrap();
+            let entries: Vec<_> = std::fs::read_dir(file.parent().unwrap())
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name())
+                .collect();
+            assert_eq!(entries, vec![std::ffi::OsString::from(F_USER_DIRS)]);
+            std::fs::remove_dir_all(&root).unwrap();
+        } else {
+            assert_eq!(
+                set_user_dir("XDIRSSCANS", &path).unwrap_err().kind(),
+                io::ErrorKind::Unsupported
+            );
+        }
+    }
+}
@@ -0,0 +1,152 @@
+/*!
+Provides pre-resolution of an application's directories on a background thread, so that
+latency-sensitive applications do not pay the cost of resolution, which on some platforms
+includes calls to the Known Folder API or file system access, on first use.
+*/
+
+use crate::resolve::platform_dir_for;
+use crate::DirKind;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+use std::thread::{self, JoinHandle};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Resolve the application-specific form of each of `kinds` for `app` on a background thread,
+/// and cache the results so that subsequent calls to the corresponding `_for` functions do not
+/// repeat the work. Kinds with no application-specific form are ignored.
+///
+/// Environment overrides are still checked on every call, and so take precedence over cached
+/// values. The returned handle may be joined to wait for completion, or simply dropped.
+///
+pub fn warm(app: &str, kinds: &[DirKind]) -> JoinHandle<()> {
+    let app = app.to_string();
+    let kinds = kinds.to_vec();
+    thread::spawn(move || {
+        let _ = resolve_all(&app, &kinds);
+    })
+}
+
+///
+/// As for [`warm`](fn.warm.html), but also create each resolved directory, with the same
+/// semantics as [`create_dir_for`](fn.create_dir_for.html). The returned handle yields the
+/// first error encountered, if any.
+///
+#[cfg(feature = "create")]
+pub fn warm_and_create(app: &str, kinds: &[DirKind]) -> JoinHandle<std::io::Result<()>> {
+    let app = app.to_string();
+    let kinds = kinds.to_vec();
+    thread::spawn(move || {
+        for (kind, _) in resolve_all(&app, &kinds) {
+            let _ = crate::create_dir_for(kind, &app)?;
+        }
+        Ok(())
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the cached value for `kind` and `app`, or `None` if it has not been warmed; note
+/// that a warmed kind with no value on the current platform is `Some(None)`.
+///
+pub(crate) fn cached(kind: DirKind, app: &str) -> Option<Option<PathBuf>> {
+    let cache = match cache().read() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    cache.get(app).and_then(|kinds| kinds.get(&kind)).cloned()
+}
+
+///
+/// Discard all warmed values.
+///
+pub(crate) fn clear() {
+    let mut cache = match cache().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    cache.clear();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+type Cache = HashMap<String, HashMap<DirKind, Option<PathBuf>>>;
+
+fn cache() -> &'static RwLock<Cache> {
+    static CACHE: OnceLock<RwLock<Cache>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+fn resolve_all(app: &str, kinds: &[DirKind]) -> Vec<(DirKind, Option<PathBuf>)> {
+    let resolved: Vec<(DirKind, Option<PathBuf>)> = kinds
+        .iter()
+        .filter(|kind| kind.has_app_form())
+        .map(|kind| (*kind, platform_dir_for(*kind, app)))
+        .collect();
+    let mut cache = match cache().write() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let entry = cache.entry(app.to_string()).or_default();
+    for (kind, path) in &resolved {
+        let _ = entry.insert(*kind, path.clone());
+    }
+    resolved
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warm() {
+        let app = "xdirs-warm-test";
+        assert_eq!(cached(DirKind::Config, app), None);
+        warm(
+            app,
+            &[DirKind::Config, DirKind::Log, DirKind::ApplicationShared],
+        )
+        .join()
+        .unwrap();
+        assert_eq!(
+            cached(DirKind::Config, app),
+            Some(platform_dir_for(DirKind::Config, app))
+        );
+        assert_eq!(
+            cached(DirKind::Log, app),
+            Some(platform_dir_for(DirKind::Log, app))
+        );
+        assert_eq!(cached(DirKind::ApplicationShared, app), None);
+        assert_eq!(cached(DirKind::Cache, app), None);
+        assert_eq!(
+            crate::config_dir_for(app),
+            platform_dir_for(DirKind::Config, app)
+        );
+    }
+
+    #[cfg(feature = "create")]
+    #[test]
+    fn test_warm_and_create() {
+        let app = format!("xdirs-warm-create-{}", std::process::id());
+        warm_and_create(&app, &[DirKind::Cache])
+            .join()
+            .unwrap()
+            .unwrap();
+        let path = crate::cache_dir_for(&app).unwrap();
+        assert!(path.is_dir());
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
@@ -0,0 +1,114 @@
+pub use crate::unsupported::{
+    application_dir, application_scripts_dir_for, application_shared_dir, autostart_dir,
+    crash_dumps_dir, crash_reports_dir, desktop_dir, desktop_entry_dir, document_dir, download_dir,
+    executable_dir, extra_plugin_dirs_for, fallback_source, favorites_dir, flatpak_id, font_dir,
+    glib_schemas_dir, group_container_dir_for, host_home_dir, icloud_container_dir_for,
+    icloud_drive_dir, icons_dir, installed_container_dir_for, keyfile_backend_dir,
+    known_folder_dir, launch_agents_dir, launch_daemons_dir, log_dir, man_dir, mime_dir,
+    package_dir_for, package_family_name, pipe_name_for, public_data_dir, public_dir, recent_dir,
+    registered_install_dir_for, runtime_dir, sandbox_container_dir_for, sandbox_container_id,
+    sandboxed_app_dir, saved_games_dir, shared_documents_dir, snap_name, snap_user_common_dir,
+    system_autostart_dir, system_crash_reports_dir, system_desktop_entry_dir, system_font_dir,
+    system_glib_schemas_dir, system_launch_agents_dir, system_locale_dirs, system_log_dir,
+    system_man_dir, system_mime_dir, system_template_dir, system_wallpaper_dir, template_dir,
+    themes_dir, trash_dir, user_application_dir, user_application_shared_dir, user_id,
+    wallpaper_dir,
+};
+use crate::BaseKind;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the home directory given to a WASI module by its host in `$HOME`; the host must also
+/// have preopened the directory, or one above it, for paths beneath it to be usable. There is no
+/// file system, and so no home directory, in a browser. Without `$HOME`, the
+/// [`HomeFallback`](../enum.HomeFallback.html) strategy applies, but there is no user database.
+///
+pub fn home_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "wasi") {
+        crate::environment::var_os(E_HOME)
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| crate::home_fallback::fallback_home(|| None))
+    } else {
+        None
+    }
+}
+
+pub fn cache_dir() -> Option<PathBuf> {
+    base_dir(BaseKind::CacheHome)
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    base_dir(BaseKind::ConfigHome)
+}
+
+pub fn config_local_dir() -> Option<PathBuf> {
+    config_dir()
+}
+
+pub fn data_dir() -> Option<PathBuf> {
+    base_dir(BaseKind::DataHome)
+}
+
+pub fn data_local_dir() -> Option<PathBuf> {
+    data_dir()
+}
+
+pub fn preference_dir() -> Option<PathBuf> {
+    config_dir()
+}
+
+pub fn state_dir() -> Option<PathBuf> {
+    data_local_dir()
+}
+
+pub fn is_remote(_path: &Path) -> bool {
+    false
+}
+
+pub fn temp_dir() -> Option<PathBuf> {
+    crate::environment::var_os(E_TMPDIR)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+pub fn system_config_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn system_data_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn system_domain_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn service_dirs_for(_: &str) -> crate::ServiceDirs {
+    crate::ServiceDirs::default()
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub fn uses_system_journal() -> bool {
+    false
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const E_HOME: &str = "HOME";
+const E_TMPDIR: &str = "TMPDIR";
+
+///
+/// Returns an XDG base directory, as on Linux, but relative to the home directory provided by the
+/// host rather than the one in the password database.
+///
+fn base_dir(kind: BaseKind) -> Option<PathBuf> {
+    crate::xdg::resolve(kind).value
+}
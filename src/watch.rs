@@ -0,0 +1,145 @@
+/*!
+Provides notification of changes to the files in an application's configuration directory, so
+that an application may reload its configuration without restarting.
+*/
+
+use crate::{DirKind, Error};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::io;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A watch on a directory, as returned by [`watch_config_dir_for`](fn.watch_config_dir_for.html);
+/// the watch ends when this value is dropped.
+///
+#[derive(Debug)]
+pub struct DirWatcher {
+    path: PathBuf,
+    _watcher: RecommendedWatcher,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Watch the config directory of a specific application, as returned by
+/// [`config_dir_for`](fn.config_dir_for.html), calling `callback` with the path of each file or
+/// directory beneath it that is created, modified, or removed. The callback is called on a
+/// thread owned by the platform's watcher, until the returned `DirWatcher` is dropped; a single
+/// change, such as an editor saving a file, may result in more than one call.
+///
+/// The directory must already exist; if it has no value on the current platform the returned
+/// error has the kind `NotFound`, and wraps an
+/// [`Error::Unavailable`](enum.Error.html#variant.Unavailable).
+///
+/// ```rust,no_run
+/// use xdirs::watch_config_dir_for;
+///
+/// let _watcher = watch_config_dir_for("MyApp", |path| {
+///     println!("configuration changed: {:?}", path);
+/// })
+/// .unwrap();
+/// ```
+///
+pub fn watch_config_dir_for<F>(app: &str, callback: F) -> io::Result<DirWatcher>
+where
+    F: FnMut(&Path) + Send + 'static,
+{
+    match crate::config_dir_for(app) {
+        Some(path) => watch(path, callback),
+        None => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            Error::Unavailable(DirKind::Config),
+        )),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl DirWatcher {
+    ///
+    /// Returns the directory being watched.
+    ///
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn watch<F>(path: PathBuf, mut callback: F) -> io::Result<DirWatcher>
+where
+    F: FnMut(&Path) + Send + 'static,
+{
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+        if let Ok(event) = result {
+            if is_change(&event.kind) {
+                event.paths.iter().for_each(|path| callback(path));
+            }
+        }
+    })
+    .map_err(to_io_error)?;
+    watcher
+        .watch(&path, RecursiveMode::Recursive)
+        .map_err(to_io_error)?;
+    Ok(DirWatcher {
+        path,
+        _watcher: watcher,
+    })
+}
+
+fn is_change(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    )
+}
+
+fn to_io_error(error: notify::Error) -> io::Error {
+    match error.kind {
+        notify::ErrorKind::Io(error) => error,
+        notify::ErrorKind::PathNotFound => io::Error::new(io::ErrorKind::NotFound, error),
+        _ => io::Error::new(io::ErrorKind::Other, error),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    #[test]
+    fn test_watch() {
+        let root = std::env::temp_dir().join(format!("xdirs-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        let (sender, receiver) = channel();
+        let watcher = watch(root.clone(), move |path| {
+            let _ = sender.send(path.to_path_buf());
+        })
+        .unwrap();
+        assert_eq!(watcher.path(), root.as_path());
+
+        std::fs::write(root.join("config.toml"), "a = 1").unwrap();
+        let changed = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert!(changed.ends_with("config.toml"));
+        drop(watcher);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let error = watch(root, |_| {}).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
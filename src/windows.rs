@@ -1,90 +1,629 @@
-use dirs_next::{cache_dir, config_dir, data_dir, data_local_dir};
-use dirs_sys_next::known_folder;
-use std::path::PathBuf;
-use winapi::um::knownfolders;
+pub use crate::unsupported::{
+    application_scripts_dir_for, crash_reports_dir, executable_dir, extra_plugin_dirs_for,
+    flatpak_id, glib_schemas_dir, group_container_dir_for, icloud_container_dir_for,
+    icloud_drive_dir, icons_dir, keyfile_backend_dir, launch_agents_dir, launch_daemons_dir,
+    man_dir, mime_dir, runtime_dir, sandbox_container_dir_for, sandbox_container_id,
+    sandboxed_app_dir, snap_name, snap_user_common_dir, system_crash_reports_dir,
+    system_glib_schemas_dir, system_launch_agents_dir, system_locale_dirs, system_man_dir,
+    system_mime_dir, trash_dir, user_id, wallpaper_dir,
+};
+use crate::DirKind;
+use std::path::{Path, PathBuf};
+use windows_sys::core::GUID;
+use windows_sys::Win32::UI::Shell::{
+    FOLDERID_CommonPrograms, FOLDERID_CommonStartup, FOLDERID_CommonTemplates, FOLDERID_Desktop,
+    FOLDERID_Documents, FOLDERID_Downloads, FOLDERID_Favorites, FOLDERID_Fonts,
+    FOLDERID_LocalAppData, FOLDERID_Profile, FOLDERID_ProgramData, FOLDERID_ProgramFiles,
+    FOLDERID_ProgramFilesCommon, FOLDERID_Programs, FOLDERID_Public, FOLDERID_PublicDocuments,
+    FOLDERID_Recent, FOLDERID_RoamingAppData, FOLDERID_SavedGames, FOLDERID_Startup,
+    FOLDERID_Templates, FOLDERID_UserProgramFiles, FOLDERID_UserProgramFilesCommon,
+};
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-const D_CACHE: &str = "Cache";
+const D_SERVICE_CONFIG: &str = "Config";
+const D_SERVICE_DATA: &str = "Data";
+const D_SERVICE_CACHE: &str = "Cache";
+const D_SERVICE_LOGS: &str = "Logs";
+const D_PROGRAMS: &str = "Programs";
+const D_COMMON: &str = "Common";
+const D_PACKAGES: &str = "Packages";
+const P_PIPES: &str = r"\\.\pipe\";
+const K_APP_PATHS: &str = r"SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths";
+const V_PATH: &str = "Path";
+#[cfg(feature = "registry")]
+const K_UNINSTALL: &str = r"SOFTWARE\Microsoft\Windows\CurrentVersion\Uninstall";
+#[cfg(feature = "registry")]
+const K_UNINSTALL_WOW64: &str = r"SOFTWARE\WOW6432Node\Microsoft\Windows\CurrentVersion\Uninstall";
+#[cfg(feature = "registry")]
+const V_DISPLAY_NAME: &str = "DisplayName";
+#[cfg(feature = "registry")]
+const V_INSTALL_LOCATION: &str = "InstallLocation";
+
+pub fn home_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Profile)
+}
 
 pub fn application_dir() -> Option<PathBuf> {
-    known_folder(&knownfolders::FOLDERID_ProgramFiles)
+    known_folder(&FOLDERID_ProgramFiles)
 }
 
 pub fn application_shared_dir() -> Option<PathBuf> {
-    known_folder(&knownfolders::FOLDERID_ProgramFilesCommon)
+    known_folder(&FOLDERID_ProgramFilesCommon)
 }
 
 pub fn user_application_dir() -> Option<PathBuf> {
-    known_folder(&knownfolders::FOLDERID_UserProgramFiles)
+    known_folder(&FOLDERID_UserProgramFiles)
+        .or_else(|| data_local_dir().map(|path| path.join(D_PROGRAMS)))
+}
+
+pub fn user_application_shared_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_UserProgramFilesCommon)
+        .or_else(|| data_local_dir().map(|path| path.join(D_PROGRAMS).join(D_COMMON)))
+}
+
+pub fn fallback_source(kind: DirKind) -> Option<&'static str> {
+    match kind {
+        DirKind::UserApplication if known_folder(&FOLDERID_UserProgramFiles).is_none() => {
+            Some("%LOCALAPPDATA%\\Programs")
+        }
+        DirKind::UserApplicationShared
+            if known_folder(&FOLDERID_UserProgramFilesCommon).is_none() =>
+        {
+            Some("%LOCALAPPDATA%\\Programs\\Common")
+        }
+        _ => None,
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
 
-pub fn app_container_dir_for(_: &str) -> Option<PathBuf> {
-    None
+pub fn host_home_dir() -> Option<PathBuf> {
+    home_dir()
 }
 
-pub fn app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
-    app_container_dir_for(app)
+pub fn package_family_name() -> Option<String> {
+    current_package_family_name()
 }
 
-pub fn user_app_container_dir_for(_: &str) -> Option<PathBuf> {
-    None
+pub fn package_dir_for(family_name: &str) -> Option<PathBuf> {
+    data_local_dir().map(|path| {
+        path.join(D_PACKAGES)
+            .join(crate::sanitize_app_name(family_name).as_ref())
+    })
+}
+
+pub fn installed_container_dir_for(kind: DirKind, app: &str) -> Option<PathBuf> {
+    use windows_sys::Win32::System::Registry::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+    let (root, parent) = match kind {
+        DirKind::AppContainer | DirKind::AppContainerExecutable => {
+            (HKEY_LOCAL_MACHINE, application_dir())
+        }
+        DirKind::UserAppContainer | DirKind::UserAppContainerExecutable => {
+            (HKEY_CURRENT_USER, user_application_dir())
+        }
+        _ => return None,
+    };
+    let app = crate::sanitize_app_name(app);
+    let key = if app.to_ascii_lowercase().ends_with(".exe") {
+        format!("{}\\{}", K_APP_PATHS, app)
+    } else {
+        format!("{}\\{}.exe", K_APP_PATHS, app)
+    };
+    app_paths_dir(
+        registry_string(root, &key, Some(V_PATH)),
+        registry_string(root, &key, None),
+    )
+    .or_else(|| {
+        // a registered location is only used where the conventional folder does not exist.
+        if parent.is_some_and(|parent| crate::override_root::is_dir(&parent.join(app.as_ref()))) {
+            None
+        } else {
+            uninstall_location(root, &app)
+        }
+    })
+}
+
+pub fn registered_install_dir_for(app: &str) -> Option<PathBuf> {
+    use windows_sys::Win32::System::Registry::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+    let app = crate::sanitize_app_name(app);
+    uninstall_location(HKEY_LOCAL_MACHINE, &app)
+        .or_else(|| uninstall_location(HKEY_CURRENT_USER, &app))
+}
+
+pub fn crash_dumps_dir() -> Option<PathBuf> {
+    data_local_dir().map(|path| path.join("CrashDumps"))
+}
+
+pub fn known_folder_dir(id: crate::FolderId) -> Option<PathBuf> {
+    known_folder(folder_id_guid(id))
+}
+
+pub fn temp_dir() -> Option<PathBuf> {
+    Some(std::env::temp_dir())
+}
+
+pub fn pipe_name_for(app: &str) -> Option<String> {
+    Some(format!("{}{}", P_PIPES, crate::sanitize_app_name(app)))
+}
+
+pub fn recent_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Recent)
+}
+
+pub fn system_config_dirs() -> Vec<PathBuf> {
+    system_data_dirs()
+}
+
+pub fn system_data_dirs() -> Vec<PathBuf> {
+    known_folder(&FOLDERID_ProgramData).into_iter().collect()
+}
+
+pub fn system_domain_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+pub fn service_dirs_for(app: &str) -> crate::ServiceDirs {
+    let base = known_folder(&FOLDERID_ProgramData)
+        .map(|path| path.join(crate::sanitize_app_name(app).as_ref()));
+    let dir = |name: &str| base.as_ref().map(|path| path.join(name));
+    crate::ServiceDirs {
+        config: dir(D_SERVICE_CONFIG),
+        data: dir(D_SERVICE_DATA),
+        cache: dir(D_SERVICE_CACHE),
+        runtime: None,
+        log: dir(D_SERVICE_LOGS),
+    }
+}
+
+pub fn autostart_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Startup)
+}
+
+pub fn system_autostart_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_CommonStartup)
+}
+
+pub fn font_dir() -> Option<PathBuf> {
+    data_local_dir().map(|path| path.join("Microsoft").join("Windows").join("Fonts"))
+}
+
+pub fn system_font_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Fonts)
+}
+
+pub fn system_wallpaper_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Windows).map(|path| path.join("Web").join("Wallpaper"))
+}
+
+pub fn desktop_entry_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Programs)
+}
+
+pub fn system_desktop_entry_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_CommonPrograms)
+}
+
+pub fn public_data_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_ProgramData)
 }
 
-pub fn user_app_container_executable_dir_for(app: &str) -> Option<PathBuf> {
-    user_app_container_dir_for(app)
+pub fn shared_documents_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_PublicDocuments)
 }
 
 // ------------------------------------------------------------------------------------------------
 
-pub fn cache_dir_for(app: &str) -> Option<PathBuf> {
-    cache_dir().map(|path| path.join(app).join(D_CACHE))
+pub fn cache_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_LocalAppData)
 }
 
-pub fn config_dir_for(app: &str) -> Option<PathBuf> {
-    config_dir().map(|path| path.join(app))
+pub fn config_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_RoamingAppData)
 }
 
-pub fn data_dir_for(app: &str) -> Option<PathBuf> {
-    data_dir().map(|path| path.join(app))
+pub fn config_local_dir() -> Option<PathBuf> {
+    data_local_dir()
 }
 
-pub fn data_local_dir_for(app: &str) -> Option<PathBuf> {
-    data_local_dir().map(|path| path.join(app))
+pub fn data_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_RoamingAppData)
 }
 
-pub fn favorites_dir() -> Option<PathBuf> {
-    known_folder(&knownfolders::FOLDERID_Favorites)
+pub fn data_local_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_LocalAppData)
+}
+
+pub fn document_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Documents)
+}
+
+pub fn download_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Downloads)
+}
+
+pub fn desktop_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Desktop)
+}
+
+pub fn public_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Public)
 }
 
-pub fn favorites_dir_for(app: &str) -> Option<PathBuf> {
-    favorites_dir().map(|path| path.join(app))
+pub fn favorites_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Favorites)
 }
 
 pub fn log_dir() -> Option<PathBuf> {
     data_local_dir().map(|h| h.join("Logs"))
 }
 
-pub fn log_dir_for(app: &str) -> Option<PathBuf> {
-    log_dir().map(|path| path.join(app))
+pub fn system_log_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_ProgramData)
 }
 
 pub fn preference_dir() -> Option<PathBuf> {
     config_dir()
 }
 
-pub fn preference_dir_for(app: &str) -> Option<PathBuf> {
-    config_dir_for(app)
+pub fn state_dir() -> Option<PathBuf> {
+    data_local_dir()
+}
+
+pub fn template_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_Templates)
+}
+
+pub fn system_template_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_CommonTemplates)
+}
+
+pub fn is_remote(path: &Path) -> bool {
+    use std::path::{Component, Prefix};
+    use windows_sys::Win32::Storage::FileSystem::GetDriveTypeW;
+    use windows_sys::Win32::System::WindowsProgramming::DRIVE_REMOTE;
+
+    match path.components().next() {
+        Some(Component::Prefix(prefix)) => match prefix.kind() {
+            Prefix::UNC(..) | Prefix::VerbatimUNC(..) => true,
+            Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => {
+                let root: Vec<u16> = format!("{}:\\", letter as char)
+                    .encode_utf16()
+                    .chain(Some(0))
+                    .collect();
+                unsafe { GetDriveTypeW(root.as_ptr()) == DRIVE_REMOTE }
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+pub fn saved_games_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_SavedGames)
+}
+
+pub fn themes_dir() -> Option<PathBuf> {
+    data_local_dir().map(|path| path.join("Microsoft").join("Windows").join("Themes"))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub fn uses_system_journal() -> bool {
+    true
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the path of the known folder `id`, or `None` if it is not defined on this system.
+///
+fn known_folder(id: &GUID) -> Option<PathBuf> {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt;
+    use std::ptr::null_mut;
+    use windows_sys::Win32::Foundation::S_OK;
+    use windows_sys::Win32::System::Com::CoTaskMemFree;
+    use windows_sys::Win32::UI::Shell::{SHGetKnownFolderPath, KF_FLAG_DEFAULT};
+
+    unsafe {
+        let mut path = null_mut();
+        let result = SHGetKnownFolderPath(id, KF_FLAG_DEFAULT as _, null_mut(), &mut path);
+        // the buffer must be freed even when the call fails.
+        let value = if result == S_OK && !path.is_null() {
+            let length = (0..).take_while(|&i| *path.add(i) != 0).count();
+            Some(PathBuf::from(OsString::from_wide(
+                std::slice::from_raw_parts(path, length),
+            )))
+        } else {
+            None
+        };
+        CoTaskMemFree(path.cast());
+        value
+    }
+}
+
+fn folder_id_guid(id: crate::FolderId) -> &'static GUID {
+    use crate::FolderId;
+    use windows_sys::Win32::UI::Shell::*;
+
+    match id {
+        FolderId::AccountPictures => &FOLDERID_AccountPictures,
+        FolderId::AdminTools => &FOLDERID_AdminTools,
+        FolderId::CameraRoll => &FOLDERID_CameraRoll,
+        FolderId::CDBurning => &FOLDERID_CDBurning,
+        FolderId::CommonAdminTools => &FOLDERID_CommonAdminTools,
+        FolderId::CommonStartMenu => &FOLDERID_CommonStartMenu,
+        FolderId::CommonTemplates => &FOLDERID_CommonTemplates,
+        FolderId::Contacts => &FOLDERID_Contacts,
+        FolderId::Cookies => &FOLDERID_Cookies,
+        FolderId::Fonts => &FOLDERID_Fonts,
+        FolderId::History => &FOLDERID_History,
+        FolderId::InternetCache => &FOLDERID_InternetCache,
+        FolderId::Links => &FOLDERID_Links,
+        FolderId::LocalAppDataLow => &FOLDERID_LocalAppDataLow,
+        FolderId::Music => &FOLDERID_Music,
+        FolderId::NetHood => &FOLDERID_NetHood,
+        FolderId::Objects3D => &FOLDERID_Objects3D,
+        FolderId::OneDrive => &FOLDERID_OneDrive,
+        FolderId::Pictures => &FOLDERID_Pictures,
+        FolderId::Playlists => &FOLDERID_Playlists,
+        FolderId::PrintHood => &FOLDERID_PrintHood,
+        FolderId::ProgramFilesX86 => &FOLDERID_ProgramFilesX86,
+        FolderId::ProgramFilesCommonX86 => &FOLDERID_ProgramFilesCommonX86,
+        FolderId::PublicDesktop => &FOLDERID_PublicDesktop,
+        FolderId::PublicDocuments => &FOLDERID_PublicDocuments,
+        FolderId::PublicDownloads => &FOLDERID_PublicDownloads,
+        FolderId::PublicMusic => &FOLDERID_PublicMusic,
+        FolderId::PublicPictures => &FOLDERID_PublicPictures,
+        FolderId::PublicRingtones => &FOLDERID_PublicRingtones,
+        FolderId::PublicVideos => &FOLDERID_PublicVideos,
+        FolderId::QuickLaunch => &FOLDERID_QuickLaunch,
+        FolderId::Ringtones => &FOLDERID_Ringtones,
+        FolderId::SavedPictures => &FOLDERID_SavedPictures,
+        FolderId::SavedSearches => &FOLDERID_SavedSearches,
+        FolderId::Screenshots => &FOLDERID_Screenshots,
+        FolderId::SendTo => &FOLDERID_SendTo,
+        FolderId::StartMenu => &FOLDERID_StartMenu,
+        FolderId::System => &FOLDERID_System,
+        FolderId::SystemX86 => &FOLDERID_SystemX86,
+        FolderId::UserPinned => &FOLDERID_UserPinned,
+        FolderId::UserProfiles => &FOLDERID_UserProfiles,
+        FolderId::Videos => &FOLDERID_Videos,
+        FolderId::Windows => &FOLDERID_Windows,
+    }
+}
+
+///
+/// Returns the directory of an application registered under App Paths, given the key's `Path`
+/// value and its default value, the full path of the executable. `Path` is the directory added to
+/// the application's `PATH` and, where present, is preferred; it may list several directories.
+///
+fn app_paths_dir(path: Option<String>, executable: Option<String>) -> Option<PathBuf> {
+    path.and_then(|path| {
+        path.split(';')
+            .map(|dir| dir.trim().trim_matches('"'))
+            .find(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    })
+    .or_else(|| {
+        executable.and_then(|executable| {
+            PathBuf::from(executable.trim().trim_matches('"'))
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .map(PathBuf::from)
+        })
+    })
+}
+
+///
+/// Returns the `InstallLocation` of the application registered for uninstallation beneath
+/// `root`, whose key or `DisplayName` is `app`, ignoring case. On 64-bit Windows the entries of
+/// 32-bit applications are searched after the native ones.
+///
+#[cfg(feature = "registry")]
+fn uninstall_location(
+    root: windows_sys::Win32::System::Registry::HKEY,
+    app: &str,
+) -> Option<PathBuf> {
+    [K_UNINSTALL, K_UNINSTALL_WOW64]
+        .iter()
+        .find_map(|uninstall| {
+            registry_subkeys(root, uninstall)
+                .into_iter()
+                .find_map(|subkey| {
+                    let key = format!("{}\\{}", uninstall, subkey);
+                    if is_uninstall_entry_for(
+                        &subkey,
+                        registry_string(root, &key, Some(V_DISPLAY_NAME)),
+                        app,
+                    ) {
+                        install_location_dir(registry_string(root, &key, Some(V_INSTALL_LOCATION)))
+                    } else {
+                        None
+                    }
+                })
+        })
+}
+
+#[cfg(not(feature = "registry"))]
+fn uninstall_location(_: windows_sys::Win32::System::Registry::HKEY, _: &str) -> Option<PathBuf> {
+    None
+}
+
+///
+/// Returns `true` if the uninstall entry with the key `subkey` and display name `display_name` is
+/// that of `app`.
+///
+#[cfg(feature = "registry")]
+fn is_uninstall_entry_for(subkey: &str, display_name: Option<String>, app: &str) -> bool {
+    subkey.eq_ignore_ascii_case(app)
+        || display_name.is_some_and(|name| name.trim().eq_ignore_ascii_case(app))
+}
+
+///
+/// Returns the directory of an uninstall entry's `InstallLocation`, which may be quoted, and is
+/// often empty.
+///
+#[cfg(feature = "registry")]
+fn install_location_dir(location: Option<String>) -> Option<PathBuf> {
+    location
+        .map(|location| PathBuf::from(location.trim().trim_matches('"')))
+        .filter(|location| location.is_absolute())
+}
+
+///
+/// Returns the names of the subkeys of the registry key `key` beneath `root`.
+///
+#[cfg(feature = "registry")]
+fn registry_subkeys(root: windows_sys::Win32::System::Registry::HKEY, key: &str) -> Vec<String> {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt;
+    use std::ptr::{null, null_mut};
+    use windows_sys::Win32::Foundation::ERROR_SUCCESS;
+    use windows_sys::Win32::System::Registry::{
+        RegCloseKey, RegEnumKeyExW, RegOpenKeyExW, KEY_READ,
+    };
+
+    let key: Vec<u16> = key.encode_utf16().chain(Some(0)).collect();
+    let mut subkeys = Vec::new();
+    unsafe {
+        let mut handle = null_mut();
+        if RegOpenKeyExW(root, key.as_ptr(), 0, KEY_READ, &mut handle) != ERROR_SUCCESS {
+            return subkeys;
+        }
+        // the name of a key is at most 255 characters.
+        let mut buffer = [0u16; 256];
+        let mut index = 0;
+        loop {
+            let mut length = buffer.len() as u32;
+            let result = RegEnumKeyExW(
+                handle,
+                index,
+                buffer.as_mut_ptr(),
+                &mut length,
+                null(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+            );
+            if result != ERROR_SUCCESS {
+                break;
+            }
+            subkeys.push(
+                OsString::from_wide(&buffer[..length as usize])
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+            index += 1;
+        }
+        let _ = RegCloseKey(handle);
+    }
+    subkeys
 }
 
-pub use dirs_next::template_dir;
+///
+/// Returns the string value `value` of the registry key `key` beneath `root`, or the key's
+/// default value if `value` is `None`. Values of type `REG_EXPAND_SZ` are expanded.
+///
+fn registry_string(
+    root: windows_sys::Win32::System::Registry::HKEY,
+    key: &str,
+    value: Option<&str>,
+) -> Option<String> {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt;
+    use std::ptr::{null, null_mut};
+    use windows_sys::Win32::Foundation::{ERROR_MORE_DATA, ERROR_SUCCESS};
+    use windows_sys::Win32::System::Registry::{RegGetValueW, RRF_RT_REG_SZ};
 
-pub fn template_dir_for(app: &str) -> Option<PathBuf> {
-    template_dir().map(|d| d.join(app))
+    let key: Vec<u16> = key.encode_utf16().chain(Some(0)).collect();
+    let value: Option<Vec<u16>> = value.map(|value| value.encode_utf16().chain(Some(0)).collect());
+    let value = value.as_ref().map_or(null(), |value| value.as_ptr());
+    unsafe {
+        let mut size = 0u32;
+        let result = RegGetValueW(
+            root,
+            key.as_ptr(),
+            value,
+            RRF_RT_REG_SZ,
+            null_mut(),
+            null_mut(),
+            &mut size,
+        );
+        if result != ERROR_SUCCESS {
+            return None;
+        }
+        // the size of an expanded string is only an estimate, so the buffer may need to grow.
+        loop {
+            let mut buffer = vec![0u16; (size as usize + 1) / 2];
+            let result = RegGetValueW(
+                root,
+                key.as_ptr(),
+                value,
+                RRF_RT_REG_SZ,
+                null_mut(),
+                buffer.as_mut_ptr().cast(),
+                &mut size,
+            );
+            if result == ERROR_SUCCESS {
+                let length = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+                return Some(
+                    OsString::from_wide(&buffer[..length])
+                        .to_string_lossy()
+                        .into_owned(),
+                );
+            } else if result != ERROR_MORE_DATA {
+                return None;
+            }
+        }
+    }
+}
+
+type GetCurrentPackageFamilyName = unsafe extern "system" fn(*mut u32, *mut u16) -> i32;
+
+///
+/// Returns the package family name of the current process, if it is running as part of an MSIX
+/// package. `GetCurrentPackageFamilyName` is looked up at runtime, as it is not present before
+/// Windows 8.
+///
+fn current_package_family_name() -> Option<String> {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt;
+    use std::ptr::null_mut;
+    use windows_sys::Win32::Foundation::{ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS};
+    use windows_sys::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress};
+
+    let module: Vec<u16> = "kernel32.dll".encode_utf16().chain(Some(0)).collect();
+    unsafe {
+        let kernel32 = GetModuleHandleW(module.as_ptr());
+        if kernel32.is_null() {
+            return None;
+        }
+        let address = GetProcAddress(kernel32, b"GetCurrentPackageFamilyName\0".as_ptr())?;
+        let get_family_name = std::mem::transmute::<
+            unsafe extern "system" fn() -> isize,
+            GetCurrentPackageFamilyName,
+        >(address);
+        // an unpackaged process fails with APPMODEL_ERROR_NO_PACKAGE rather than reporting the
+        // length required.
+        let mut length = 0;
+        if get_family_name(&mut length, null_mut()) != ERROR_INSUFFICIENT_BUFFER as i32 {
+            return None;
+        }
+        let mut buffer = vec![0u16; length as usize];
+        if get_family_name(&mut length, buffer.as_mut_ptr()) != ERROR_SUCCESS as i32 {
+            return None;
+        }
+        buffer.truncate(length.saturating_sub(1) as usize);
+        Some(OsString::from_wide(&buffer).to_string_lossy().into_owned())
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -93,7 +632,7 @@ pub fn template_dir_for(app: &str) -> Option<PathBuf> {
 
 #[cfg(test)]
 mod tests {
-    use dirs_next::home_dir;
+    use super::home_dir;
     use std::path::PathBuf;
 
     const SYSTEM_DRIVE: &str = env!("SystemDrive");
@@ -101,18 +640,14 @@ mod tests {
     fn test_user_dir(dir: PathBuf, suffix: &str) {
         assert_eq!(
             dir.to_string_lossy().to_string(),
-            format!(
-                "{}\\{}",
-                home_dir().unwrap().to_string_lossy().to_string(),
-                suffix
-            )
+            format!("{}\\{}", home_dir().unwrap().to_string_lossy(), suffix)
         )
     }
 
     fn test_dir(dir: PathBuf, path: &str) {
         assert_eq!(
             dir.to_string_lossy().to_string(),
-            format!("{}\\{}", SYSTEM_DRIVE, path.to_string())
+            format!("{}\\{}", SYSTEM_DRIVE, path)
         )
     }
 
@@ -125,6 +660,10 @@ mod tests {
     #[test]
     fn test_application_dir() {
         test_dir(crate::application_dir().unwrap(), "Program Files");
+        test_dir(
+            crate::application_dir_for("Chrome").unwrap(),
+            "Program Files\\Chrome",
+        );
     }
 
     #[test]
@@ -137,29 +676,222 @@ mod tests {
 
     #[test]
     fn test_user_application_dir() {
-        test_dir_is_none(crate::user_application_dir());
+        let dir = crate::user_application_dir().unwrap();
+        if crate::explain_dir(crate::DirKind::UserApplication).source != crate::Source::Platform {
+            test_user_dir(dir, "AppData\\Local\\Programs");
+            test_user_dir(
+                crate::user_application_dir_for("Chrome").unwrap(),
+                "AppData\\Local\\Programs\\Chrome",
+            );
+        }
+    }
+
+    #[test]
+    fn test_user_application_shared_dir() {
+        let dir = crate::user_application_shared_dir().unwrap();
+        if crate::explain_dir(crate::DirKind::UserApplicationShared).source
+            != crate::Source::Platform
+        {
+            test_user_dir(dir, "AppData\\Local\\Programs\\Common");
+        }
+    }
+
+    #[test]
+    fn test_sandbox_container_dir_for() {
+        assert!(crate::sandbox_container_id().is_none());
+        assert!(crate::sandbox_container_dir_for("com.example.App").is_none());
+    }
+
+    #[test]
+    fn test_group_container_dir_for() {
+        assert!(crate::group_container_dir_for("group.com.example.App").is_none());
+    }
+
+    #[test]
+    fn test_crash_reports_dir() {
+        assert!(crate::crash_reports_dir().is_none());
+        assert!(crate::system_crash_reports_dir().is_none());
+        assert!(crate::crash_reports_for("Chrome").is_empty());
+    }
+
+    #[test]
+    fn test_crash_dumps_dir_for() {
+        test_user_dir(
+            crate::crash_dumps_dir_for("Chrome").unwrap(),
+            "AppData\\Local\\CrashDumps",
+        );
+    }
+
+    #[test]
+    fn test_font_dir() {
+        test_user_dir(
+            crate::font_dir().unwrap(),
+            "AppData\\Local\\Microsoft\\Windows\\Fonts",
+        );
+        assert!(crate::system_font_dir().unwrap().ends_with("Fonts"));
+    }
+
+    #[test]
+    fn test_man_dir() {
+        assert!(crate::man_dir().is_none());
+        assert!(crate::system_man_dir().is_none());
+    }
+
+    #[test]
+    fn test_desktop_entry_dir() {
+        test_user_dir(
+            crate::desktop_entry_dir().unwrap(),
+            "AppData\\Roaming\\Microsoft\\Windows\\Start Menu\\Programs",
+        );
+        assert!(crate::system_desktop_entry_dir().is_some());
+    }
+
+    #[test]
+    fn test_mime_dir() {
+        test_dir_is_none(crate::mime_dir());
+        test_dir_is_none(crate::system_mime_dir());
+        test_dir_is_none(crate::glib_schemas_dir());
+        test_dir_is_none(crate::keyfile_backend_dir());
+    }
+
+    #[test]
+    fn test_public_data_dir() {
+        test_dir(
+            crate::public_data_dir_for("Chrome").unwrap(),
+            "ProgramData\\Chrome",
+        );
+        test_dir(
+            crate::shared_documents_dir_for("Chrome").unwrap(),
+            "Users\\Public\\Documents\\Chrome",
+        );
+    }
+
+    #[test]
+    fn test_autostart_dir() {
+        test_user_dir(
+            crate::autostart_dir().unwrap(),
+            "AppData\\Roaming\\Microsoft\\Windows\\Start Menu\\Programs\\Startup",
+        );
+        assert!(crate::system_autostart_dir().is_some());
+    }
+
+    #[test]
+    fn test_linux_sandbox() {
+        assert!(crate::flatpak_id().is_none());
+        assert!(crate::snap_name().is_none());
+        assert!(crate::sandboxed_app_dir().is_none());
+        assert_eq!(crate::host_home_dir(), home_dir());
+    }
+
+    #[test]
+    fn test_package_dir_for() {
+        assert!(crate::package_family_name().is_none());
+        test_user_dir(
+            crate::package_dir_for("Example.App_8wekyb3d8bbwe").unwrap(),
+            "AppData\\Local\\Packages\\Example.App_8wekyb3d8bbwe",
+        );
+    }
+
+    #[test]
+    fn test_icloud_dirs() {
+        assert!(crate::icloud_drive_dir().is_none());
+        assert!(crate::icloud_container_dir_for("iCloud.com.example.App").is_none());
+    }
+
+    #[test]
+    fn test_application_scripts_dir_for() {
+        assert!(crate::application_scripts_dir_for("com.example.App").is_none());
+    }
+
+    #[test]
+    fn test_launch_agents_dir() {
+        assert!(crate::launch_agents_dir().is_none());
+        assert!(crate::system_launch_agents_dir().is_none());
+        assert!(crate::launch_daemons_dir().is_none());
     }
 
     // --------------------------------------------------------------------------------------------
 
     #[test]
     fn test_app_container_dir_for() {
-        test_dir_is_none(crate::app_container_dir_for("Chrome"));
+        // an installation found in the registry is preferred to the conventional folder.
+        assert_eq!(
+            crate::app_container_dir_for("Chrome"),
+            super::installed_container_dir_for(crate::DirKind::AppContainer, "Chrome")
+                .or_else(|| crate::application_dir().map(|path| path.join("Chrome")))
+        );
+        test_dir(
+            crate::app_container_dir_for("NoSuchApp").unwrap(),
+            "Program Files\\NoSuchApp",
+        );
+        test_dir_is_none(crate::app_container_resources_dir_for("Chrome"));
     }
 
     #[test]
     fn test_app_container_executable_dir_for() {
-        test_dir_is_none(crate::app_container_executable_dir_for("Chrome"));
+        assert_eq!(
+            crate::app_container_executable_dir_for("Chrome"),
+            crate::app_container_dir_for("Chrome")
+        );
     }
 
     #[test]
     fn test_user_app_container_dir_for() {
-        test_dir_is_none(crate::user_app_container_dir_for("Chrome"));
+        assert_eq!(
+            crate::user_app_container_dir_for("Chrome"),
+            super::installed_container_dir_for(crate::DirKind::UserAppContainer, "Chrome")
+                .or_else(|| crate::user_application_dir().map(|path| path.join("Chrome")))
+        );
     }
 
     #[test]
     fn test_user_app_container_executable_dir_for() {
-        test_dir_is_none(crate::user_app_container_executable_dir_for("Chrome"));
+        assert_eq!(
+            crate::user_app_container_executable_dir_for("Chrome"),
+            crate::user_app_container_dir_for("Chrome")
+        );
+    }
+
+    #[test]
+    fn test_app_paths_dir() {
+        assert_eq!(
+            super::app_paths_dir(
+                Some("C:\\Tools\\Chrome;C:\\Tools".to_string()),
+                Some("C:\\Other\\chrome.exe".to_string())
+            ),
+            Some(PathBuf::from("C:\\Tools\\Chrome"))
+        );
+        assert_eq!(
+            super::app_paths_dir(None, Some("\"C:\\Tools\\Chrome\\chrome.exe\"".to_string())),
+            Some(PathBuf::from("C:\\Tools\\Chrome"))
+        );
+        assert_eq!(super::app_paths_dir(Some(String::new()), None), None);
+        assert_eq!(
+            super::app_paths_dir(None, Some("chrome.exe".to_string())),
+            None
+        );
+    }
+
+    #[cfg(feature = "registry")]
+    #[test]
+    fn test_uninstall_entry() {
+        assert!(super::is_uninstall_entry_for("Chrome", None, "chrome"));
+        assert!(super::is_uninstall_entry_for(
+            "{8A69D345-D564-463C-AFF1-A69D9E530F96}",
+            Some("Google Chrome ".to_string()),
+            "Google Chrome"
+        ));
+        assert!(!super::is_uninstall_entry_for(
+            "ChromeUpdate",
+            Some("Chrome Update".to_string()),
+            "Chrome"
+        ));
+        assert_eq!(
+            super::install_location_dir(Some("\"C:\\Vendor\\Chrome\"".to_string())),
+            Some(PathBuf::from("C:\\Vendor\\Chrome"))
+        );
+        assert_eq!(super::install_location_dir(Some(String::new())), None);
+        assert_eq!(super::install_location_dir(None), None);
     }
 
     // --------------------------------------------------------------------------------------------
@@ -190,6 +922,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_local_dir() {
+        test_user_dir(crate::config_local_dir().unwrap(), "AppData\\Local");
+    }
+
+    #[test]
+    fn test_config_local_dir_for() {
+        test_user_dir(
+            crate::config_local_dir_for("Chrome").unwrap(),
+            "AppData\\Local\\Chrome",
+        );
+    }
+
     #[test]
     fn test_data_dir() {
         test_user_dir(crate::data_dir().unwrap(), "AppData\\Roaming");
@@ -242,6 +987,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_system_log_dir() {
+        test_dir(crate::system_log_dir().unwrap(), "ProgramData");
+    }
+
+    #[test]
+    fn test_system_log_dir_for() {
+        test_dir(
+            crate::system_log_dir_for("Chrome").unwrap(),
+            "ProgramData\\Chrome\\Logs",
+        );
+    }
+
+    #[test]
+    fn test_service_dirs_for() {
+        let dirs = crate::service_dirs_for("Chrome");
+        test_dir(dirs.config.unwrap(), "ProgramData\\Chrome\\Config");
+        test_dir(dirs.log.unwrap(), "ProgramData\\Chrome\\Logs");
+        test_dir_is_none(dirs.runtime);
+    }
+
     #[test]
     fn test_preference_dir() {
         test_user_dir(crate::preference_dir().unwrap(), "AppData\\Roaming");
@@ -255,6 +1021,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_state_dir_for() {
+        test_user_dir(
+            crate::state_dir_for("Chrome").unwrap(),
+            "AppData\\Local\\Chrome",
+        );
+    }
+
     #[test]
     fn test_template_dir() {
         test_user_dir(
@@ -270,4 +1044,69 @@ mod tests {
             "AppData\\Roaming\\Microsoft\\Windows\\Templates\\Chrome",
         );
     }
+
+    #[test]
+    fn test_system_template_dir_for() {
+        test_dir(
+            crate::system_template_dir_for("Chrome").unwrap(),
+            "ProgramData\\Microsoft\\Windows\\Templates\\Chrome",
+        );
+    }
+
+    #[test]
+    fn test_saved_games_dir() {
+        test_user_dir(crate::saved_games_dir().unwrap(), "Saved Games");
+    }
+
+    #[test]
+    fn test_saved_games_dir_for() {
+        test_user_dir(
+            crate::saved_games_dir_for("Chrome").unwrap(),
+            "Saved Games\\Chrome",
+        );
+    }
+
+    #[test]
+    fn test_icons_dir_for() {
+        assert_eq!(crate::icons_dir(), None);
+        test_user_dir(
+            crate::icons_dir_for("Chrome").unwrap(),
+            "AppData\\Roaming\\Chrome\\Icons",
+        );
+    }
+
+    #[test]
+    fn test_themes_dir_for() {
+        test_user_dir(
+            crate::themes_dir().unwrap(),
+            "AppData\\Local\\Microsoft\\Windows\\Themes",
+        );
+        test_user_dir(
+            crate::themes_dir_for("Chrome").unwrap(),
+            "AppData\\Roaming\\Chrome\\Themes",
+        );
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn test_minidump_path_for() {
+        test_user_dir(
+            crate::minidump_path_for("Chrome").unwrap(),
+            "AppData\\Local\\Chrome\\CrashDumps",
+        );
+    }
+
+    #[test]
+    fn test_pipe_name_for() {
+        assert_eq!(
+            crate::pipe_name_for("Chrome"),
+            Some(r"\\.\pipe\Chrome".to_string())
+        );
+    }
+
+    #[test]
+    fn test_trash_dir() {
+        test_dir_is_none(crate::trash_dir());
+    }
 }
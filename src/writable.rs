@@ -0,0 +1,106 @@
+/*!
+Provides the selection of a directory in which an application can actually write, from the
+directories it searches, for systems on which the user's own directories may be read-only.
+*/
+
+use std::fs::{remove_file, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the first directory, in the order returned by
+/// [`config_search_dirs_for`](fn.config_search_dirs_for.html), in which the current process is
+/// able to create files, or `None` if there is no such directory.
+///
+/// A directory that does not yet exist is writable if the process could create it; that is, if
+/// the nearest existing ancestor is writable. Writability is determined by creating, and then
+/// removing, a file, as permissions alone do not account for read-only file systems or access
+/// control lists.
+///
+/// ```rust
+/// if let Some(config) = xdirs::writable_config_dir_for("MyApp") {
+///     println!("saving settings to {:?}", config);
+/// }
+/// ```
+///
+pub fn writable_config_dir_for(app: &str) -> Option<PathBuf> {
+    first_writable(crate::config_search_dirs_for(app))
+}
+
+///
+/// Returns the first directory, in the order returned by
+/// [`data_search_dirs_for`](fn.data_search_dirs_for.html), in which the current process is able
+/// to create files, or `None` if there is no such directory. Writability is determined as for
+/// [`writable_config_dir_for`](fn.writable_config_dir_for.html).
+///
+pub fn writable_data_dir_for(app: &str) -> Option<PathBuf> {
+    first_writable(crate::data_search_dirs_for(app))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn first_writable(dirs: Vec<PathBuf>) -> Option<PathBuf> {
+    dirs.into_iter().find(|dir| is_writable(dir))
+}
+
+fn is_writable(dir: &Path) -> bool {
+    match dir.ancestors().find(|path| path.exists()) {
+        Some(existing) if existing.is_dir() => can_create_file_in(existing),
+        _ => false,
+    }
+}
+
+fn can_create_file_in(dir: &Path) -> bool {
+    static PROBES: AtomicUsize = AtomicUsize::new(0);
+    let probe = dir.join(format!(
+        ".xdirs-probe-{}-{}",
+        std::process::id(),
+        PROBES.fetch_add(1, Ordering::Relaxed)
+    ));
+    match OpenOptions::new().write(true).create_new(true).open(&probe) {
+        Ok(_) => {
+            let _ = remove_file(&probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+
+    #[test]
+    fn test_first_writable() {
+        let root = std::env::temp_dir().join(format!("xdirs-writable-{}", std::process::id()));
+        create_dir_all(&root).unwrap();
+        let file = root.join("file");
+        write(&file, b"").unwrap();
+
+        // a directory beneath a file can never be created.
+        let blocked = file.join("MyApp");
+        let missing = root.join("missing/MyApp");
+        assert_eq!(
+            first_writable(vec![blocked, missing.clone()]),
+            Some(missing.clone())
+        );
+        assert!(!missing.exists());
+        assert_eq!(
+            std::fs::read_dir(&root).unwrap().count(),
+            1,
+            "probe files are removed"
+        );
+        remove_dir_all(root).unwrap();
+    }
+}
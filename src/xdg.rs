@@ -0,0 +1,222 @@
+/*!
+Provides the individual XDG base directories, and how each was resolved, as building blocks for
+consumers composing their own layouts.
+
+The [XDG base directory](https://standards.freedesktop.org/basedir-spec/basedir-spec-latest.html)
+specification requires that the value of each variable be an absolute path, and that any other
+value be ignored in favor of the default; [`xdg_base`](fn.xdg_base.html) applies these rules, and
+reports a value that was ignored, so that consumers need not read the variables themselves.
+*/
+
+use crate::{override_root, Source};
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Identifies one of the XDG base directories.
+///
+/// |Kind         | Variable            | Default                   |
+/// | ----------- | ------------------- | ------------------------- |
+/// | `ConfigHome`| `$XDG_CONFIG_HOME`  | `$HOME`/.config           |
+/// | `DataHome`  | `$XDG_DATA_HOME`    | `$HOME`/.local/share      |
+/// | `CacheHome` | `$XDG_CACHE_HOME`   | `$HOME`/.cache            |
+/// | `StateHome` | `$XDG_STATE_HOME`   | `$HOME`/.local/state      |
+/// | `RuntimeDir`| `$XDG_RUNTIME_DIR`  | -                         |
+/// | `BinHome`   | `$XDG_BIN_HOME`     | `$HOME`/.local/bin        |
+///
+/// `$XDG_BIN_HOME` is not yet part of the specification, which only names `$HOME`/.local/bin as
+/// the location of user-specific executables, but is widely used to relocate it.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BaseKind {
+    /// User-specific configuration files.
+    ConfigHome,
+    /// User-specific data files.
+    DataHome,
+    /// User-specific non-essential, cached, data.
+    CacheHome,
+    /// User-specific state data that should persist between restarts, but is not important
+    /// enough to be kept with the user's data.
+    StateHome,
+    /// User-specific runtime files, such as sockets; this has no default.
+    RuntimeDir,
+    /// User-specific executable files.
+    BinHome,
+}
+
+///
+/// The resolution of a single XDG base directory.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Resolution {
+    /// The resolved directory, or `None` if the variable was not validly set and there is no
+    /// default, or the home directory is unknown.
+    pub value: Option<PathBuf>,
+    /// Either [`Source::Environment`](enum.Source.html#variant.Environment), naming the
+    /// variable, or [`Source::Fallback`](enum.Source.html#variant.Fallback), describing the
+    /// default used.
+    pub source: Source,
+    /// The value of the variable if it was set, but ignored because it was not an absolute path.
+    pub invalid: Option<OsString>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the XDG base directory `kind`, from its environment variable or the specification's
+/// default, on any platform; this ignores the platform's own conventions, which are applied by
+/// functions such as [`config_dir`](fn.config_dir.html).
+///
+/// ```rust
+/// use xdirs::{xdg_base, BaseKind};
+///
+/// let config = xdg_base(BaseKind::ConfigHome);
+/// if let Some(invalid) = config.invalid {
+///     eprintln!("ignoring XDG_CONFIG_HOME={:?}, it is not absolute", invalid);
+/// }
+/// ```
+///
+pub fn xdg_base(kind: BaseKind) -> Resolution {
+    let resolution = resolve(kind);
+    Resolution {
+        value: override_root::apply(resolution.value),
+        ..resolution
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Crate Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the XDG base directory `kind`, as for `xdg_base`, but without any root override
+/// applied; this is for the platform modules, whose results are overridden by their callers.
+///
+pub(crate) fn resolve(kind: BaseKind) -> Resolution {
+    let variable = kind.variable();
+    let mut invalid = None;
+    if let Some(value) = crate::environment::var_os(variable).filter(|value| !value.is_empty()) {
+        let path = PathBuf::from(&value);
+        if path.is_absolute() {
+            return Resolution {
+                value: Some(path),
+                source: Source::Environment(variable.to_string()),
+                invalid: None,
+            };
+        }
+        invalid = Some(value);
+    }
+    let value = kind.default_components().and_then(|components| {
+        crate::sys::home_dir().map(|home| {
+            components
+                .iter()
+                .fold(home, |path, component| path.join(component))
+        })
+    });
+    Resolution {
+        value,
+        source: Source::Fallback(kind.default_description()),
+        invalid,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl BaseKind {
+    ///
+    /// Returns the name of the environment variable for this base directory.
+    ///
+    pub fn variable(&self) -> &'static str {
+        match self {
+            Self::ConfigHome => "XDG_CONFIG_HOME",
+            Self::DataHome => "XDG_DATA_HOME",
+            Self::CacheHome => "XDG_CACHE_HOME",
+            Self::StateHome => "XDG_STATE_HOME",
+            Self::RuntimeDir => "XDG_RUNTIME_DIR",
+            Self::BinHome => "XDG_BIN_HOME",
+        }
+    }
+
+    fn default_components(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::ConfigHome => Some(&[".config"]),
+            Self::DataHome => Some(&[".local", "share"]),
+            Self::CacheHome => Some(&[".cache"]),
+            Self::StateHome => Some(&[".local", "state"]),
+            Self::RuntimeDir => None,
+            Self::BinHome => Some(&[".local", "bin"]),
+        }
+    }
+
+    fn default_description(&self) -> &'static str {
+        match self {
+            Self::ConfigHome => "$HOME/.config",
+            Self::DataHome => "$HOME/.local/share",
+            Self::CacheHome => "$HOME/.cache",
+            Self::StateHome => "$HOME/.local/state",
+            Self::RuntimeDir => "none",
+            Self::BinHome => "$HOME/.local/bin",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::EnvGuard;
+
+    #[test]
+    fn test_state_home() {
+        let mut env = EnvGuard::lock();
+        let absolute = std::env::temp_dir().join("xdirs-state");
+        env.set("XDG_STATE_HOME", &absolute);
+        crate::reload_env();
+        assert_eq!(
+            xdg_base(BaseKind::StateHome),
+            Resolution {
+                value: Some(absolute),
+                source: Source::Environment(String::from("XDG_STATE_HOME")),
+                invalid: None,
+            }
+        );
+
+        env.set("XDG_STATE_HOME", "relative/state");
+
+        crate::reload_env();
+        assert_eq!(
+            xdg_base(BaseKind::StateHome),
+            Resolution {
+                value: crate::sys::home_dir().map(|home| home.join(".local").join("state")),
+                source: Source::Fallback("$HOME/.local/state"),
+                invalid: Some(OsString::from("relative/state")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_runtime_dir() {
+        let resolution = xdg_base(BaseKind::RuntimeDir);
+        assert_eq!(
+            resolution.value.is_some(),
+            resolution.source == Source::Environment(String::from("XDG_RUNTIME_DIR"))
+        );
+    }
+
+    #[test]
+    fn test_variable() {
+        assert_eq!(BaseKind::CacheHome.variable(), "XDG_CACHE_HOME");
+    }
+}